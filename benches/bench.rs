@@ -4,7 +4,7 @@ use bencher::{Bencher, benchmark_group, benchmark_main};
 
 fn bench_weekendsonly(bench: &mut Bencher) {
     let cal = bdays::calendars::WeekendsOnly;
-    let d0 = Date::from_ymd(2015, 06, 29).unwrap();
+    let d0 = Date::from_ymd(2015, 6, 29).unwrap();
     let d1 = Date::from_ymd(2100, 12, 20).unwrap();
 
     bench.iter(|| {
@@ -14,7 +14,7 @@ fn bench_weekendsonly(bench: &mut Bencher) {
 
 fn bench_brsettlement(bench: &mut Bencher) {
     let cal = bdays::calendars::brazil::BRSettlement;
-    let d0 = Date::from_ymd(2015, 06, 29).unwrap();
+    let d0 = Date::from_ymd(2015, 6, 29).unwrap();
     let d1 = Date::from_ymd(2100, 12, 20).unwrap();
 
     bench.iter(|| {
@@ -24,7 +24,7 @@ fn bench_brsettlement(bench: &mut Bencher) {
 
 fn bench_brsettlement_cached(bench: &mut Bencher) {
     let cal = bdays::calendars::brazil::BRSettlement;
-    let d0 = Date::from_ymd(2015, 06, 29).unwrap();
+    let d0 = Date::from_ymd(2015, 6, 29).unwrap();
     let d1 = Date::from_ymd(2100, 12, 20).unwrap();
 
     let cached_cal = bdays::HolidayCalendarCache::new(
@@ -38,10 +38,106 @@ fn bench_brsettlement_cached(bench: &mut Bencher) {
     })
 }
 
+// every date from 2015 to 2024, checked one by one as without a HolidayCalendarCache
+fn check_dates<H: HolidayCalendar>(cal: &H) -> usize {
+    let mut date = Date::from_ymd(2015, 1, 1).unwrap();
+    let d1 = Date::from_ymd(2024, 12, 31).unwrap();
+    let mut holidays_count = 0;
+
+    while date <= d1 {
+        if cal.is_holiday(date) {
+            holidays_count += 1;
+        }
+
+        date = date.next_date();
+    }
+
+    holidays_count
+}
+
+fn bench_brsettlement_is_holiday(bench: &mut Bencher) {
+    let cal = bdays::calendars::brazil::BRSettlement;
+
+    bench.iter(|| check_dates(&cal))
+}
+
+fn bench_germanstate_is_holiday(bench: &mut Bencher) {
+    let cal = bdays::calendars::de::GermanState::BY;
+
+    bench.iter(|| check_dates(&cal))
+}
+
+fn brsettlement_cache() -> bdays::HolidayCalendarCache {
+    bdays::HolidayCalendarCache::new(
+        bdays::calendars::brazil::BRSettlement,
+        Date::from_ymd(1980, 1, 1).unwrap(),
+        Date::from_ymd(2100, 12, 31).unwrap(),
+    )
+}
+
+// 10_000 pairs of dates spread from 2015 to 2095, as in a risk run over a portfolio
+fn date_pairs() -> Vec<(Date, Date)> {
+    let d0 = Date::from_ymd(2015, 6, 29).unwrap();
+
+    (0..10_000)
+        .map(|i| (d0.advance_days(i % 3_000), d0.advance_days((i * 7) % 29_000)))
+        .collect()
+}
+
+fn bench_brsettlement_cached_bdays_loop(bench: &mut Bencher) {
+    let cached_cal = brsettlement_cache();
+    let pairs = date_pairs();
+    let mut out = vec![0; pairs.len()];
+
+    bench.iter(|| {
+        for (&(d0, d1), result) in pairs.iter().zip(out.iter_mut()) {
+            *result = cached_cal.bdays(d0, d1);
+        }
+    })
+}
+
+fn bench_brsettlement_cached_bdays_many(bench: &mut Bencher) {
+    let cached_cal = brsettlement_cache();
+    let pairs = date_pairs();
+    let mut out = vec![0; pairs.len()];
+
+    bench.iter(|| {
+        cached_cal.bdays_many(&pairs, &mut out);
+    })
+}
+
+fn bench_brsettlement_cached_advance_bdays_loop(bench: &mut Bencher) {
+    let cached_cal = brsettlement_cache();
+    let dates: Vec<(Date, i32)> = date_pairs().iter().map(|&(d0, _)| (d0, 252)).collect();
+    let mut out = vec![dates[0].0; dates.len()];
+
+    bench.iter(|| {
+        for (&(date, bdays_count), result) in dates.iter().zip(out.iter_mut()) {
+            *result = cached_cal.advance_bdays(date, bdays_count);
+        }
+    })
+}
+
+fn bench_brsettlement_cached_advance_bdays_many(bench: &mut Bencher) {
+    let cached_cal = brsettlement_cache();
+    let dates: Vec<(Date, i32)> = date_pairs().iter().map(|&(d0, _)| (d0, 252)).collect();
+    let mut out = vec![dates[0].0; dates.len()];
+
+    bench.iter(|| {
+        cached_cal.advance_bdays_many(&dates, &mut out);
+    })
+}
+
 benchmark_group!(
     benches,
     bench_weekendsonly,
     bench_brsettlement,
-    bench_brsettlement_cached
+    bench_brsettlement_cached,
+    bench_brsettlement_is_holiday,
+    bench_germanstate_is_holiday,
+    bench_brsettlement_cached_bdays_loop,
+    bench_brsettlement_cached_bdays_many,
+    bench_brsettlement_cached_advance_bdays_loop,
+    bench_brsettlement_cached_advance_bdays_many
 );
 benchmark_main!(benches);
@@ -0,0 +1,156 @@
+#ifndef BDAYS_H
+#define BDAYS_H
+
+/* Generated by cbindgen from src/ffi.rs. Do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Status code returned by every function.
+ */
+typedef enum BdaysStatus {
+  /**
+   * Success.
+   */
+  BDAYS_OK = 0,
+  /**
+   * A pointer argument is null.
+   */
+  BDAYS_NULL_POINTER = 1,
+  /**
+   * There's no built-in calendar with the given code.
+   */
+  BDAYS_UNKNOWN_CALENDAR = 2,
+  /**
+   * A date argument is not a valid date.
+   */
+  BDAYS_INVALID_DATE = 3,
+  /**
+   * A date, or the resulting date, is out of the range of the calendar.
+   */
+  BDAYS_OUT_OF_RANGE = 4,
+  /**
+   * The calendar code is not valid UTF-8, or the date format is unknown.
+   */
+  BDAYS_INVALID_ARGUMENT = 5,
+} BdaysStatus;
+
+/**
+ * How dates are encoded as `int32_t`.
+ */
+typedef enum BdaysDateFormat {
+  /**
+   * `year * 10000 + month * 100 + day`, such as `20251120`.
+   */
+  BDAYS_YYYYMMDD = 0,
+  /**
+   * Julian Day Number, such as `2461000` for 2025-11-20.
+   */
+  BDAYS_JDN = 1,
+} BdaysDateFormat;
+
+/**
+ * Opaque handle to a calendar, created by `bdays_calendar_new` and released by `bdays_calendar_free`.
+ */
+typedef struct BdaysCalendar BdaysCalendar;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Creates a calendar from its `code`, such as `"BRSettlement"`,
+ * for dates in the range from `dt_min` to `dt_max`, encoded as `date_format`.
+ * On success, the new calendar is written to `out`.
+ * The range must be within the years 1583 to 9999, or `BDAYS_OUT_OF_RANGE` is returned.
+ * `date_format` is a `BdaysDateFormat` value, or `BDAYS_INVALID_ARGUMENT` is returned.
+ *
+ * # Safety
+ *
+ * `code` must be null or a NUL-terminated string, and `out` must be null or valid for writes.
+ */
+enum BdaysStatus bdays_calendar_new(const char *code,
+                                    int32_t date_format,
+                                    int32_t dt_min,
+                                    int32_t dt_max,
+                                    struct BdaysCalendar **out);
+
+/**
+ * Releases a calendar created by `bdays_calendar_new`. Does nothing if `calendar` is null.
+ *
+ * # Safety
+ *
+ * `calendar` must be null or a calendar created by `bdays_calendar_new` that was not released yet.
+ */
+void bdays_calendar_free(struct BdaysCalendar *calendar);
+
+/**
+ * Writes to `out` whether `date` is a holiday.
+ *
+ * # Safety
+ *
+ * `calendar` must be null or a live calendar, and `out` must be null or valid for writes.
+ */
+enum BdaysStatus bdays_is_holiday(const struct BdaysCalendar *calendar, int32_t date, bool *out);
+
+/**
+ * Writes to `out` whether `date` is a business day.
+ *
+ * # Safety
+ *
+ * `calendar` must be null or a live calendar, and `out` must be null or valid for writes.
+ */
+enum BdaysStatus bdays_is_bday(const struct BdaysCalendar *calendar, int32_t date, bool *out);
+
+/**
+ * Writes to `out` the nearest business day to `date`,
+ * looking forward if `adjust_next` is true, backwards otherwise.
+ *
+ * # Safety
+ *
+ * `calendar` must be null or a live calendar, and `out` must be null or valid for writes.
+ */
+enum BdaysStatus bdays_to_bday(const struct BdaysCalendar *calendar,
+                               int32_t date,
+                               bool adjust_next,
+                               int32_t *out);
+
+/**
+ * Writes to `out` the date `bdays_count` business days after `date`,
+ * or before it if `bdays_count` is negative.
+ *
+ * # Safety
+ *
+ * `calendar` must be null or a live calendar, and `out` must be null or valid for writes.
+ */
+enum BdaysStatus bdays_advance_bdays(const struct BdaysCalendar *calendar,
+                                     int32_t date,
+                                     int32_t bdays_count,
+                                     int32_t *out);
+
+/**
+ * Writes to `out` the number of business days between `d0` and `d1`.
+ *
+ * # Safety
+ *
+ * `calendar` must be null or a live calendar, and `out` must be null or valid for writes.
+ */
+enum BdaysStatus bdays_bdays(const struct BdaysCalendar *calendar,
+                             int32_t d0,
+                             int32_t d1,
+                             int32_t *out);
+
+/**
+ * Returns a static, NUL-terminated description of `status`.
+ */
+const char *bdays_status_message(enum BdaysStatus status);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* BDAYS_H */
@@ -0,0 +1,441 @@
+use crate::{HolidayCalendar, HolidayCalendarCache};
+use crate::calendars;
+use crate::date::Date;
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+/// What to calculate for a `Request`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    /// Advances a number of business days from the request date,
+    /// such as the settlement lag of a trade.
+    Advance(i32),
+    /// Counts the business days from the request date to an end date.
+    Count(Date),
+}
+
+/// A row of a batch: a calendar code, a date and an operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub calendar: String,
+    pub date: Date,
+    pub operation: Operation,
+}
+
+/// Result of a `Request`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// The date resulting from `Operation::Advance`.
+    Date(Date),
+    /// The number of business days resulting from `Operation::Count`.
+    Bdays(i32),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Date(date) => write!(f, "{date}"),
+            Outcome::Bdays(count) => write!(f, "{count}"),
+        }
+    }
+}
+
+/// Error type for a single row of a batch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchError {
+    /// There's no built-in calendar with this code.
+    UnknownCalendar(String),
+    /// The dates involved are out of the range covered by the `BatchCalculator`.
+    OutOfRange { dt_min: Date, dt_max: Date },
+    /// A CSV row couldn't be parsed.
+    InvalidRow(String),
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BatchError::UnknownCalendar(code) => write!(f, "unknown calendar `{code}`"),
+            BatchError::OutOfRange { dt_min, dt_max } => {
+                write!(f, "dates out of the supported range [{dt_min}, {dt_max}]")
+            }
+            BatchError::InvalidRow(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl error::Error for BatchError {}
+
+/// Number of rows processed by [BatchCalculator::process_csv](BatchCalculator::process_csv).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BatchSummary {
+    pub rows: usize,
+    pub errors: usize,
+}
+
+/// Calculates many business days requests over the built-in calendars.
+///
+/// A `HolidayCalendarCache` is built for each calendar on its first use,
+/// covering the range of dates from `dt_min` to `dt_max`,
+/// and reused for every following request on that calendar.
+///
+/// ```
+/// use bdays::batch::{BatchCalculator, Operation, Outcome, Request};
+/// use bdays::date::Date;
+///
+/// let mut calculator = BatchCalculator::new(
+///     Date::from_ymd(2000, 1, 1).unwrap(),
+///     Date::from_ymd(2030, 12, 31).unwrap(),
+/// );
+///
+/// let request = Request {
+///     calendar: "BRSettlement".to_string(),
+///     date: Date::from_ymd(2025, 11, 19).unwrap(),
+///     operation: Operation::Advance(2),
+/// };
+///
+/// assert_eq!(calculator.calculate(&request), Ok(Outcome::Date(Date::from_ymd(2025, 11, 24).unwrap())));
+/// ```
+pub struct BatchCalculator {
+    dt_min: Date,
+    dt_max: Date,
+    caches: HashMap<String, HolidayCalendarCache>,
+}
+
+impl BatchCalculator {
+
+    /// Creates a `BatchCalculator` for requests in the range of dates from `dt_min` to `dt_max`.
+    pub fn new(mut dt_min: Date, mut dt_max: Date) -> Self {
+        if dt_min > dt_max {
+            (dt_min, dt_max) = (dt_max, dt_min);
+        }
+
+        BatchCalculator {
+            dt_min,
+            dt_max,
+            caches: HashMap::new(),
+        }
+    }
+
+    /// The cache of a calendar only covers the years whose holidays the calendar knows.
+    fn cache(&mut self, code: &str) -> Result<&HolidayCalendarCache, BatchError> {
+        let key = code.to_ascii_uppercase();
+
+        if !self.caches.contains_key(&key) {
+            let calendar = calendars::from_code(code)
+                .ok_or_else(|| BatchError::UnknownCalendar(code.to_string()))?;
+
+            let (dt_min, dt_max) = covered_range(&calendar, self.dt_min, self.dt_max).ok_or(BatchError::OutOfRange {
+                dt_min: self.dt_min,
+                dt_max: self.dt_max,
+            })?;
+
+            self.caches.insert(key.clone(), HolidayCalendarCache::new(calendar, dt_min, dt_max));
+        }
+
+        Ok(&self.caches[&key])
+    }
+
+    /// Calculates a single request.
+    pub fn calculate(&mut self, request: &Request) -> Result<Outcome, BatchError> {
+        let cache = self.cache(&request.calendar)?;
+
+        let out_of_range = BatchError::OutOfRange {
+            dt_min: cache.dt_min,
+            dt_max: cache.dt_max,
+        };
+
+        let outcome = match request.operation {
+            Operation::Advance(bdays_count) => cache
+                .checked_advance_bdays(request.date, bdays_count)
+                .map(Outcome::Date),
+            Operation::Count(end) => cache.checked_bdays(request.date, end).map(Outcome::Bdays),
+        };
+
+        outcome.ok_or(out_of_range)
+    }
+
+    /// Calculates every request, returning one result per request, in the same order.
+    /// Errors are reported per request and don't stop the remaining ones.
+    pub fn calculate_many(&mut self, requests: &[Request]) -> Vec<Result<Outcome, BatchError>> {
+        requests.iter().map(|request| self.calculate(request)).collect()
+    }
+
+    /// Reads requests as CSV rows from `input` and writes one CSV row with the result to `output`.
+    ///
+    /// Each input row has three fields: a calendar code, a date (`YYYY-MM-DD`),
+    /// and either a number of business days to advance or an end date to count business days to.
+    /// A header row and blank lines are skipped.
+    ///
+    /// The output has the columns `line,calendar,date,argument,result,error`, where `line`
+    /// is the line number of the row in `input`. Rows that fail have an empty `result`
+    /// and a message in `error`, and don't stop the remaining rows.
+    pub fn process_csv<R: BufRead, W: Write>(&mut self, input: R, mut output: W) -> io::Result<BatchSummary> {
+        let mut summary = BatchSummary::default();
+
+        writeln!(output, "line,calendar,date,argument,result,error")?;
+
+        for (index, line) in input.lines().enumerate() {
+            let line = line?;
+            let line_number = index + 1;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let fields = split_csv_row(&line);
+
+            if index == 0 && is_header(&fields) {
+                continue;
+            }
+
+            summary.rows += 1;
+
+            let result = parse_request(&fields).and_then(|request| self.calculate(&request));
+
+            let field = |i: usize| fields.get(i).map(String::as_str).unwrap_or_default();
+            write!(
+                output,
+                "{line_number},{},{},{},",
+                csv_field(field(0)),
+                csv_field(field(1)),
+                csv_field(field(2)),
+            )?;
+
+            match result {
+                Ok(outcome) => writeln!(output, "{outcome},")?,
+                Err(err) => {
+                    summary.errors += 1;
+                    writeln!(output, ",{}", csv_field(&err.to_string()))?;
+                }
+            }
+        }
+
+        output.flush()?;
+        Ok(summary)
+    }
+}
+
+/// Returns the range of dates needed to calculate the requests in the CSV `input`,
+/// in the format of [BatchCalculator::process_csv](BatchCalculator::process_csv),
+/// or `None` if no row is a valid request.
+///
+/// The range goes from the earliest to the latest date of the requests, including end dates,
+/// widened by twice the number of business days each request advances, to leave room for weekends.
+/// Holidays may need a wider margin.
+pub fn csv_date_range(input: &str) -> Option<(Date, Date)> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| parse_request(&split_csv_row(line)).ok())
+        .map(|request| {
+            let other = match request.operation {
+                Operation::Advance(bdays_count) => request.date.saturating_advance_days(bdays_count.saturating_mul(2)),
+                Operation::Count(end) => end,
+            };
+
+            (request.date.min(other), request.date.max(other))
+        })
+        .reduce(|(min0, max0), (min1, max1)| (min0.min(min1), max0.max(max1)))
+}
+
+/// Splits a CSV row into fields, handling double-quoted fields.
+/// Narrows the range of dates from `dt_min` to `dt_max` to the years covered by `calendar`,
+/// or returns `None` if it covers none of them. The covered years are assumed to be consecutive.
+fn covered_range<T: HolidayCalendar + ?Sized>(calendar: &T, dt_min: Date, dt_max: Date) -> Option<(Date, Date)> {
+    let first_year = (dt_min.year()..=dt_max.year()).find(|&year| calendar.covers_year(year))?;
+    let last_year = (first_year..=dt_max.year()).rev().find(|&year| calendar.covers_year(year))?;
+
+    Some((
+        dt_min.max(Date::from_ymd(first_year, 1, 1).ok()?),
+        dt_max.min(Date::from_ymd(last_year, 12, 31).ok()?),
+    ))
+}
+
+fn split_csv_row(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field).trim().to_string()),
+            _ => field.push(c),
+        }
+    }
+
+    fields.push(field.trim().to_string());
+    fields
+}
+
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+/// A first row whose date field is not a date is a header.
+fn is_header(fields: &[String]) -> bool {
+    fields.get(1).is_some_and(|field| field.parse::<Date>().is_err())
+}
+
+fn parse_request(fields: &[String]) -> Result<Request, BatchError> {
+    let [calendar, date, argument] = fields else {
+        return Err(BatchError::InvalidRow(format!("expected 3 fields, found {}", fields.len())));
+    };
+
+    let date = date
+        .parse::<Date>()
+        .map_err(|_| BatchError::InvalidRow(format!("invalid date `{date}`")))?;
+
+    let operation = if let Ok(bdays_count) = argument.parse::<i32>() {
+        Operation::Advance(bdays_count)
+    } else if let Ok(end) = argument.parse::<Date>() {
+        Operation::Count(end)
+    } else {
+        return Err(BatchError::InvalidRow(format!(
+            "invalid argument `{argument}`, expected a number of business days or an end date"
+        )));
+    };
+
+    Ok(Request {
+        calendar: calendar.clone(),
+        date,
+        operation,
+    })
+}
+
+#[test]
+fn test_calculate_many() {
+    let mut calculator = BatchCalculator::new(
+        Date::from_ymd(2030, 12, 31).unwrap(),
+        Date::from_ymd(2010, 1, 1).unwrap(),
+    );
+
+    let request = |calendar: &str, date: Date, operation: Operation| Request {
+        calendar: calendar.to_string(),
+        date,
+        operation,
+    };
+
+    let d0 = Date::from_ymd(2013, 2, 6).unwrap();
+    let d1 = Date::from_ymd(2013, 2, 14).unwrap();
+
+    let results = calculator.calculate_many(&[
+        request("BRSettlement", d0, Operation::Advance(3)),
+        request("brsettlement", d1, Operation::Advance(-4)),
+        request("BRSettlement", d0, Operation::Count(d1)),
+        request("USSettlement", d0, Operation::Count(d1)),
+        request("XX", d0, Operation::Advance(1)),
+        request("BRSettlement", Date::from_ymd(2009, 12, 31).unwrap(), Operation::Advance(1)),
+        request("BRSettlement", Date::from_ymd(2030, 12, 20).unwrap(), Operation::Advance(30)),
+        request("SSE", Date::from_ymd(2026, 12, 30).unwrap(), Operation::Advance(1)),
+        request("SSE", Date::from_ymd(2026, 12, 30).unwrap(), Operation::Advance(2)),
+    ]);
+
+    let out_of_range = Err(BatchError::OutOfRange {
+        dt_min: Date::from_ymd(2010, 1, 1).unwrap(),
+        dt_max: Date::from_ymd(2030, 12, 31).unwrap(),
+    });
+
+    assert_eq!(
+        results,
+        vec![
+            Ok(Outcome::Date(Date::from_ymd(2013, 2, 13).unwrap())),
+            Ok(Outcome::Date(d0)),
+            Ok(Outcome::Bdays(4)),
+            Ok(Outcome::Bdays(6)),
+            Err(BatchError::UnknownCalendar("XX".to_string())),
+            out_of_range.clone(),
+            out_of_range,
+            Ok(Outcome::Date(Date::from_ymd(2026, 12, 31).unwrap())),
+            // the holidays of the exchange are only known up to 2026
+            Err(BatchError::OutOfRange {
+                dt_min: Date::from_ymd(2010, 1, 1).unwrap(),
+                dt_max: Date::from_ymd(2026, 12, 31).unwrap(),
+            }),
+        ]
+    );
+
+    // one cache per calendar, regardless of the case of the code
+    assert_eq!(calculator.caches.len(), 3);
+
+    // no year known by the calendar
+    let mut calculator = BatchCalculator::new(
+        Date::from_ymd(2030, 1, 1).unwrap(),
+        Date::from_ymd(2030, 12, 31).unwrap(),
+    );
+    assert_eq!(
+        calculator.calculate(&request("CFETS", Date::from_ymd(2030, 6, 3).unwrap(), Operation::Advance(1))),
+        Err(BatchError::OutOfRange {
+            dt_min: Date::from_ymd(2030, 1, 1).unwrap(),
+            dt_max: Date::from_ymd(2030, 12, 31).unwrap(),
+        })
+    );
+}
+
+#[test]
+fn test_process_csv() {
+    let input = "\
+calendar,trade_date,lag
+BRSettlement,2025-11-19,2
+
+USSettlement,2025-11-26,1
+USSettlement,2025-11-26,2025-12-05
+\"DE-BY\",2026-01-02,-1
+XX,2025-01-01,1
+BRSettlement,2025-13-01,1
+BRSettlement,2025-01-01,soon
+BRSettlement,2025-01-01
+BRSettlement,1999-01-01,1
+";
+
+    let mut calculator = BatchCalculator::new(
+        Date::from_ymd(2000, 1, 1).unwrap(),
+        Date::from_ymd(2030, 12, 31).unwrap(),
+    );
+
+    let mut output: Vec<u8> = Vec::new();
+    let summary = calculator.process_csv(input.as_bytes(), &mut output).unwrap();
+
+    assert_eq!(summary, BatchSummary { rows: 9, errors: 5 });
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "\
+line,calendar,date,argument,result,error
+2,BRSettlement,2025-11-19,2,2025-11-24,
+4,USSettlement,2025-11-26,1,2025-11-28,
+5,USSettlement,2025-11-26,2025-12-05,6,
+6,DE-BY,2026-01-02,-1,2025-12-31,
+7,XX,2025-01-01,1,,unknown calendar `XX`
+8,BRSettlement,2025-13-01,1,,invalid date `2025-13-01`
+9,BRSettlement,2025-01-01,soon,,\"invalid argument `soon`, expected a number of business days or an end date\"
+10,BRSettlement,2025-01-01,,,\"expected 3 fields, found 2\"
+11,BRSettlement,1999-01-01,1,,\"dates out of the supported range [2000-01-01, 2030-12-31]\"
+"
+    );
+}
+
+#[test]
+fn test_csv_date_range() {
+    let input = "calendar,date,argument\nBRSettlement,2025-11-19,2\n\nUSSettlement,2025-11-26,2025-01-05\nXX,2025-11-26,-10\nBR,x,1\n";
+    assert_eq!(
+        csv_date_range(input),
+        Some((Date::from_ymd(2025, 1, 5).unwrap(), Date::from_ymd(2025, 11, 26).unwrap()))
+    );
+
+    assert_eq!(
+        csv_date_range("BRSettlement,2025-11-19,-5"),
+        Some((Date::from_ymd(2025, 11, 9).unwrap(), Date::from_ymd(2025, 11, 19).unwrap()))
+    );
+    assert_eq!(csv_date_range("calendar,date,argument\n"), None);
+}
@@ -0,0 +1,677 @@
+//! Command-line tool for business days calculation with the built-in holiday calendars.
+
+use bdays::batch::{self, BatchCalculator};
+use bdays::date::Date;
+use bdays::{HolidayCalendar, HolidayCalendarCache};
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::process::ExitCode;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const USAGE: &str = "\
+Usage: bdays <COMMAND> [OPTIONS] [ARGS]
+
+Commands:
+  is-bday <CALENDAR> <DATE>            Checks if DATE is a business day
+  advance <CALENDAR> <DATE> <N>        Advances N business days from DATE
+  count <CALENDAR> <FROM> <TO>         Counts business days between FROM and TO
+  adjust <CALENDAR> <DATE>             Adjusts DATE to the next business day
+  holidays <CALENDAR> <YEAR>           Lists holidays in YEAR
+  holidays <CALENDAR> <FROM> <TO>      Lists holidays from FROM to TO
+  calendars                            Lists the available calendars
+  batch [FILE]                         Calculates CSV rows from FILE, or stdin,
+                                       with columns CALENDAR,DATE,N or CALENDAR,FROM,TO
+
+Options:
+  -f, --format <FORMAT>   Output format: text, json or csv [default: text]
+      --previous          adjust: adjusts to the previous business day instead
+  -h, --help              Prints this message
+
+Dates are written as YYYY-MM-DD, or `today`.
+
+Exit status is 0 on success, 1 if `is-bday` finds that DATE is not a business day
+or if any row of `batch` fails, and 2 on invalid arguments.
+";
+
+/// Exit status when `is-bday` finds that the date is not a business day.
+const EXIT_NOT_BDAY: u8 = 1;
+
+/// Exit status when any row of `batch` fails.
+const EXIT_ROW_ERRORS: u8 = 1;
+
+/// Exit status on invalid arguments.
+const EXIT_USAGE: u8 = 2;
+
+/// The calendar caches cover this number of days before and after the dates in a query.
+const CACHE_MARGIN_DAYS: i32 = 366;
+
+/// Supported range of years. Easter-based calendars can't go before 1583.
+const MIN_YEAR: i32 = 1583;
+const MAX_YEAR: i32 = 9999;
+
+#[derive(Debug)]
+enum CliError {
+    /// Invalid command line, prints usage.
+    Usage(String),
+    /// Valid command line with invalid values.
+    Input(String),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CliError::Usage(msg) | CliError::Input(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Value {
+    Text(String),
+    Integer(i64),
+    Bool(bool),
+    Null,
+}
+
+impl Value {
+    fn date(date: Date) -> Self {
+        Value::Text(date.to_string())
+    }
+
+    fn optional_text(text: Option<&str>) -> Self {
+        text.map_or(Value::Null, |text| Value::Text(text.to_string()))
+    }
+
+    fn write_text<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        match self {
+            Value::Text(text) => write!(out, "{text}"),
+            Value::Integer(number) => write!(out, "{number}"),
+            Value::Bool(value) => write!(out, "{value}"),
+            Value::Null => Ok(()),
+        }
+    }
+
+    fn write_json<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        match self {
+            Value::Text(text) => {
+                write!(out, "\"")?;
+                for c in text.chars() {
+                    match c {
+                        '"' => write!(out, "\\\"")?,
+                        '\\' => write!(out, "\\\\")?,
+                        '\n' => write!(out, "\\n")?,
+                        '\r' => write!(out, "\\r")?,
+                        '\t' => write!(out, "\\t")?,
+                        c if c.is_control() => write!(out, "\\u{:04x}", c as u32)?,
+                        c => write!(out, "{c}")?,
+                    }
+                }
+                write!(out, "\"")
+            }
+            Value::Integer(number) => write!(out, "{number}"),
+            Value::Bool(value) => write!(out, "{value}"),
+            Value::Null => write!(out, "null"),
+        }
+    }
+
+    fn write_csv<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        match self {
+            Value::Text(text) if text.contains([',', '"', '\n', '\r']) => {
+                write!(out, "\"{}\"", text.replace('"', "\"\""))
+            }
+            other => other.write_text(out),
+        }
+    }
+}
+
+/// Result of a command.
+struct Table {
+    columns: Vec<&'static str>,
+    rows: Vec<Vec<Value>>,
+    /// Commands that answer a single question print an object instead of an array in JSON.
+    single: bool,
+    /// Columns printed in text format.
+    text_columns: Vec<usize>,
+}
+
+impl Table {
+    fn single(columns: Vec<&'static str>, row: Vec<Value>, text_column: usize) -> Self {
+        Table {
+            columns,
+            rows: vec![row],
+            single: true,
+            text_columns: vec![text_column],
+        }
+    }
+
+    fn write<W: Write>(&self, out: &mut W, format: Format) -> io::Result<()> {
+        match format {
+            Format::Text => {
+                for row in &self.rows {
+                    for (i, column) in self.text_columns.iter().enumerate() {
+                        if i > 0 {
+                            write!(out, "\t")?;
+                        }
+                        row[*column].write_text(out)?;
+                    }
+                    writeln!(out)?;
+                }
+            }
+            Format::Json => {
+                if !self.single {
+                    write!(out, "[")?;
+                }
+
+                for (i, row) in self.rows.iter().enumerate() {
+                    if i > 0 {
+                        write!(out, ",")?;
+                    }
+
+                    write!(out, "{{")?;
+                    for (j, (column, value)) in self.columns.iter().zip(row).enumerate() {
+                        if j > 0 {
+                            write!(out, ",")?;
+                        }
+                        write!(out, "\"{column}\":")?;
+                        value.write_json(out)?;
+                    }
+                    write!(out, "}}")?;
+                }
+
+                if !self.single {
+                    write!(out, "]")?;
+                }
+                writeln!(out)?;
+            }
+            Format::Csv => {
+                writeln!(out, "{}", self.columns.join(","))?;
+                for row in &self.rows {
+                    for (i, value) in row.iter().enumerate() {
+                        if i > 0 {
+                            write!(out, ",")?;
+                        }
+                        value.write_csv(out)?;
+                    }
+                    writeln!(out)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the current date in UTC.
+fn today() -> Date {
+    // 1970-01-01 is day 719163 from the common era
+    const UNIX_EPOCH_DAYS_FROM_CE: i64 = 719163;
+
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("System clock is after 1970")
+        .as_secs() as i64;
+
+    Date::from_num_days_from_ce((UNIX_EPOCH_DAYS_FROM_CE + seconds / 86_400) as i32)
+}
+
+fn check_year(date: Date) -> Result<Date, CliError> {
+    if (MIN_YEAR..=MAX_YEAR).contains(&date.year()) {
+        Ok(date)
+    } else {
+        Err(CliError::Input(format!(
+            "date {date} is out of the supported range of years {MIN_YEAR} to {MAX_YEAR}"
+        )))
+    }
+}
+
+fn parse_date(text: &str) -> Result<Date, CliError> {
+    let date = if text == "today" {
+        today()
+    } else {
+        text.parse::<Date>()
+            .map_err(|_| CliError::Input(format!("invalid date `{text}`, expected YYYY-MM-DD")))?
+    };
+
+    check_year(date)
+}
+
+fn parse_integer(text: &str, what: &str) -> Result<i32, CliError> {
+    text.parse::<i32>()
+        .map_err(|_| CliError::Input(format!("invalid {what} `{text}`")))
+}
+
+fn calendar(code: &str) -> Result<Box<dyn HolidayCalendar + Send + Sync>, CliError> {
+    bdays::calendars::from_code(code).ok_or_else(|| {
+        CliError::Input(format!("unknown calendar `{code}`, run `bdays calendars` to list them"))
+    })
+}
+
+/// Fails unless the calendar `code` knows the holidays in every year from `d0` to `d1`.
+fn check_covered(code: &str, d0: Date, d1: Date) -> Result<(), CliError> {
+    let calendar = calendar(code)?;
+    let (from, to) = if d0 <= d1 { (d0, d1) } else { (d1, d0) };
+
+    match (from.year()..=to.year()).find(|&year| !calendar.covers_year(year)) {
+        Some(year) => Err(CliError::Input(format!(
+            "year {year} is out of the range of years known by calendar `{code}`"
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Caches `code` between `d0` and `d1`, with a margin for adjustments to business days.
+fn cached_calendar(code: &str, d0: Date, d1: Date) -> Result<HolidayCalendarCache, CliError> {
+    let calendar = calendar(code)?;
+    let (from, to) = if d0 <= d1 { (d0, d1) } else { (d1, d0) };
+
+    Ok(HolidayCalendarCache::new(
+        calendar,
+        from.advance_days(-CACHE_MARGIN_DAYS),
+        to.advance_days(CACHE_MARGIN_DAYS),
+    ))
+}
+
+/// Runs the `batch` command, reading from `path` or from stdin if `path` is `None` or `-`.
+/// The calendar caches cover the dates in the input, with a margin.
+fn run_batch<W: Write>(path: Option<&str>, out: &mut W) -> Result<u8, CliError> {
+    let input = match path {
+        None | Some("-") => io::read_to_string(io::stdin().lock())
+            .map_err(|err| CliError::Input(err.to_string()))?,
+        Some(path) => fs::read_to_string(path)
+            .map_err(|err| CliError::Input(format!("couldn't open `{path}`: {err}")))?,
+    };
+
+    let min = Date::from_ymd(MIN_YEAR, 1, 1).expect("Valid date");
+    let max = Date::from_ymd(MAX_YEAR, 12, 31).expect("Valid date");
+
+    // rows out of the range of the input fail anyway, so any range will do without valid rows
+    let (from, to) = batch::csv_date_range(&input).unwrap_or((min, min));
+
+    let mut calculator = BatchCalculator::new(
+        from.saturating_advance_days(-CACHE_MARGIN_DAYS).clamp(min, max),
+        to.saturating_advance_days(CACHE_MARGIN_DAYS).clamp(min, max),
+    );
+
+    let summary = calculator
+        .process_csv(input.as_bytes(), out)
+        .map_err(|err| CliError::Input(err.to_string()))?;
+
+    if summary.errors > 0 {
+        eprintln!("bdays: {} of {} rows failed", summary.errors, summary.rows);
+        Ok(EXIT_ROW_ERRORS)
+    } else {
+        Ok(0)
+    }
+}
+
+/// Parsed command line.
+struct Args {
+    format: Format,
+    adjust_next: bool,
+    help: bool,
+    positional: Vec<String>,
+}
+
+fn parse_args(args: &[String]) -> Result<Args, CliError> {
+    let mut parsed = Args {
+        format: Format::Text,
+        adjust_next: true,
+        help: false,
+        positional: Vec::new(),
+    };
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let format_value = if arg == "-f" || arg == "--format" {
+            Some(
+                iter.next()
+                    .ok_or_else(|| CliError::Usage(format!("missing value for {arg}")))?
+                    .as_str(),
+            )
+        } else {
+            arg.strip_prefix("--format=")
+        };
+
+        if let Some(format) = format_value {
+            parsed.format = match format {
+                "text" => Format::Text,
+                "json" => Format::Json,
+                "csv" => Format::Csv,
+                _ => return Err(CliError::Usage(format!("invalid format `{format}`"))),
+            };
+        } else if arg == "--previous" {
+            parsed.adjust_next = false;
+        } else if arg == "--next" {
+            parsed.adjust_next = true;
+        } else if arg == "-h" || arg == "--help" {
+            parsed.help = true;
+        } else if arg.starts_with('-') && arg.len() > 1 && arg.parse::<i32>().is_err() {
+            return Err(CliError::Usage(format!("unknown option `{arg}`")));
+        } else {
+            parsed.positional.push(arg.clone());
+        }
+    }
+
+    Ok(parsed)
+}
+
+/// Runs the command line `args`, without the program name, writing results to `out`.
+/// Returns the exit status.
+fn run<W: Write>(args: &[String], out: &mut W) -> Result<u8, CliError> {
+    let args = parse_args(args)?;
+
+    if args.help {
+        write!(out, "{USAGE}").map_err(|err| CliError::Input(err.to_string()))?;
+        return Ok(0);
+    }
+
+    let Some((command, operands)) = args.positional.split_first() else {
+        return Err(CliError::Usage("missing command".to_string()));
+    };
+
+    let operands: Vec<&str> = operands.iter().map(String::as_str).collect();
+    let mut status = 0;
+
+    // batch always writes CSV, row by row
+    match (command.as_str(), operands.as_slice()) {
+        ("batch", []) => return run_batch(None, out),
+        ("batch", [path]) => return run_batch(Some(path), out),
+        ("batch", _) => return Err(CliError::Usage("wrong number of arguments for `batch`".to_string())),
+        _ => {}
+    }
+
+    let table = match (command.as_str(), operands.as_slice()) {
+        ("is-bday", [code, date]) => {
+            let cal = calendar(code)?;
+            let date = parse_date(date)?;
+            check_covered(code, date, date)?;
+            let is_bday = cal.is_bday(date);
+
+            if !is_bday {
+                status = EXIT_NOT_BDAY;
+            }
+
+            Table::single(
+                vec!["calendar", "date", "is_bday", "holiday"],
+                vec![
+                    Value::Text(code.to_string()),
+                    Value::date(date),
+                    Value::Bool(is_bday),
+                    Value::optional_text(cal.holiday_name(date)),
+                ],
+                2,
+            )
+        }
+        ("advance", [code, date, count]) => {
+            let date = parse_date(date)?;
+            let count = parse_integer(count, "number of business days")?;
+
+            // a business day is at most a few calendar days apart from the next one
+            let span = count
+                .checked_mul(2)
+                .and_then(|span| date.checked_advance_days(span))
+                .ok_or_else(|| CliError::Input(format!("too many business days `{count}`")))?;
+
+            let cal = cached_calendar(code, date, check_year(span)?)?;
+            let result = cal.advance_bdays(date, count);
+            check_covered(code, date, result)?;
+
+            Table::single(
+                vec!["calendar", "date", "bdays", "result"],
+                vec![
+                    Value::Text(code.to_string()),
+                    Value::date(date),
+                    Value::Integer(count as i64),
+                    Value::date(result),
+                ],
+                3,
+            )
+        }
+        ("count", [code, from, to]) => {
+            let from = parse_date(from)?;
+            let to = parse_date(to)?;
+            check_covered(code, from, to)?;
+            let cal = cached_calendar(code, from, to)?;
+
+            Table::single(
+                vec!["calendar", "from", "to", "bdays"],
+                vec![
+                    Value::Text(code.to_string()),
+                    Value::date(from),
+                    Value::date(to),
+                    Value::Integer(cal.bdays(from, to) as i64),
+                ],
+                3,
+            )
+        }
+        ("adjust", [code, date]) => {
+            let date = parse_date(date)?;
+            let cal = cached_calendar(code, date, date)?;
+            let direction = if args.adjust_next { "next" } else { "previous" };
+            let result = cal.to_bday(date, args.adjust_next);
+            check_covered(code, date, result)?;
+
+            Table::single(
+                vec!["calendar", "date", "direction", "result"],
+                vec![
+                    Value::Text(code.to_string()),
+                    Value::date(date),
+                    Value::Text(direction.to_string()),
+                    Value::date(result),
+                ],
+                3,
+            )
+        }
+        ("holidays", [code, range @ ..]) if range.len() == 1 || range.len() == 2 => {
+            let cal = calendar(code)?;
+
+            let (from, to) = if let [year] = range {
+                let year = parse_integer(year, "year")?;
+                let from = Date::from_ymd(year, 1, 1)
+                    .map_err(|_| CliError::Input(format!("invalid year `{year}`")))?;
+                let to = Date::from_ymd(year, 12, 31)
+                    .map_err(|_| CliError::Input(format!("invalid year `{year}`")))?;
+                (check_year(from)?, to)
+            } else {
+                (parse_date(range[0])?, parse_date(range[1])?)
+            };
+            check_covered(code, from, to)?;
+
+            let mut rows = Vec::new();
+            let mut date = from;
+            while date <= to {
+                if cal.is_holiday(date) {
+                    rows.push(vec![
+                        Value::date(date),
+                        Value::Text(date.weekday().to_string()),
+                        Value::optional_text(cal.holiday_name(date)),
+                    ]);
+                }
+                date = date.next_date();
+            }
+
+            Table {
+                columns: vec!["date", "weekday", "name"],
+                rows,
+                single: false,
+                text_columns: vec![0, 2],
+            }
+        }
+        ("calendars", []) => Table {
+            columns: vec!["code", "description"],
+            rows: bdays::calendars::CALENDAR_CODES
+                .iter()
+                .map(|(code, description)| {
+                    vec![Value::Text(code.to_string()), Value::Text(description.to_string())]
+                })
+                .collect(),
+            single: false,
+            text_columns: vec![0, 1],
+        },
+        ("is-bday" | "advance" | "count" | "adjust" | "holidays" | "calendars", _) => {
+            return Err(CliError::Usage(format!("wrong number of arguments for `{command}`")));
+        }
+        _ => return Err(CliError::Usage(format!("unknown command `{command}`"))),
+    };
+
+    table
+        .write(out, args.format)
+        .map_err(|err| CliError::Input(err.to_string()))?;
+
+    Ok(status)
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let stdout = io::stdout();
+
+    match run(&args, &mut stdout.lock()) {
+        Ok(status) => ExitCode::from(status),
+        Err(err) => {
+            eprintln!("bdays: {err}");
+            if let CliError::Usage(_) = err {
+                eprintln!();
+                eprint!("{USAGE}");
+            }
+            ExitCode::from(EXIT_USAGE)
+        }
+    }
+}
+
+#[cfg(test)]
+fn run_with(args: &str) -> (Result<u8, String>, String) {
+    let args: Vec<String> = args.split_whitespace().map(String::from).collect();
+    let mut out: Vec<u8> = Vec::new();
+    let result = run(&args, &mut out).map_err(|err| err.to_string());
+    (result, String::from_utf8(out).unwrap())
+}
+
+#[test]
+fn test_is_bday() {
+    assert_eq!(run_with("is-bday BRSettlement 2025-11-20"), (Ok(1), "false\n".to_string()));
+    assert_eq!(run_with("is-bday brsettlement 2025-11-21"), (Ok(0), "true\n".to_string()));
+    assert_eq!(
+        run_with("is-bday BRSettlement 2025-11-20 --format json"),
+        (
+            Ok(1),
+            "{\"calendar\":\"BRSettlement\",\"date\":\"2025-11-20\",\"is_bday\":false,\"holiday\":\"Dia Nacional de Zumbi e da Consciência Negra\"}\n".to_string()
+        )
+    );
+    assert_eq!(
+        run_with("-f csv is-bday USSettlement 2025-11-21"),
+        (Ok(0), "calendar,date,is_bday,holiday\nUSSettlement,2025-11-21,true,\n".to_string())
+    );
+}
+
+#[test]
+fn test_advance_count_adjust() {
+    assert_eq!(run_with("advance USSettlement 2025-11-20 10"), (Ok(0), "2025-12-05\n".to_string()));
+    assert_eq!(run_with("advance USSettlement 2025-12-05 -10"), (Ok(0), "2025-11-20\n".to_string()));
+    assert_eq!(run_with("count BRSettlement 2013-02-06 2013-02-14"), (Ok(0), "4\n".to_string()));
+    assert_eq!(run_with("count BRSettlement 2013-02-14 2013-02-06"), (Ok(0), "-4\n".to_string()));
+    assert_eq!(run_with("adjust BRSettlement 2013-02-09"), (Ok(0), "2013-02-13\n".to_string()));
+    assert_eq!(run_with("adjust BRSettlement 2013-02-12 --previous"), (Ok(0), "2013-02-08\n".to_string()));
+    assert_eq!(
+        run_with("count WeekendsOnly 2025-01-01 2025-01-08 --format=csv"),
+        (Ok(0), "calendar,from,to,bdays\nWeekendsOnly,2025-01-01,2025-01-08,5\n".to_string())
+    );
+
+    let (result, output) = run_with("advance WeekendsOnly today 0");
+    assert_eq!(result, Ok(0));
+    assert_eq!(output.trim().parse::<Date>().map(|date| date.year() >= 2025), Ok(true));
+}
+
+#[test]
+fn test_holidays_and_calendars() {
+    let (result, output) = run_with("holidays DE-BY 2026");
+    assert_eq!(result, Ok(0));
+    assert_eq!(output.lines().count(), 13);
+    assert_eq!(output.lines().next(), Some("2026-01-01\tNeujahrstag"));
+
+    assert_eq!(
+        run_with("holidays USSettlement 2021-12-20 2022-01-10 -f json"),
+        (
+            Ok(0),
+            "[{\"date\":\"2021-12-24\",\"weekday\":\"Friday\",\"name\":\"Christmas Day\"},\
+             {\"date\":\"2021-12-31\",\"weekday\":\"Friday\",\"name\":\"New Year's Day\"}]\n".to_string()
+        )
+    );
+
+    assert_eq!(
+        run_with("holidays USSettlement 2022-01-17 2022-01-17 -f csv"),
+        (Ok(0), "date,weekday,name\n2022-01-17,Monday,\"Birthday of Martin Luther King, Jr.\"\n".to_string())
+    );
+
+    let (result, output) = run_with("calendars");
+    assert_eq!(result, Ok(0));
+    assert_eq!(output.lines().count(), bdays::calendars::CALENDAR_CODES.len());
+    assert!(output.contains("BRSettlement\tBrazilian banking holidays\n"));
+}
+
+#[test]
+fn test_batch() {
+    let path = std::env::temp_dir().join(format!("bdays-batch-{}.csv", std::process::id()));
+    std::fs::write(&path, "BRSettlement,2025-11-19,2\nUSSettlement,2025-11-26,2025-12-05\n").unwrap();
+
+    let (result, output) = run_with(&format!("batch {}", path.display()));
+    assert_eq!(result, Ok(0));
+    assert_eq!(
+        output,
+        "line,calendar,date,argument,result,error\n1,BRSettlement,2025-11-19,2,2025-11-24,\n2,USSettlement,2025-11-26,2025-12-05,6,\n"
+    );
+
+    // the caches cover the dates in the input, with a margin
+    std::fs::write(&path, "USSettlement,1600-01-03,1\n").unwrap();
+    let (result, output) = run_with(&format!("batch {}", path.display()));
+    assert_eq!(result, Ok(0));
+    assert!(output.ends_with("\n1,USSettlement,1600-01-03,1,1600-01-04,\n"), "{output}");
+
+    std::fs::write(&path, "USSettlement,9999-12-30,5\n").unwrap();
+    let (result, output) = run_with(&format!("batch {}", path.display()));
+    assert_eq!(result, Ok(EXIT_ROW_ERRORS));
+    assert!(output.ends_with(",,\"dates out of the supported range [9998-12-29, 9999-12-31]\"\n"), "{output}");
+
+    std::fs::write(&path, "BRSettlement,2025-11-19,2\nXX,2025-11-26,1\n").unwrap();
+    assert_eq!(run_with(&format!("batch {}", path.display())).0, Ok(EXIT_ROW_ERRORS));
+
+    std::fs::remove_file(&path).unwrap();
+    assert!(run_with(&format!("batch {}", path.display())).0.is_err());
+}
+
+#[test]
+fn test_errors() {
+    let error = |args: &str| {
+        let args: Vec<String> = args.split_whitespace().map(String::from).collect();
+        run(&args, &mut Vec::new()).unwrap_err()
+    };
+
+    assert!(matches!(error(""), CliError::Usage(_)));
+    assert!(matches!(error("frobnicate"), CliError::Usage(_)));
+    assert!(matches!(error("count BRSettlement 2025-01-01"), CliError::Usage(_)));
+    assert!(matches!(error("calendars --verbose"), CliError::Usage(_)));
+    assert!(matches!(error("calendars -f xml"), CliError::Usage(_)));
+    assert!(matches!(error("is-bday XX 2025-01-01"), CliError::Input(_)));
+    assert!(matches!(error("is-bday BRSettlement 2025-02-30"), CliError::Input(_)));
+    assert!(matches!(error("is-bday BRSettlement 1500-01-01"), CliError::Input(_)));
+    assert!(matches!(error("advance BRSettlement 2025-01-01 x"), CliError::Input(_)));
+    assert!(matches!(error("advance USSettlement 2025-01-01 1073000000"), CliError::Input(_)));
+    assert!(matches!(error("holidays BRSettlement 99999"), CliError::Input(_)));
+
+    // the holidays of the exchange are only known up to 2026
+    assert!(matches!(error("is-bday SSE 2027-10-01"), CliError::Input(_)));
+    assert!(matches!(error("advance SSE 2026-12-30 2"), CliError::Input(_)));
+    assert!(matches!(error("count CFETS 2026-01-05 2027-01-05"), CliError::Input(_)));
+    assert!(matches!(error("adjust SSE 2027-01-04"), CliError::Input(_)));
+    assert!(matches!(error("holidays SSE 2007"), CliError::Input(_)));
+    assert_eq!(run_with("advance SSE 2026-12-30 1").0, Ok(0));
+
+    assert_eq!(run_with("--help").0, Ok(0));
+}
@@ -0,0 +1,107 @@
+use crate::HolidayCalendar;
+use crate::calendars::sa;
+use crate::date::{Date, Weekday};
+use crate::hijri::{HijriCalendar, HijriError};
+
+/// United Arab Emirates public holidays for the private sector, on which AED payments don't settle.
+///
+/// The weekend is Thursday and Friday until 31 August 2006,
+/// Friday and Saturday until 31 December 2021, and Saturday and Sunday since 2022.
+/// Holidays moved by the Cabinet to make longer weekends are not modelled.
+///
+/// Hijri dates follow the month starts announced from Ramadan 1438 AH (2017)
+/// to Dhu al-Hijjah 1447 AH (2026), which were the same as in Saudi Arabia,
+/// and the tabular Islamic calendar otherwise, which may be a day or two apart from the announced dates.
+/// Further announcements can be added with [UAESettlement::with_month_start].
+///
+/// ```
+/// use bdays::HolidayCalendar;
+/// use bdays::calendars::ae::UAESettlement;
+/// use bdays::date::Date;
+///
+/// let cal = UAESettlement::new();
+/// assert_eq!(cal.holiday_name(Date::from_ymd(2024, 6, 15).unwrap()), Some("Arafat Day"));
+/// assert_eq!(cal.holiday_name(Date::from_ymd(2024, 12, 2).unwrap()), Some("National Day"));
+///
+/// // Friday was a weekend day until 2021
+/// assert!(!cal.is_bday(Date::from_ymd(2021, 12, 10).unwrap()));
+/// assert!(cal.is_bday(Date::from_ymd(2022, 1, 7).unwrap()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UAESettlement {
+    hijri: HijriCalendar,
+}
+
+impl UAESettlement {
+    /// Creates the calendar with the month starts announced from 2017 to 2026.
+    pub fn new() -> Self {
+        UAESettlement {
+            hijri: sa::announced_hijri_calendar(),
+        }
+    }
+
+    /// Sets the first day of `month` of Hijri `year`, as announced.
+    /// See [HijriCalendar::with_month_start].
+    pub fn with_month_start(self, year: i32, month: i32, date: Date) -> Result<Self, HijriError> {
+        Ok(UAESettlement {
+            hijri: self.hijri.with_month_start(year, month, date)?,
+        })
+    }
+
+    /// Returns the Hijri calendar used for the Islamic holidays.
+    pub fn hijri_calendar(&self) -> &HijriCalendar {
+        &self.hijri
+    }
+
+    fn uae_holiday_name(&self, date: Date) -> Option<&'static str> {
+        let (yy, mm, dd) = date.to_ymd();
+
+        match (mm, dd) {
+            (1, 1) => return Some("New Year's Day"),
+            (11, 30) if (2015..=2018).contains(&yy) => return Some("Commemoration Day"),
+            (12, 1) if yy >= 2019 => return Some("Commemoration Day"),
+            (12, 2) | (12, 3) => return Some("National Day"),
+            _ => {}
+        }
+
+        let hijri = self.hijri.to_hijri(date).ok()?;
+
+        match (hijri.month, hijri.day) {
+            (1, 1) => Some("Islamic New Year"),
+            (3, 12) => Some("Prophet's Birthday"),
+            (7, 27) if yy <= 2018 => Some("Isra and Mi'raj"),
+            // from 29 Ramadan since 2019
+            (9, 29..=30) if yy >= 2019 => Some("Eid al-Fitr"),
+            (10, 1..=3) => Some("Eid al-Fitr"),
+            (12, 9) => Some("Arafat Day"),
+            (12, 10..=12) => Some("Eid al-Adha"),
+            _ => None,
+        }
+    }
+}
+
+impl Default for UAESettlement {
+    fn default() -> Self {
+        UAESettlement::new()
+    }
+}
+
+impl HolidayCalendar for UAESettlement {
+    fn is_holiday(&self, date: Date) -> bool {
+        self.uae_holiday_name(date).is_some()
+    }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        self.uae_holiday_name(date)
+    }
+
+    fn is_weekend(&self, date: Date) -> bool {
+        match date.weekday() {
+            Weekday::Thursday => date < Date::from_ymd_const(2006, 9, 1),
+            Weekday::Friday => date < Date::from_ymd_const(2022, 1, 1),
+            Weekday::Saturday => date >= Date::from_ymd_const(2006, 9, 1),
+            Weekday::Sunday => date >= Date::from_ymd_const(2022, 1, 1),
+            _ => false,
+        }
+    }
+}
@@ -7,108 +7,787 @@ use crate::date::{Date, Weekday};
 pub struct BRSettlement;
 
 fn is_brazilian_national_holiday(date: Date) -> bool {
+    brazilian_national_holiday_name(date).is_some()
+}
+
+fn brazilian_national_holiday_name(date: Date) -> Option<&'static str> {
     let (yy, mm, dd) = date.to_ymd();
 
     // Bisection
     if mm >= 8 {
         // Fixed holidays
-        if
-        // Independencia do Brasil
-        ((mm == 9) && (dd == 7))
-            ||
-            // Nossa Senhora Aparecida
-            ((mm == 10) && (dd == 12))
-            ||
-            // Finados
-            ((mm == 11) && (dd == 2))
-            ||
-            // Proclamacao da Republica
-            ((mm == 11) && (dd == 15))
-            ||
-            // Dia Nacional de Zumbi e da Consciência Negra
-            ((mm == 11) && (dd == 20) && (yy > 2023))
-            ||
-            // Natal
-            ((mm == 12) && (dd == 25))
-        {
-            return true;
+        match (mm, dd) {
+            (9, 7) => Some("Independência do Brasil"),
+            (10, 12) => Some("Nossa Senhora Aparecida"),
+            (11, 2) => Some("Finados"),
+            (11, 15) => Some("Proclamação da República"),
+            (11, 20) if yy > 2023 => Some("Dia Nacional de Zumbi e da Consciência Negra"),
+            (12, 25) => Some("Natal"),
+            _ => None,
         }
     } else {
         // mm < 8
         // Fixed holidays
-        if
-        // Confraternizacao Universal
-        ((mm == 1) && (dd == 1))
-            ||
-            // Tiradentes
-            ((mm == 4) && (dd == 21))
-            ||
-            // Dia do Trabalho
-            ((mm == 5) && (dd == 1))
-        {
-            return true;
+        match (mm, dd) {
+            (1, 1) => return Some("Confraternização Universal"),
+            (4, 21) => return Some("Tiradentes"),
+            (5, 1) => return Some("Dia do Trabalho"),
+            _ => {}
         }
 
         // Easter occurs up to April, so Corpus Christi will be up to July in the worst case, which is before August (mm < 8).
         // Holidays based on easter date.
         let dt_rata = date.num_days_from_ce();
-        let e_rata = easter::easter_num_days_from_ce(yy).unwrap();
-
-        if
-        // Segunda de Carnaval
-        ( dt_rata == ( e_rata - 48 ) )
-            ||
-            // Terca de Carnaval
-            ( dt_rata == ( e_rata - 47 ) )
-            ||
-            // Sexta-feira Santa
-            ( dt_rata == ( e_rata -  2 ) )
-            ||
-            // Corpus Christi
-            ( dt_rata == ( e_rata + 60 ) )
-        {
-            return true;
+        let e_rata = easter::easter_num_days_from_ce(yy).ok()?;
+
+        match dt_rata - e_rata {
+            -48 => Some("Segunda-feira de Carnaval"),
+            -47 => Some("Terça-feira de Carnaval"),
+            -2 => Some("Sexta-feira Santa"),
+            60 => Some("Corpus Christi"),
+            _ => None,
         }
     }
+}
+
+/// Dates in `yy` that may be a brazilian national holiday, checked by `brazilian_national_holiday_name`.
+fn brazilian_national_holiday_candidates(yy: i32) -> Vec<Date> {
+    let mut dates: Vec<Date> = [(1, 1), (4, 21), (5, 1), (9, 7), (10, 12), (11, 2), (11, 15), (11, 20), (12, 25)]
+        .into_iter()
+        .map(|(mm, dd)| Date::from_ymd(yy, mm, dd).expect("Valid date"))
+        .collect();
 
-    false
+    if let Ok(easter_date) = easter::easter_date(yy) {
+        dates.extend([-48, -47, -2, 60].map(|offset| easter_date.advance_days(offset)));
+    }
+
+    dates
 }
 
 impl HolidayCalendar for BRSettlement {
     fn is_holiday(&self, date: Date) -> bool {
         is_brazilian_national_holiday(date)
     }
+
+    fn holidays_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        let mut dates = brazilian_national_holiday_candidates(year);
+        dates.retain(|&date| is_brazilian_national_holiday(date));
+        Some(dates)
+    }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        brazilian_national_holiday_name(date)
+    }
 }
 
 /// B3 Exchange holidays (<https://www.b3.com.br>).
 pub struct BrazilExchange;
 
+fn brazil_exchange_holiday_name(date: Date) -> Option<&'static str> {
+    let (yy, mm, dd) = date.to_ymd();
+
+    if mm == 1 && dd == 25 && yy < 2022 {
+        Some("Aniversário de São Paulo")
+    } else if mm == 7 && dd == 9 && yy != 2020 && yy < 2022 {
+        Some("Revolução Constitucionalista")
+    } else if yy >= 2007 && mm == 11 && dd == 20 && yy != 2020 && yy < 2022 {
+        // since 2007
+        Some("Dia da Consciência Negra")
+    } else if mm == 12 && dd == 24 {
+        Some("Véspera de Natal")
+    } else if mm == 12 && (dd == 31 || (dd >= 29 && date.weekday() == Weekday::Friday)) {
+        Some("Último dia útil do ano")
+    } else {
+        // national holidays
+        brazilian_national_holiday_name(date)
+    }
+}
+
 impl HolidayCalendar for BrazilExchange {
 
     fn is_holiday(&self, date: Date) -> bool {
+        brazil_exchange_holiday_name(date).is_some()
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        let mut dates = brazilian_national_holiday_candidates(year);
+
+        dates.extend(
+            [(1, 25), (7, 9), (12, 24), (12, 29), (12, 30), (12, 31)]
+                .map(|(mm, dd)| Date::from_ymd(year, mm, dd).expect("Valid date")),
+        );
+
+        dates.retain(|&date| self.is_holiday(date));
+        Some(dates)
+    }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        brazil_exchange_holiday_name(date)
+    }
+}
+
+/// Classification of the days on which business is not as usual.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HolidayKind {
+    /// No business on the whole day.
+    Holiday,
+    /// "Ponto facultativo": a day off for the public administration, which other institutions may honour.
+    OptionalDay,
+    /// Business only on part of the day, such as Ash Wednesday's late opening.
+    HalfDay,
+}
+
+/// Days from Easter Sunday to Ash Wednesday.
+const ASH_WEDNESDAY_EASTER_OFFSET: i32 = -46;
 
-        let (yy, mm, dd) = date.to_ymd();
-
-        if
-        // Aniversário de São Paulo
-        ( mm == 1 && dd == 25 && yy < 2022 )
-            ||
-            // Revolucão
-            ( mm == 7 && dd == 9 && yy != 2020 && yy < 2022 )
-            ||
-            // Consciência Negra (since 2007)
-            ( yy >= 2007 && mm == 11 && dd == 20 && yy != 2020 && yy < 2022 )
-            // Christmas Eve
-            ||
-            ( mm == 12 && dd == 24)
-            ||
-            // Último dia útil do ano
-            ( mm == 12 && (dd == 31 || (dd>=29 && date.weekday() == Weekday::Friday) ))
-        {
-            return true;
+fn is_ash_wednesday(date: Date) -> bool {
+    easter::easter_num_days_from_ce(date.year())
+        .is_ok_and(|easter| date.num_days_from_ce() - easter == ASH_WEDNESDAY_EASTER_OFFSET)
+}
+
+impl BRSettlement {
+    /// Returns `HolidayKind::Holiday` on holidays, and `HolidayKind::HalfDay` on Ash Wednesday,
+    /// when banks open late. Returns `None` on other days.
+    ///
+    /// ```
+    /// use bdays::calendars::brazil::{BRSettlement, HolidayKind};
+    /// use bdays::date::Date;
+    ///
+    /// assert_eq!(BRSettlement.day_kind(Date::from_ymd(2025, 3, 4).unwrap()), Some(HolidayKind::Holiday));
+    /// assert_eq!(BRSettlement.day_kind(Date::from_ymd(2025, 3, 5).unwrap()), Some(HolidayKind::HalfDay));
+    /// ```
+    pub fn day_kind(&self, date: Date) -> Option<HolidayKind> {
+        if self.is_holiday(date) {
+            Some(HolidayKind::Holiday)
+        } else if is_ash_wednesday(date) {
+            Some(HolidayKind::HalfDay)
+        } else {
+            None
         }
+    }
+}
 
-        // national holidays
-        is_brazilian_national_holiday(date)
+impl BrazilExchange {
+    /// Returns `HolidayKind::Holiday` on holidays, and `HolidayKind::HalfDay` on Ash Wednesday,
+    /// when trading opens at 13:00. Returns `None` on other days.
+    pub fn day_kind(&self, date: Date) -> Option<HolidayKind> {
+        if self.is_holiday(date) {
+            Some(HolidayKind::Holiday)
+        } else if is_ash_wednesday(date) {
+            Some(HolidayKind::HalfDay)
+        } else {
+            None
+        }
+    }
+}
+
+/// Holidays and "pontos facultativos" of the Brazilian federal public administration.
+///
+/// Carnival and Corpus Christi are optional days. Ash Wednesday, until 14:00,
+/// and Christmas and New Year's Eve, from 14:00, are half days.
+/// The other national holidays of [BRSettlement] are holidays.
+///
+/// Optional days are not business days and half days are business days, unless configured otherwise.
+///
+/// ```
+/// use bdays::HolidayCalendar;
+/// use bdays::calendars::brazil::BrazilPublicAdministration;
+/// use bdays::date::Date;
+///
+/// let corpus_christi = Date::from_ymd(2025, 6, 19).unwrap();
+/// assert!(!BrazilPublicAdministration::new().is_bday(corpus_christi));
+/// assert!(BrazilPublicAdministration::new().with_optional_days_as_bdays(true).is_bday(corpus_christi));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BrazilPublicAdministration {
+    optional_days_as_bdays: bool,
+    half_days_as_bdays: bool,
+}
+
+impl Default for BrazilPublicAdministration {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BrazilPublicAdministration {
+    pub const fn new() -> Self {
+        BrazilPublicAdministration {
+            optional_days_as_bdays: false,
+            half_days_as_bdays: true,
+        }
+    }
+
+    /// Sets whether optional days are business days.
+    pub const fn with_optional_days_as_bdays(mut self, optional_days_as_bdays: bool) -> Self {
+        self.optional_days_as_bdays = optional_days_as_bdays;
+        self
+    }
+
+    /// Sets whether half days are business days.
+    pub const fn with_half_days_as_bdays(mut self, half_days_as_bdays: bool) -> Self {
+        self.half_days_as_bdays = half_days_as_bdays;
+        self
+    }
+
+    /// Returns the classification of `date`, regardless of the configuration,
+    /// or `None` on regular days.
+    pub fn day_kind(&self, date: Date) -> Option<HolidayKind> {
+        public_administration_day(date).map(|(kind, _)| kind)
+    }
+
+    fn is_day_off(&self, kind: HolidayKind) -> bool {
+        match kind {
+            HolidayKind::Holiday => true,
+            HolidayKind::OptionalDay => !self.optional_days_as_bdays,
+            HolidayKind::HalfDay => !self.half_days_as_bdays,
+        }
+    }
+}
+
+fn public_administration_day(date: Date) -> Option<(HolidayKind, &'static str)> {
+    match (date.month(), date.day()) {
+        (12, 24) => return Some((HolidayKind::HalfDay, "Véspera de Natal")),
+        (12, 31) => return Some((HolidayKind::HalfDay, "Véspera de Ano Novo")),
+        _ => {}
+    }
+
+    if is_ash_wednesday(date) {
+        return Some((HolidayKind::HalfDay, "Quarta-feira de Cinzas"));
+    }
+
+    let name = brazilian_national_holiday_name(date)?;
+    let days_from_easter = date.num_days_from_ce() - easter::easter_num_days_from_ce(date.year()).ok()?;
+
+    match days_from_easter {
+        // Carnival and Corpus Christi
+        -48 | -47 | 60 => Some((HolidayKind::OptionalDay, name)),
+        _ => Some((HolidayKind::Holiday, name)),
+    }
+}
+
+impl HolidayCalendar for BrazilPublicAdministration {
+    fn is_holiday(&self, date: Date) -> bool {
+        public_administration_day(date).is_some_and(|(kind, _)| self.is_day_off(kind))
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        let mut dates = brazilian_national_holiday_candidates(year);
+
+        dates.extend([(12, 24), (12, 31)].map(|(mm, dd)| Date::from_ymd(year, mm, dd).expect("Valid date")));
+
+        if let Ok(easter_date) = easter::easter_date(year) {
+            dates.push(easter_date.advance_days(ASH_WEDNESDAY_EASTER_OFFSET));
+        }
+
+        dates.retain(|&date| self.is_holiday(date));
+        Some(dates)
+    }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        public_administration_day(date)
+            .filter(|(kind, _)| self.is_day_off(*kind))
+            .map(|(_, name)| name)
+    }
+}
+
+/// Date of a state or municipal holiday.
+#[derive(Clone, Copy, Debug)]
+enum LocalDate {
+    /// Fixed month and day
+    Fixed(i32, i32),
+    /// Days from Easter Sunday
+    Easter(i32),
+}
+
+/// A state or municipal holiday, effective from `first_year` through `last_year`.
+#[derive(Clone, Copy, Debug)]
+struct LocalHoliday {
+    date: LocalDate,
+    name: &'static str,
+    first_year: i32,
+    last_year: i32,
+}
+
+const fn fixed(mm: i32, dd: i32, name: &'static str) -> LocalHoliday {
+    LocalHoliday { date: LocalDate::Fixed(mm, dd), name, first_year: i32::MIN, last_year: i32::MAX }
+}
+
+const fn fixed_since(first_year: i32, mm: i32, dd: i32, name: &'static str) -> LocalHoliday {
+    LocalHoliday { date: LocalDate::Fixed(mm, dd), name, first_year, last_year: i32::MAX }
+}
+
+const fn easter_offset(days: i32, name: &'static str) -> LocalHoliday {
+    LocalHoliday { date: LocalDate::Easter(days), name, first_year: i32::MIN, last_year: i32::MAX }
+}
+
+impl LocalHoliday {
+    /// Ends the holiday after `last_year`, when it was revoked or superseded by a national holiday.
+    const fn until(self, last_year: i32) -> LocalHoliday {
+        LocalHoliday { last_year, ..self }
+    }
+
+    fn is_effective(&self, year: i32) -> bool {
+        self.first_year <= year && year <= self.last_year
+    }
+
+    fn date_in_year(&self, year: i32) -> Option<Date> {
+        if !self.is_effective(year) {
+            return None;
+        }
+
+        match self.date {
+            LocalDate::Fixed(mm, dd) => Date::from_ymd(year, mm, dd).ok(),
+            LocalDate::Easter(days) => easter::easter_date(year).ok().map(|easter_date| easter_date.advance_days(days)),
+        }
+    }
+
+    fn matches(&self, date: Date) -> bool {
+        let year = date.year();
+
+        if !self.is_effective(year) {
+            return false;
+        }
+
+        match self.date {
+            LocalDate::Fixed(mm, dd) => date.month() == mm && date.day() == dd,
+            LocalDate::Easter(days) => easter::easter_num_days_from_ce(year).is_ok_and(|easter| date.num_days_from_ce() - easter == days),
+        }
+    }
+}
+
+/// Name of the first of `holidays` on `date`.
+fn local_holiday_name(holidays: &[LocalHoliday], date: Date) -> Option<&'static str> {
+    holidays.iter().find(|holiday| holiday.matches(date)).map(|holiday| holiday.name)
+}
+
+/// Enum for all 27 Brazilian federative units: the 26 states and the Federal District.
+/// Each one is a [HolidayCalendar](crate::HolidayCalendar) with the national holidays
+/// of [BRSettlement] plus the state holidays.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BrazilState {
+    /// Acre
+    AC,
+    /// Alagoas
+    AL,
+    /// Amapá
+    AP,
+    /// Amazonas
+    AM,
+    /// Bahia
+    BA,
+    /// Ceará
+    CE,
+    /// Distrito Federal
+    DF,
+    /// Espírito Santo
+    ES,
+    /// Goiás
+    GO,
+    /// Maranhão
+    MA,
+    /// Mato Grosso
+    MT,
+    /// Mato Grosso do Sul
+    MS,
+    /// Minas Gerais
+    MG,
+    /// Pará
+    PA,
+    /// Paraíba
+    PB,
+    /// Paraná
+    PR,
+    /// Pernambuco
+    PE,
+    /// Piauí
+    PI,
+    /// Rio de Janeiro
+    RJ,
+    /// Rio Grande do Norte
+    RN,
+    /// Rio Grande do Sul
+    RS,
+    /// Rondônia
+    RO,
+    /// Roraima
+    RR,
+    /// Santa Catarina
+    SC,
+    /// São Paulo
+    SP,
+    /// Sergipe
+    SE,
+    /// Tocantins
+    TO,
+}
+
+impl BrazilState {
+    /// All the federative units, in alphabetical order of their names.
+    pub const ALL: [BrazilState; 27] = {
+        use BrazilState::*;
+        [AC, AL, AP, AM, BA, CE, DF, ES, GO, MA, MT, MS, MG, PA, PB, PR, PE, PI, RJ, RN, RS, RO, RR, SC, SP, SE, TO]
+    };
+
+    /// Holidays set by state law, besides the national ones.
+    const fn holidays(&self) -> &'static [LocalHoliday] {
+        match self {
+            Self::AC => const { &[
+                fixed(1, 23, "Dia do Evangélico"),
+                fixed(3, 8, "Dia Internacional da Mulher"),
+                fixed(6, 15, "Aniversário do Acre"),
+                fixed(9, 5, "Dia da Amazônia"),
+                fixed(11, 17, "Assinatura do Tratado de Petrópolis"),
+            ] },
+            Self::AL => const { &[
+                fixed(6, 24, "São João"),
+                fixed(6, 29, "São Pedro"),
+                fixed(9, 16, "Emancipação Política de Alagoas"),
+                fixed(11, 20, "Dia da Consciência Negra").until(2023),
+                fixed(11, 30, "Dia do Evangélico"),
+            ] },
+            Self::AP => const { &[
+                fixed(3, 19, "São José"),
+                fixed(7, 25, "São Tiago"),
+                fixed_since(1989, 10, 5, "Criação do Estado do Amapá"),
+                fixed(11, 20, "Dia da Consciência Negra").until(2023),
+            ] },
+            Self::AM => const { &[
+                fixed(9, 5, "Elevação do Amazonas à categoria de Província"),
+                fixed(11, 20, "Dia da Consciência Negra").until(2023),
+            ] },
+            Self::BA => const { &[
+                fixed(7, 2, "Independência da Bahia"),
+            ] },
+            Self::CE => const { &[
+                fixed(3, 19, "São José"),
+                fixed(3, 25, "Data Magna do Ceará"),
+            ] },
+            Self::DF => const { &[
+                fixed(4, 21, "Fundação de Brasília"),
+                fixed(11, 30, "Dia do Evangélico"),
+            ] },
+            // no state holidays
+            Self::ES | Self::GO => &[],
+            Self::MA => const { &[
+                fixed(7, 28, "Adesão do Maranhão à Independência do Brasil"),
+            ] },
+            Self::MT => const { &[
+                fixed(11, 20, "Dia da Consciência Negra").until(2023),
+            ] },
+            Self::MS => const { &[
+                fixed_since(1979, 10, 11, "Criação do Estado de Mato Grosso do Sul"),
+            ] },
+            Self::MG => const { &[
+                fixed(4, 21, "Data Magna de Minas Gerais"),
+            ] },
+            Self::PA => const { &[
+                fixed(8, 15, "Adesão do Grão-Pará à Independência do Brasil"),
+            ] },
+            Self::PB => const { &[
+                fixed(8, 5, "Fundação do Estado da Paraíba"),
+            ] },
+            Self::PR => const { &[
+                fixed(12, 19, "Emancipação Política do Paraná"),
+            ] },
+            Self::PE => const { &[
+                fixed_since(2018, 3, 6, "Data Magna de Pernambuco"),
+                fixed(6, 24, "São João"),
+            ] },
+            Self::PI => const { &[
+                fixed(3, 13, "Dia da Batalha do Jenipapo"),
+                fixed(10, 19, "Dia do Piauí"),
+            ] },
+            Self::RJ => const { &[
+                fixed_since(2008, 4, 23, "São Jorge"),
+                fixed_since(2002, 11, 20, "Dia da Consciência Negra").until(2023),
+            ] },
+            Self::RN => const { &[
+                fixed(6, 29, "São Pedro"),
+                fixed_since(2007, 10, 3, "Mártires de Cunhaú e Uruaçu"),
+            ] },
+            Self::RS => const { &[
+                fixed(9, 20, "Revolução Farroupilha"),
+            ] },
+            Self::RO => const { &[
+                fixed_since(1982, 1, 4, "Criação do Estado de Rondônia"),
+                fixed(6, 18, "Dia do Evangélico"),
+            ] },
+            Self::RR => const { &[
+                fixed_since(1989, 10, 5, "Criação do Estado de Roraima"),
+            ] },
+            // the state holidays are observed on the following Sunday
+            Self::SC => &[],
+            Self::SP => const { &[
+                fixed_since(1997, 7, 9, "Revolução Constitucionalista de 1932"),
+            ] },
+            Self::SE => const { &[
+                fixed(7, 8, "Emancipação Política de Sergipe"),
+            ] },
+            Self::TO => const { &[
+                fixed(3, 18, "Autonomia do Estado do Tocantins"),
+                fixed(9, 8, "Nossa Senhora da Natividade"),
+                fixed_since(1989, 10, 5, "Criação do Estado do Tocantins"),
+            ] },
+        }
+    }
+
+    /// Returns the number of state holidays in `year`, not counting national holidays
+    /// nor state holidays on the date of a national one.
+    ///
+    /// ```
+    /// use bdays::calendars::brazil::BrazilState;
+    /// assert_eq!(BrazilState::SP.num_holidays(2023), 1);
+    /// assert_eq!(BrazilState::SP.num_holidays(1996), 0);
+    /// ```
+    pub fn num_holidays(&self, year: i32) -> u32 {
+        self.holidays()
+            .iter()
+            .filter_map(|holiday| holiday.date_in_year(year))
+            .filter(|&date| !is_brazilian_national_holiday(date))
+            .count() as u32
+    }
+}
+
+fn brazil_state_holiday_name(state: BrazilState, date: Date) -> Option<&'static str> {
+    brazilian_national_holiday_name(date).or_else(|| local_holiday_name(state.holidays(), date))
+}
+
+/// National holiday candidates in `year`, plus the dates of `holidays`, sorted and without duplicates.
+fn local_holiday_candidates<'a>(year: i32, holidays: impl IntoIterator<Item = &'a LocalHoliday>) -> Vec<Date> {
+    let mut dates = brazilian_national_holiday_candidates(year);
+    dates.extend(holidays.into_iter().filter_map(|holiday| holiday.date_in_year(year)));
+    dates.sort();
+    dates.dedup();
+    dates
+}
+
+impl HolidayCalendar for BrazilState {
+    fn is_holiday(&self, date: Date) -> bool {
+        brazil_state_holiday_name(*self, date).is_some()
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        let mut dates = local_holiday_candidates(year, self.holidays());
+        dates.retain(|&date| self.is_holiday(date));
+        Some(dates)
+    }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        brazil_state_holiday_name(*self, date)
+    }
+}
+
+/// Brazilian state capitals. Each one is a [HolidayCalendar](crate::HolidayCalendar)
+/// with the national holidays, the holidays of its [BrazilState] and the municipal holidays.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BrazilMunicipality {
+    /// Aracaju, SE
+    Aracaju,
+    /// Belém, PA
+    Belem,
+    /// Belo Horizonte, MG
+    BeloHorizonte,
+    /// Boa Vista, RR
+    BoaVista,
+    /// Brasília, DF
+    Brasilia,
+    /// Campo Grande, MS
+    CampoGrande,
+    /// Cuiabá, MT
+    Cuiaba,
+    /// Curitiba, PR
+    Curitiba,
+    /// Florianópolis, SC
+    Florianopolis,
+    /// Fortaleza, CE
+    Fortaleza,
+    /// Goiânia, GO
+    Goiania,
+    /// João Pessoa, PB
+    JoaoPessoa,
+    /// Macapá, AP
+    Macapa,
+    /// Maceió, AL
+    Maceio,
+    /// Manaus, AM
+    Manaus,
+    /// Natal, RN
+    Natal,
+    /// Palmas, TO
+    Palmas,
+    /// Porto Alegre, RS
+    PortoAlegre,
+    /// Porto Velho, RO
+    PortoVelho,
+    /// Recife, PE
+    Recife,
+    /// Rio Branco, AC
+    RioBranco,
+    /// Rio de Janeiro, RJ
+    RioDeJaneiro,
+    /// Salvador, BA
+    Salvador,
+    /// São Luís, MA
+    SaoLuis,
+    /// São Paulo, SP
+    SaoPaulo,
+    /// Teresina, PI
+    Teresina,
+    /// Vitória, ES
+    Vitoria,
+}
+
+impl BrazilMunicipality {
+    /// The state of the municipality.
+    pub const fn state(&self) -> BrazilState {
+        match self {
+            Self::Aracaju => BrazilState::SE,
+            Self::Belem => BrazilState::PA,
+            Self::BeloHorizonte => BrazilState::MG,
+            Self::BoaVista => BrazilState::RR,
+            Self::Brasilia => BrazilState::DF,
+            Self::CampoGrande => BrazilState::MS,
+            Self::Cuiaba => BrazilState::MT,
+            Self::Curitiba => BrazilState::PR,
+            Self::Florianopolis => BrazilState::SC,
+            Self::Fortaleza => BrazilState::CE,
+            Self::Goiania => BrazilState::GO,
+            Self::JoaoPessoa => BrazilState::PB,
+            Self::Macapa => BrazilState::AP,
+            Self::Maceio => BrazilState::AL,
+            Self::Manaus => BrazilState::AM,
+            Self::Natal => BrazilState::RN,
+            Self::Palmas => BrazilState::TO,
+            Self::PortoAlegre => BrazilState::RS,
+            Self::PortoVelho => BrazilState::RO,
+            Self::Recife => BrazilState::PE,
+            Self::RioBranco => BrazilState::AC,
+            Self::RioDeJaneiro => BrazilState::RJ,
+            Self::Salvador => BrazilState::BA,
+            Self::SaoLuis => BrazilState::MA,
+            Self::SaoPaulo => BrazilState::SP,
+            Self::Teresina => BrazilState::PI,
+            Self::Vitoria => BrazilState::ES,
+        }
+    }
+
+    /// Holidays set by municipal law, besides the national and state ones.
+    const fn holidays(&self) -> &'static [LocalHoliday] {
+        match self {
+            Self::Aracaju => const { &[
+                fixed(3, 17, "Aniversário de Aracaju"),
+                fixed(12, 8, "Nossa Senhora da Conceição"),
+            ] },
+            Self::Belem => const { &[
+                fixed(1, 12, "Aniversário de Belém"),
+                fixed(12, 8, "Nossa Senhora da Conceição"),
+            ] },
+            Self::BeloHorizonte => const { &[
+                fixed(8, 15, "Assunção de Nossa Senhora"),
+                fixed(12, 8, "Imaculada Conceição"),
+            ] },
+            Self::BoaVista => const { &[
+                fixed(7, 9, "Aniversário de Boa Vista"),
+            ] },
+            Self::Brasilia => &[],
+            Self::CampoGrande => const { &[
+                fixed(8, 26, "Aniversário de Campo Grande"),
+            ] },
+            Self::Cuiaba => const { &[
+                fixed(4, 8, "Aniversário de Cuiabá"),
+            ] },
+            Self::Curitiba => const { &[
+                fixed(9, 8, "Nossa Senhora da Luz dos Pinhais"),
+            ] },
+            Self::Florianopolis => const { &[
+                fixed(3, 23, "Aniversário de Florianópolis"),
+            ] },
+            Self::Fortaleza => const { &[
+                fixed(4, 13, "Aniversário de Fortaleza"),
+                fixed(8, 15, "Nossa Senhora da Assunção"),
+            ] },
+            Self::Goiania => const { &[
+                fixed(5, 24, "Nossa Senhora Auxiliadora"),
+                fixed(10, 24, "Aniversário de Goiânia"),
+            ] },
+            Self::JoaoPessoa => const { &[
+                fixed(8, 5, "Nossa Senhora das Neves"),
+            ] },
+            Self::Macapa => const { &[
+                fixed(2, 4, "Aniversário de Macapá"),
+            ] },
+            Self::Maceio => const { &[
+                fixed(8, 27, "Nossa Senhora dos Prazeres"),
+                fixed(12, 8, "Nossa Senhora da Conceição"),
+            ] },
+            Self::Manaus => const { &[
+                fixed(10, 24, "Aniversário de Manaus"),
+                fixed(12, 8, "Nossa Senhora da Conceição"),
+            ] },
+            Self::Natal => const { &[
+                fixed(1, 6, "Santos Reis"),
+                fixed(11, 21, "Nossa Senhora da Apresentação"),
+            ] },
+            Self::Palmas => const { &[
+                fixed(5, 20, "Aniversário de Palmas"),
+            ] },
+            Self::PortoAlegre => const { &[
+                fixed(2, 2, "Nossa Senhora dos Navegantes"),
+            ] },
+            Self::PortoVelho => const { &[
+                fixed(10, 2, "Aniversário de Porto Velho"),
+            ] },
+            Self::Recife => const { &[
+                fixed(7, 16, "Nossa Senhora do Carmo"),
+                fixed(12, 8, "Nossa Senhora da Conceição"),
+            ] },
+            Self::RioBranco => const { &[
+                fixed(12, 28, "Aniversário de Rio Branco"),
+            ] },
+            Self::RioDeJaneiro => const { &[
+                fixed(1, 20, "São Sebastião"),
+            ] },
+            Self::Salvador => const { &[
+                fixed(6, 24, "São João"),
+                fixed(12, 8, "Nossa Senhora da Conceição da Praia"),
+            ] },
+            Self::SaoLuis => const { &[
+                fixed(6, 29, "São Pedro"),
+                fixed(9, 8, "Aniversário de São Luís"),
+                fixed(12, 8, "Nossa Senhora da Conceição"),
+            ] },
+            Self::SaoPaulo => const { &[
+                fixed(1, 25, "Aniversário de São Paulo"),
+                fixed_since(2004, 11, 20, "Dia da Consciência Negra").until(2023),
+            ] },
+            Self::Teresina => const { &[
+                fixed(8, 16, "Aniversário de Teresina"),
+            ] },
+            Self::Vitoria => const { &[
+                easter_offset(8, "Nossa Senhora da Penha"),
+                fixed(9, 8, "Aniversário de Vitória"),
+            ] },
+        }
+    }
+}
+
+fn brazil_municipality_holiday_name(municipality: BrazilMunicipality, date: Date) -> Option<&'static str> {
+    brazil_state_holiday_name(municipality.state(), date).or_else(|| local_holiday_name(municipality.holidays(), date))
+}
+
+impl HolidayCalendar for BrazilMunicipality {
+    fn is_holiday(&self, date: Date) -> bool {
+        brazil_municipality_holiday_name(*self, date).is_some()
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        let mut dates = local_holiday_candidates(year, self.state().holidays().iter().chain(self.holidays()));
+        dates.retain(|&date| self.is_holiday(date));
+        Some(dates)
+    }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        brazil_municipality_holiday_name(*self, date)
     }
 }
@@ -0,0 +1,267 @@
+use crate::HolidayCalendar;
+use crate::date::Date;
+
+/// Shanghai Stock Exchange holidays (<https://www.sse.com.cn>).
+///
+/// The exchange closes on the public holidays scheduled by the State Council every year.
+/// The weekend days worked to make up for longer holidays are not trading days.
+/// Only the holidays from [FIRST_KNOWN_YEAR] to [LAST_KNOWN_YEAR] are known,
+/// since each year's schedule is published at the end of the previous year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SSE;
+
+/// China Foreign Exchange Trade System holidays, for the interbank market (<https://www.chinamoney.com.cn>).
+///
+/// The interbank market closes on the same holidays as [SSE],
+/// but opens on the weekend days worked to make up for longer holidays.
+/// Only the holidays from [FIRST_KNOWN_YEAR] to [LAST_KNOWN_YEAR] are known.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CFETS;
+
+/// First year of the known holiday schedules of [SSE] and [CFETS].
+pub const FIRST_KNOWN_YEAR: i32 = 2008;
+
+/// Last year of the known holiday schedules of [SSE] and [CFETS].
+pub const LAST_KNOWN_YEAR: i32 = 2026;
+
+/// Year, month and day.
+type Ymd = (i32, i32, i32);
+
+/// Public holidays scheduled by the State Council, including the weekends in between,
+/// as the first and last days of each holiday.
+const CN_HOLIDAYS: [(Ymd, Ymd, &str); 128] = [
+    ((2007, 12, 30), (2008, 1, 1), "New Year's Day"),
+    ((2008, 2, 6), (2008, 2, 12), "Spring Festival"),
+    ((2008, 4, 4), (2008, 4, 6), "Qingming Festival"),
+    ((2008, 5, 1), (2008, 5, 3), "Labour Day"),
+    ((2008, 6, 7), (2008, 6, 9), "Dragon Boat Festival"),
+    ((2008, 9, 13), (2008, 9, 15), "Mid-Autumn Festival"),
+    ((2008, 9, 29), (2008, 10, 5), "National Day"),
+    ((2009, 1, 1), (2009, 1, 3), "New Year's Day"),
+    ((2009, 1, 25), (2009, 1, 31), "Spring Festival"),
+    ((2009, 4, 4), (2009, 4, 6), "Qingming Festival"),
+    ((2009, 5, 1), (2009, 5, 3), "Labour Day"),
+    ((2009, 5, 28), (2009, 5, 30), "Dragon Boat Festival"),
+    ((2009, 10, 1), (2009, 10, 8), "National Day and Mid-Autumn Festival"),
+    ((2010, 1, 1), (2010, 1, 3), "New Year's Day"),
+    ((2010, 2, 13), (2010, 2, 19), "Spring Festival"),
+    ((2010, 4, 3), (2010, 4, 5), "Qingming Festival"),
+    ((2010, 5, 1), (2010, 5, 3), "Labour Day"),
+    ((2010, 6, 14), (2010, 6, 16), "Dragon Boat Festival"),
+    ((2010, 9, 22), (2010, 9, 24), "Mid-Autumn Festival"),
+    ((2010, 10, 1), (2010, 10, 7), "National Day"),
+    ((2011, 1, 1), (2011, 1, 3), "New Year's Day"),
+    ((2011, 2, 2), (2011, 2, 8), "Spring Festival"),
+    ((2011, 4, 3), (2011, 4, 5), "Qingming Festival"),
+    ((2011, 4, 30), (2011, 5, 2), "Labour Day"),
+    ((2011, 6, 4), (2011, 6, 6), "Dragon Boat Festival"),
+    ((2011, 9, 10), (2011, 9, 12), "Mid-Autumn Festival"),
+    ((2011, 10, 1), (2011, 10, 7), "National Day"),
+    ((2012, 1, 1), (2012, 1, 3), "New Year's Day"),
+    ((2012, 1, 22), (2012, 1, 28), "Spring Festival"),
+    ((2012, 4, 2), (2012, 4, 4), "Qingming Festival"),
+    ((2012, 4, 29), (2012, 5, 1), "Labour Day"),
+    ((2012, 6, 22), (2012, 6, 24), "Dragon Boat Festival"),
+    ((2012, 9, 30), (2012, 10, 7), "Mid-Autumn Festival and National Day"),
+    ((2013, 1, 1), (2013, 1, 3), "New Year's Day"),
+    ((2013, 2, 9), (2013, 2, 15), "Spring Festival"),
+    ((2013, 4, 4), (2013, 4, 6), "Qingming Festival"),
+    ((2013, 4, 29), (2013, 5, 1), "Labour Day"),
+    ((2013, 6, 10), (2013, 6, 12), "Dragon Boat Festival"),
+    ((2013, 9, 19), (2013, 9, 21), "Mid-Autumn Festival"),
+    ((2013, 10, 1), (2013, 10, 7), "National Day"),
+    ((2014, 1, 1), (2014, 1, 1), "New Year's Day"),
+    ((2014, 1, 31), (2014, 2, 6), "Spring Festival"),
+    ((2014, 4, 5), (2014, 4, 7), "Qingming Festival"),
+    ((2014, 5, 1), (2014, 5, 3), "Labour Day"),
+    ((2014, 5, 31), (2014, 6, 2), "Dragon Boat Festival"),
+    ((2014, 9, 6), (2014, 9, 8), "Mid-Autumn Festival"),
+    ((2014, 10, 1), (2014, 10, 7), "National Day"),
+    ((2015, 1, 1), (2015, 1, 3), "New Year's Day"),
+    ((2015, 2, 18), (2015, 2, 24), "Spring Festival"),
+    ((2015, 4, 4), (2015, 4, 6), "Qingming Festival"),
+    ((2015, 5, 1), (2015, 5, 3), "Labour Day"),
+    ((2015, 6, 20), (2015, 6, 22), "Dragon Boat Festival"),
+    ((2015, 9, 3), (2015, 9, 5), "Victory Day"),
+    ((2015, 9, 26), (2015, 9, 27), "Mid-Autumn Festival"),
+    ((2015, 10, 1), (2015, 10, 7), "National Day"),
+    ((2016, 1, 1), (2016, 1, 3), "New Year's Day"),
+    ((2016, 2, 7), (2016, 2, 13), "Spring Festival"),
+    ((2016, 4, 2), (2016, 4, 4), "Qingming Festival"),
+    ((2016, 4, 30), (2016, 5, 2), "Labour Day"),
+    ((2016, 6, 9), (2016, 6, 11), "Dragon Boat Festival"),
+    ((2016, 9, 15), (2016, 9, 17), "Mid-Autumn Festival"),
+    ((2016, 10, 1), (2016, 10, 7), "National Day"),
+    ((2016, 12, 31), (2017, 1, 2), "New Year's Day"),
+    ((2017, 1, 27), (2017, 2, 2), "Spring Festival"),
+    ((2017, 4, 2), (2017, 4, 4), "Qingming Festival"),
+    ((2017, 4, 29), (2017, 5, 1), "Labour Day"),
+    ((2017, 5, 28), (2017, 5, 30), "Dragon Boat Festival"),
+    ((2017, 10, 1), (2017, 10, 8), "National Day and Mid-Autumn Festival"),
+    ((2017, 12, 30), (2018, 1, 1), "New Year's Day"),
+    ((2018, 2, 15), (2018, 2, 21), "Spring Festival"),
+    ((2018, 4, 5), (2018, 4, 7), "Qingming Festival"),
+    ((2018, 4, 29), (2018, 5, 1), "Labour Day"),
+    ((2018, 6, 16), (2018, 6, 18), "Dragon Boat Festival"),
+    ((2018, 9, 22), (2018, 9, 24), "Mid-Autumn Festival"),
+    ((2018, 10, 1), (2018, 10, 7), "National Day"),
+    ((2018, 12, 30), (2019, 1, 1), "New Year's Day"),
+    ((2019, 2, 4), (2019, 2, 10), "Spring Festival"),
+    ((2019, 4, 5), (2019, 4, 7), "Qingming Festival"),
+    ((2019, 5, 1), (2019, 5, 4), "Labour Day"),
+    ((2019, 6, 7), (2019, 6, 9), "Dragon Boat Festival"),
+    ((2019, 9, 13), (2019, 9, 15), "Mid-Autumn Festival"),
+    ((2019, 10, 1), (2019, 10, 7), "National Day"),
+    ((2020, 1, 1), (2020, 1, 1), "New Year's Day"),
+    // extended to 2 February, during the COVID-19 outbreak
+    ((2020, 1, 24), (2020, 2, 2), "Spring Festival"),
+    ((2020, 4, 4), (2020, 4, 6), "Qingming Festival"),
+    ((2020, 5, 1), (2020, 5, 5), "Labour Day"),
+    ((2020, 6, 25), (2020, 6, 27), "Dragon Boat Festival"),
+    ((2020, 10, 1), (2020, 10, 8), "National Day and Mid-Autumn Festival"),
+    ((2021, 1, 1), (2021, 1, 3), "New Year's Day"),
+    ((2021, 2, 11), (2021, 2, 17), "Spring Festival"),
+    ((2021, 4, 3), (2021, 4, 5), "Qingming Festival"),
+    ((2021, 5, 1), (2021, 5, 5), "Labour Day"),
+    ((2021, 6, 12), (2021, 6, 14), "Dragon Boat Festival"),
+    ((2021, 9, 19), (2021, 9, 21), "Mid-Autumn Festival"),
+    ((2021, 10, 1), (2021, 10, 7), "National Day"),
+    ((2022, 1, 1), (2022, 1, 3), "New Year's Day"),
+    ((2022, 1, 31), (2022, 2, 6), "Spring Festival"),
+    ((2022, 4, 3), (2022, 4, 5), "Qingming Festival"),
+    ((2022, 4, 30), (2022, 5, 4), "Labour Day"),
+    ((2022, 6, 3), (2022, 6, 5), "Dragon Boat Festival"),
+    ((2022, 9, 10), (2022, 9, 12), "Mid-Autumn Festival"),
+    ((2022, 10, 1), (2022, 10, 7), "National Day"),
+    ((2022, 12, 31), (2023, 1, 2), "New Year's Day"),
+    ((2023, 1, 21), (2023, 1, 27), "Spring Festival"),
+    ((2023, 4, 5), (2023, 4, 5), "Qingming Festival"),
+    ((2023, 4, 29), (2023, 5, 3), "Labour Day"),
+    ((2023, 6, 22), (2023, 6, 24), "Dragon Boat Festival"),
+    ((2023, 9, 29), (2023, 10, 6), "Mid-Autumn Festival and National Day"),
+    ((2023, 12, 30), (2024, 1, 1), "New Year's Day"),
+    // the markets also closed on Spring Festival Eve, 9 February
+    ((2024, 2, 9), (2024, 2, 17), "Spring Festival"),
+    ((2024, 4, 4), (2024, 4, 6), "Qingming Festival"),
+    ((2024, 5, 1), (2024, 5, 5), "Labour Day"),
+    ((2024, 6, 8), (2024, 6, 10), "Dragon Boat Festival"),
+    ((2024, 9, 15), (2024, 9, 17), "Mid-Autumn Festival"),
+    ((2024, 10, 1), (2024, 10, 7), "National Day"),
+    ((2025, 1, 1), (2025, 1, 1), "New Year's Day"),
+    ((2025, 1, 28), (2025, 2, 4), "Spring Festival"),
+    ((2025, 4, 4), (2025, 4, 6), "Qingming Festival"),
+    ((2025, 5, 1), (2025, 5, 5), "Labour Day"),
+    ((2025, 5, 31), (2025, 6, 2), "Dragon Boat Festival"),
+    ((2025, 10, 1), (2025, 10, 8), "National Day and Mid-Autumn Festival"),
+    ((2026, 1, 1), (2026, 1, 3), "New Year's Day"),
+    ((2026, 2, 15), (2026, 2, 23), "Spring Festival"),
+    ((2026, 4, 4), (2026, 4, 6), "Qingming Festival"),
+    ((2026, 5, 1), (2026, 5, 5), "Labour Day"),
+    ((2026, 6, 19), (2026, 6, 21), "Dragon Boat Festival"),
+    ((2026, 9, 25), (2026, 9, 27), "Mid-Autumn Festival"),
+    ((2026, 10, 1), (2026, 10, 7), "National Day"),
+];
+
+/// Saturdays and Sundays worked to make up for the holidays of `CN_HOLIDAYS`.
+const CN_WORKING_WEEKENDS: [Ymd; 124] = [
+    (2007, 12, 29),
+    (2008, 2, 2), (2008, 2, 3), (2008, 5, 4), (2008, 9, 27), (2008, 9, 28),
+    (2009, 1, 4), (2009, 1, 24), (2009, 2, 1), (2009, 5, 31), (2009, 9, 27), (2009, 10, 10),
+    (2010, 2, 20), (2010, 2, 21), (2010, 6, 12), (2010, 6, 13), (2010, 9, 19), (2010, 9, 25), (2010, 9, 26), (2010, 10, 9),
+    (2011, 1, 30), (2011, 2, 12), (2011, 4, 2), (2011, 10, 8), (2011, 10, 9),
+    (2011, 12, 31),
+    (2012, 1, 21), (2012, 1, 29), (2012, 3, 31), (2012, 4, 1), (2012, 4, 28), (2012, 9, 29),
+    (2013, 1, 5), (2013, 1, 6), (2013, 2, 16), (2013, 2, 17), (2013, 4, 7), (2013, 4, 27), (2013, 4, 28), (2013, 6, 8),
+    (2013, 6, 9), (2013, 9, 22), (2013, 9, 29), (2013, 10, 12),
+    (2014, 1, 26), (2014, 2, 8), (2014, 5, 4), (2014, 9, 28), (2014, 10, 11),
+    (2015, 1, 4), (2015, 2, 15), (2015, 2, 28), (2015, 9, 6), (2015, 10, 10),
+    (2016, 2, 6), (2016, 2, 14), (2016, 6, 12), (2016, 9, 18), (2016, 10, 8), (2016, 10, 9),
+    (2017, 1, 22), (2017, 2, 4), (2017, 4, 1), (2017, 5, 27), (2017, 9, 30),
+    (2018, 2, 11), (2018, 2, 24), (2018, 4, 8), (2018, 4, 28), (2018, 9, 29), (2018, 9, 30), (2018, 12, 29),
+    (2019, 2, 2), (2019, 2, 3), (2019, 4, 28), (2019, 5, 5), (2019, 9, 29), (2019, 10, 12),
+    (2020, 1, 19), (2020, 4, 26), (2020, 5, 9), (2020, 6, 28), (2020, 9, 27), (2020, 10, 10),
+    (2021, 2, 7), (2021, 2, 20), (2021, 4, 25), (2021, 5, 8), (2021, 9, 18), (2021, 9, 26), (2021, 10, 9),
+    (2022, 1, 29), (2022, 1, 30), (2022, 4, 2), (2022, 4, 24), (2022, 5, 7), (2022, 10, 8), (2022, 10, 9),
+    (2023, 1, 28), (2023, 1, 29), (2023, 4, 23), (2023, 5, 6), (2023, 6, 25), (2023, 10, 7), (2023, 10, 8),
+    (2024, 2, 4), (2024, 2, 18), (2024, 4, 7), (2024, 4, 28), (2024, 5, 11), (2024, 9, 14), (2024, 9, 29), (2024, 10, 12),
+    (2025, 1, 26), (2025, 2, 8), (2025, 4, 27), (2025, 9, 28), (2025, 10, 11),
+    (2026, 1, 4), (2026, 2, 14), (2026, 2, 28), (2026, 5, 9), (2026, 9, 20), (2026, 10, 10),
+];
+
+fn cn_holiday_name(date: Date) -> Option<&'static str> {
+    let ymd = date.to_ymd();
+
+    CN_HOLIDAYS
+        .iter()
+        .find(|(first, last, _)| *first <= ymd && ymd <= *last)
+        .map(|(_, _, name)| *name)
+}
+
+fn cn_holidays_in_year(year: i32) -> Vec<Date> {
+    let mut dates = Vec::new();
+
+    for &(first, last, _) in CN_HOLIDAYS.iter().filter(|((y0, _, _), (y1, _, _), _)| *y0 == year || *y1 == year) {
+        let mut date = Date::from_ymd(first.0, first.1, first.2).expect("Valid date");
+        let last = Date::from_ymd(last.0, last.1, last.2).expect("Valid date");
+
+        while date <= last {
+            if date.year() == year {
+                dates.push(date);
+            }
+
+            date = date.next_date();
+        }
+    }
+
+    dates
+}
+
+impl HolidayCalendar for SSE {
+    fn is_holiday(&self, date: Date) -> bool {
+        cn_holiday_name(date).is_some()
+    }
+
+    fn covers_year(&self, year: i32) -> bool {
+        (FIRST_KNOWN_YEAR..=LAST_KNOWN_YEAR).contains(&year)
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        Some(cn_holidays_in_year(year))
+    }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        cn_holiday_name(date)
+    }
+}
+
+impl HolidayCalendar for CFETS {
+    fn is_holiday(&self, date: Date) -> bool {
+        cn_holiday_name(date).is_some()
+    }
+
+    fn covers_year(&self, year: i32) -> bool {
+        (FIRST_KNOWN_YEAR..=LAST_KNOWN_YEAR).contains(&year)
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        Some(cn_holidays_in_year(year))
+    }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        cn_holiday_name(date)
+    }
+
+    fn is_working_weekend(&self, date: Date) -> bool {
+        CN_WORKING_WEEKENDS.contains(&date.to_ymd())
+    }
+
+    fn working_weekends_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        Some(
+            CN_WORKING_WEEKENDS
+                .iter()
+                .filter(|(yy, _, _)| *yy == year)
+                .map(|&(yy, mm, dd)| Date::from_ymd(yy, mm, dd).expect("Valid date"))
+                .collect(),
+        )
+    }
+}
@@ -1,62 +1,6 @@
 use crate::HolidayCalendar;
 use crate::date::Date;
 
-fn check_newyear(date: &Date) -> bool {
-    date.day() == 1 && date.month() == 1
-}
-
-fn check_epiphany(date: &Date) -> bool {
-    date.day() == 6 && date.month() == 1
-}
-
-fn check_intwomansday(date: &Date) -> bool {
-    date.day() == 8 && date.month() == 3
-}
-
-fn check_goodfriday(date: &Date, easter_sunday: &Date) -> bool {
-    date.num_days_from_ce() == easter_sunday.num_days_from_ce() - 2
-}
-
-fn check_eastermonday(date: &Date, easter_sunday: &Date) -> bool {
-    date.num_days_from_ce() == easter_sunday.num_days_from_ce() + 1
-}
-
-fn check_labourday(date: &Date) -> bool {
-    date.day() == 1 && date.month() == 5
-}
-
-fn check_ascensionday(date: &Date, easter_sunday: &Date) -> bool {
-    date.num_days_from_ce() == easter_sunday.num_days_from_ce() + 39
-}
-
-fn check_whitmonday(date: &Date, easter_sunday: &Date) -> bool {
-    date.num_days_from_ce() == easter_sunday.num_days_from_ce() + 50
-}
-
-fn check_corpuschristi(date: &Date, easter_sunday: &Date) -> bool {
-    date.num_days_from_ce() == easter_sunday.num_days_from_ce() + 60
-}
-
-fn check_assumptionday(date: &Date) -> bool {
-    date.day() == 15 && date.month() == 8
-}
-
-fn check_childrenday(date: &Date) -> bool {
-    date.day() == 20 && date.month() == 9
-}
-
-fn check_unityday(date: &Date) -> bool {
-    date.day() == 3 && date.month() == 10
-}
-
-fn check_reformationday(date: &Date) -> bool {
-    date.day() == 31 && date.month() == 10
-}
-
-fn check_allsaintsday(date: &Date) -> bool {
-    date.day() == 1 && date.month() == 11
-}
-
 fn check_repentence(date: &Date) -> bool {
     let november_22 = Date::from_ymd(date.year(), 11, 22).expect("22 Nov should exist every year");
     let offset = (november_22.weekday().number_from_monday() + 7 - 3) % 7;
@@ -64,14 +8,6 @@ fn check_repentence(date: &Date) -> bool {
     november_22.advance_days(-offset) == *date
 }
 
-fn check_christmasday(date: &Date) -> bool {
-    date.day() == 25 && date.month() == 12
-}
-
-fn check_2ndchristmasday(date: &Date) -> bool {
-    date.day() == 26 && date.month() == 12
-}
-
 /// Enum lising all possible holidays
 #[derive(Clone, Copy, Debug)]
 enum GermanStateHoliday {
@@ -114,44 +50,84 @@ enum GermanStateHoliday {
 impl GermanStateHoliday {
 
     fn from_date(date: Date) -> Option<Self> {
-        let easter_sunday = crate::easter::easter_date(date.year()).ok()?;
-
-        if check_newyear(&date) {
-            Some(Self::NewYearsDay)
-        } else if check_epiphany(&date) {
-            Some(Self::Epiphany)
-        } else if check_intwomansday(&date) {
-            Some(Self::InternationalWomensDay)
-        } else if check_goodfriday(&date, &easter_sunday) {
-            Some(Self::GoodFriday)
-        } else if check_eastermonday(&date, &easter_sunday) {
-            Some(Self::EasterMonday)
-        } else if check_labourday(&date) {
-            Some(Self::LabourDay)
-        } else if check_ascensionday(&date, &easter_sunday) {
-            Some(Self::AscensionDay)
-        } else if check_whitmonday(&date, &easter_sunday) {
-            Some(Self::WhitMonday)
-        } else if check_corpuschristi(&date, &easter_sunday) {
-            Some(Self::CorpusChristi)
-        } else if check_assumptionday(&date) {
-            Some(Self::AssumptionDay)
-        } else if check_childrenday(&date) {
-            Some(Self::WorldChildrensDay)
-        } else if check_unityday(&date) {
-            Some(Self::GermanUnityDay)
-        } else if check_reformationday(&date) {
-            Some(Self::ReformationDay)
-        } else if check_allsaintsday(&date) {
-            Some(Self::AllSaintsDay)
-        } else if check_repentence(&date) {
-            Some(Self::RepentanceAndPrayerDay)
-        } else if check_christmasday(&date) {
-            Some(Self::ChristmasDay)
-        } else if check_2ndchristmasday(&date) {
-            Some(Self::SecondDayOfChristmas)
-        } else {
-            None
+        let (yy, mm, dd) = date.to_ymd();
+        let days_from_easter = date.num_days_from_ce() - crate::easter::easter_num_days_from_ce(yy).ok()?;
+
+        // no movable holiday falls on a fixed one, except Ascension Day on Labour Day
+        match (mm, dd) {
+            (1, 1) => Some(Self::NewYearsDay),
+            (1, 6) => Some(Self::Epiphany),
+            (3, 8) => Some(Self::InternationalWomensDay),
+            (5, 1) => Some(Self::LabourDay),
+            (8, 15) => Some(Self::AssumptionDay),
+            (9, 20) => Some(Self::WorldChildrensDay),
+            (10, 3) => Some(Self::GermanUnityDay),
+            (10, 31) => Some(Self::ReformationDay),
+            (11, 1) => Some(Self::AllSaintsDay),
+            (12, 25) => Some(Self::ChristmasDay),
+            (12, 26) => Some(Self::SecondDayOfChristmas),
+            _ => match days_from_easter {
+                -2 => Some(Self::GoodFriday),
+                1 => Some(Self::EasterMonday),
+                39 => Some(Self::AscensionDay),
+                50 => Some(Self::WhitMonday),
+                60 => Some(Self::CorpusChristi),
+                _ if mm == 11 && check_repentence(&date) => Some(Self::RepentanceAndPrayerDay),
+                _ => None,
+            },
+        }
+    }
+
+    /// Returns the date of the holiday in `year`, or `None` if Easter is not defined for `year`.
+    fn date_in_year(&self, year: i32) -> Option<Date> {
+        let ymd = |mm, dd| Date::from_ymd(year, mm, dd).ok();
+        let easter_offset = |offset| crate::easter::easter_date(year).ok().map(|easter_sunday| easter_sunday.advance_days(offset));
+
+        match self {
+            Self::NewYearsDay => ymd(1, 1),
+            Self::Epiphany => ymd(1, 6),
+            Self::InternationalWomensDay => ymd(3, 8),
+            Self::GoodFriday => easter_offset(-2),
+            Self::EasterMonday => easter_offset(1),
+            Self::LabourDay => ymd(5, 1),
+            Self::AscensionDay => easter_offset(39),
+            Self::WhitMonday => easter_offset(50),
+            Self::CorpusChristi => easter_offset(60),
+            Self::AssumptionDay => ymd(8, 15),
+            Self::WorldChildrensDay => ymd(9, 20),
+            Self::GermanUnityDay => ymd(10, 3),
+            Self::ReformationDay => ymd(10, 31),
+            Self::AllSaintsDay => ymd(11, 1),
+            Self::RepentanceAndPrayerDay => {
+                // Wednesday before 23 November
+                let november_22 = ymd(11, 22)?;
+                let offset = (november_22.weekday().number_from_monday() + 7 - 3) % 7;
+                Some(november_22.advance_days(-offset))
+            }
+            Self::ChristmasDay => ymd(12, 25),
+            Self::SecondDayOfChristmas => ymd(12, 26),
+        }
+    }
+
+    const fn name(&self) -> &'static str {
+        match self {
+            Self::NewYearsDay => "Neujahrstag",
+            Self::Epiphany => "Heilige drei Könige",
+            Self::InternationalWomensDay => "Internationaler Frauentag",
+            Self::GoodFriday => "Karfreitag",
+            Self::EasterMonday => "Ostermontag",
+            Self::LabourDay => "Tag der Arbeit",
+            Self::AscensionDay => "Christi Himmelfahrt",
+            Self::WhitMonday => "Pfingstmontag",
+            Self::CorpusChristi => "Fronleichnam",
+            Self::AssumptionDay => "Mariä Himmelfahrt",
+            Self::WorldChildrensDay => "Weltkindertag",
+            Self::GermanUnityDay => "Tag der Deutschen Einheit",
+            Self::ReformationDay => "Reformationstag",
+            Self::AllSaintsDay => "Allerheiligen",
+            Self::RepentanceAndPrayerDay => "Buß- und Bettag",
+            Self::ChristmasDay => "Erster Weihnachtstag",
+            Self::SecondDayOfChristmas => "Zweiter Weihnachtstag",
         }
     }
 
@@ -372,6 +348,24 @@ impl HolidayCalendar for GermanState {
             false
         }
     }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        GermanStateHoliday::from_date(date)
+            .filter(|holiday| self.has_holiday(*holiday, date.year()))
+            .map(|holiday| holiday.name())
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        let mut dates: Vec<Date> = GermanStateHoliday::holidays()
+            .into_iter()
+            .filter(|holiday| self.has_holiday(*holiday, year))
+            .filter_map(|holiday| holiday.date_in_year(year))
+            .collect();
+
+        // a holiday that falls on the date of another one counts as the first one, like `from_date` does
+        dates.retain(|&date| self.is_holiday(date));
+        Some(dates)
+    }
 }
 
 /// This implementation considers a date a holiday if it is a holiday in any
@@ -385,6 +379,22 @@ impl HolidayCalendar for Vec<GermanState> {
             false
         }
     }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        GermanStateHoliday::from_date(date)
+            .filter(|holiday| self.iter().any(|state| state.has_holiday(*holiday, date.year())))
+            .map(|holiday| holiday.name())
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        let mut dates = Vec::new();
+
+        for state in self {
+            dates.extend(state.holidays_in_year(year)?);
+        }
+
+        Some(dates)
+    }
 }
 
 #[test]
@@ -0,0 +1,62 @@
+use crate::easter;
+use crate::HolidayCalendar;
+use crate::date::Date;
+
+/// TARGET2 closing days, on which EUR payments don't settle
+/// (<https://www.ecb.europa.eu/paym/target/target2/profuse/calendar/html/index.en.html>).
+///
+/// TARGET closes on New Year's Day, Good Friday, Easter Monday,
+/// Labour Day (1 May), Christmas Day and 26 December.
+/// In 1999 it closed only on New Year's Day and Christmas Day,
+/// and it also closed on 31 December in 1998, 1999 and 2001.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Target;
+
+fn target_holiday_name(date: Date) -> Option<&'static str> {
+    let (yy, mm, dd) = date.to_ymd();
+
+    match (mm, dd) {
+        (1, 1) => return Some("New Year's Day"),
+        (5, 1) if yy >= 2000 => return Some("Labour Day"),
+        (12, 25) => return Some("Christmas Day"),
+        (12, 26) if yy >= 2000 => return Some("Christmas Holiday"),
+        (12, 31) if yy == 1998 || yy == 1999 || yy == 2001 => return Some("Additional closing day"),
+        _ => {}
+    }
+
+    if yy < 2000 {
+        return None;
+    }
+
+    let easter_date = easter::easter_date(yy).ok()?;
+
+    match date - easter_date {
+        -2 => Some("Good Friday"),
+        1 => Some("Easter Monday"),
+        _ => None,
+    }
+}
+
+impl HolidayCalendar for Target {
+    fn is_holiday(&self, date: Date) -> bool {
+        target_holiday_name(date).is_some()
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        let mut dates: Vec<Date> = [(1, 1), (5, 1), (12, 25), (12, 26), (12, 31)]
+            .into_iter()
+            .map(|(mm, dd)| Date::from_ymd(year, mm, dd).expect("Valid date"))
+            .collect();
+
+        if let Ok(easter_date) = easter::easter_date(year) {
+            dates.extend([-2, 1].map(|offset| easter_date.advance_days(offset)));
+        }
+
+        dates.retain(|&date| self.is_holiday(date));
+        Some(dates)
+    }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        target_holiday_name(date)
+    }
+}
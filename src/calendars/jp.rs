@@ -0,0 +1,328 @@
+use crate::HolidayCalendar;
+use crate::calendars::us::find_weekday;
+use crate::date::{Date, Weekday};
+
+/// Japanese national holidays, as set by the National Holidays Act of 1948,
+/// including substitute holidays and citizen's holidays.
+/// There are no holidays before the Act came into force, on 20 July 1948.
+/// Equinox days are only known from 1900 to 2150, see [vernal_equinox_day].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JapanSettlement;
+
+/// Japan Exchange Group holidays (<https://www.jpx.co.jp>).
+/// The exchange closes on the national holidays and from 31 December to 3 January.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JPX;
+
+const NATIONAL_HOLIDAYS_ACT: Date = Date::from_ymd_const(1948, 7, 20);
+
+/// First holiday on a Sunday observed on the next day.
+const FIRST_SUBSTITUTE_HOLIDAY: Date = Date::from_ymd_const(1973, 4, 12);
+
+/// First day on which a day between two national holidays is a holiday.
+const FIRST_CITIZENS_HOLIDAY: Date = Date::from_ymd_const(1985, 12, 27);
+
+/// Since 2007, a holiday on a Sunday is observed on the next day that is not a holiday.
+/// Before, it was observed only on the next Monday if that day was not a holiday.
+const SUBSTITUTE_HOLIDAY_AMENDMENT_YEAR: i32 = 2007;
+
+/// One-off national holidays.
+const JP_SPECIAL_HOLIDAYS: [((i32, i32, i32), &str); 6] = [
+    ((1959, 4, 10), "Wedding of Crown Prince Akihito"),
+    ((1989, 2, 24), "Funeral of Emperor Showa"),
+    ((1990, 11, 12), "Enthronement Ceremony of Emperor Akihito"),
+    ((1993, 6, 9), "Wedding of Crown Prince Naruhito"),
+    ((2019, 5, 1), "Enthronement of Emperor Naruhito"),
+    ((2019, 10, 22), "Enthronement Ceremony of Emperor Naruhito"),
+];
+
+/// Day of the month of an equinox, from the approximation published by the
+/// National Astronomical Observatory of Japan.
+/// `base` is the day of the month in 1980, in millionths of a day, for each of the
+/// periods 1900-1979, 1980-2099 and 2100-2150.
+fn equinox_day(year: i32, base: [i64; 3]) -> Option<i32> {
+    let y = year as i64;
+
+    // the number of leap years since 1980 is counted from 1983 before 1980, truncated towards zero
+    let (base, leap_days) = match year {
+        1900..=1979 => (base[0], (y - 1983) / 4),
+        1980..=2099 => (base[1], (y - 1980) / 4),
+        2100..=2150 => (base[2], (y - 1980) / 4),
+        _ => return None,
+    };
+
+    Some(((base + 242_194 * (y - 1980) - 1_000_000 * leap_days) / 1_000_000) as i32)
+}
+
+/// Returns the date of the vernal equinox in Japan Standard Time, which is a holiday
+/// (Vernal Equinox Day). The date is given by an approximation of the astronomical equinox
+/// that is valid from 1900 to 2150; returns `None` for other years.
+///
+/// ```
+/// use bdays::calendars::jp::vernal_equinox_day;
+/// use bdays::date::Date;
+///
+/// assert_eq!(vernal_equinox_day(2025), Some(Date::from_ymd(2025, 3, 20).unwrap()));
+/// assert_eq!(vernal_equinox_day(2023), Some(Date::from_ymd(2023, 3, 21).unwrap()));
+/// assert_eq!(vernal_equinox_day(2151), None);
+/// ```
+pub fn vernal_equinox_day(year: i32) -> Option<Date> {
+    let dd = equinox_day(year, [20_835_700, 20_843_100, 21_851_000])?;
+    Some(Date::from_ymd(year, 3, dd).expect("Valid date"))
+}
+
+/// Returns the date of the autumnal equinox in Japan Standard Time, which is a holiday
+/// (Autumnal Equinox Day). The date is given by an approximation of the astronomical equinox
+/// that is valid from 1900 to 2150; returns `None` for other years.
+pub fn autumnal_equinox_day(year: i32) -> Option<Date> {
+    let dd = equinox_day(year, [23_258_800, 23_248_800, 24_248_800])?;
+    Some(Date::from_ymd(year, 9, dd).expect("Valid date"))
+}
+
+fn coming_of_age_day(yy: i32) -> Date {
+    match yy {
+        ..=1999 => Date::from_ymd(yy, 1, 15).expect("Valid date"),
+        // Happy Monday: second Monday of January
+        _ => find_weekday(Weekday::Monday, yy, 1, 2, true),
+    }
+}
+
+fn emperors_birthday(yy: i32) -> Option<Date> {
+    match yy {
+        ..=1988 => Some(Date::from_ymd(yy, 4, 29).expect("Valid date")),
+        1989..=2018 => Some(Date::from_ymd(yy, 12, 23).expect("Valid date")),
+        // the Emperor Akihito abdicated on 30 April 2019, after his birthday, and Naruhito's is in February
+        2019 => None,
+        _ => Some(Date::from_ymd(yy, 2, 23).expect("Valid date")),
+    }
+}
+
+fn marine_day(yy: i32) -> Option<Date> {
+    match yy {
+        ..=1995 => None,
+        1996..=2002 => Some(Date::from_ymd(yy, 7, 20).expect("Valid date")),
+        // moved next to the opening ceremony of the Tokyo Olympics
+        2020 => Some(Date::from_ymd(yy, 7, 23).expect("Valid date")),
+        2021 => Some(Date::from_ymd(yy, 7, 22).expect("Valid date")),
+        // Happy Monday: third Monday of July
+        _ => Some(find_weekday(Weekday::Monday, yy, 7, 3, true)),
+    }
+}
+
+fn mountain_day(yy: i32) -> Option<Date> {
+    match yy {
+        ..=2015 => None,
+        // moved next to the closing ceremony of the Tokyo Olympics
+        2020 => Some(Date::from_ymd(yy, 8, 10).expect("Valid date")),
+        2021 => Some(Date::from_ymd(yy, 8, 8).expect("Valid date")),
+        _ => Some(Date::from_ymd(yy, 8, 11).expect("Valid date")),
+    }
+}
+
+fn respect_for_the_aged_day(yy: i32) -> Option<Date> {
+    match yy {
+        ..=1965 => None,
+        1966..=2002 => Some(Date::from_ymd(yy, 9, 15).expect("Valid date")),
+        // Happy Monday: third Monday of September
+        _ => Some(find_weekday(Weekday::Monday, yy, 9, 3, true)),
+    }
+}
+
+fn sports_day(yy: i32) -> Option<Date> {
+    match yy {
+        ..=1965 => None,
+        1966..=1999 => Some(Date::from_ymd(yy, 10, 10).expect("Valid date")),
+        // moved to the day of the opening ceremony of the Tokyo Olympics
+        2020 => Some(Date::from_ymd(yy, 7, 24).expect("Valid date")),
+        2021 => Some(Date::from_ymd(yy, 7, 23).expect("Valid date")),
+        // Happy Monday: second Monday of October
+        _ => Some(find_weekday(Weekday::Monday, yy, 10, 2, true)),
+    }
+}
+
+/// Holidays listed by the National Holidays Act, and the one-off holidays set by special laws.
+fn national_holiday_name(date: Date) -> Option<&'static str> {
+    if date < NATIONAL_HOLIDAYS_ACT {
+        return None;
+    }
+
+    let ymd = date.to_ymd();
+
+    if let Some((_, name)) = JP_SPECIAL_HOLIDAYS.iter().find(|(special, _)| *special == ymd) {
+        return Some(name);
+    }
+
+    let (yy, mm, dd) = ymd;
+
+    match (mm, dd) {
+        (1, 1) => return Some("New Year's Day"),
+        (2, 11) if yy >= 1967 => return Some("National Foundation Day"),
+        (4, 29) if (1989..=2006).contains(&yy) => return Some("Greenery Day"),
+        (4, 29) if yy >= 2007 => return Some("Showa Day"),
+        (5, 3) => return Some("Constitution Memorial Day"),
+        (5, 4) if yy >= 2007 => return Some("Greenery Day"),
+        (5, 5) => return Some("Children's Day"),
+        (11, 3) => return Some("Culture Day"),
+        (11, 23) => return Some("Labour Thanksgiving Day"),
+        _ => {}
+    }
+
+    let date = Some(date);
+
+    if date == Some(coming_of_age_day(yy)) {
+        Some("Coming of Age Day")
+    } else if date == emperors_birthday(yy) {
+        Some("Emperor's Birthday")
+    } else if date == vernal_equinox_day(yy) {
+        Some("Vernal Equinox Day")
+    } else if date == marine_day(yy) {
+        Some("Marine Day")
+    } else if date == mountain_day(yy) {
+        Some("Mountain Day")
+    } else if date == respect_for_the_aged_day(yy) {
+        Some("Respect for the Aged Day")
+    } else if date == autumnal_equinox_day(yy) {
+        Some("Autumnal Equinox Day")
+    } else if date == sports_day(yy) {
+        if yy >= 2020 {
+            Some("Sports Day")
+        } else {
+            Some("Health and Sports Day")
+        }
+    } else {
+        None
+    }
+}
+
+fn is_national_holiday(date: Date) -> bool {
+    national_holiday_name(date).is_some()
+}
+
+/// A national holiday on a Sunday is observed on the next day.
+fn is_substitute_holiday(date: Date) -> bool {
+    if is_national_holiday(date) {
+        return false;
+    }
+
+    let mut previous = date.previous_date();
+
+    if date.year() < SUBSTITUTE_HOLIDAY_AMENDMENT_YEAR {
+        return previous >= FIRST_SUBSTITUTE_HOLIDAY && previous.weekday() == Weekday::Sunday && is_national_holiday(previous);
+    }
+
+    while is_national_holiday(previous) {
+        if previous.weekday() == Weekday::Sunday {
+            return true;
+        }
+
+        previous = previous.previous_date();
+    }
+
+    false
+}
+
+/// A day between two national holidays is a holiday.
+/// Before 2007, a Sunday between two national holidays was not a holiday.
+fn is_citizens_holiday(date: Date) -> bool {
+    date >= FIRST_CITIZENS_HOLIDAY
+        && (date.year() >= SUBSTITUTE_HOLIDAY_AMENDMENT_YEAR || date.weekday() != Weekday::Sunday)
+        && !is_national_holiday(date)
+        && is_national_holiday(date.previous_date())
+        && is_national_holiday(date.next_date())
+}
+
+fn jp_holiday_name(date: Date) -> Option<&'static str> {
+    if let Some(name) = national_holiday_name(date) {
+        Some(name)
+    } else if is_substitute_holiday(date) {
+        Some("Substitute Holiday")
+    } else if is_citizens_holiday(date) {
+        Some("Citizen's Holiday")
+    } else {
+        None
+    }
+}
+
+/// Dates in `yy` that may be a holiday, checked by `jp_holiday_name`.
+fn jp_holiday_candidates(yy: i32) -> Vec<Date> {
+    let mut national: Vec<Date> = [
+        (1, 1), (2, 11), (4, 29), (5, 3), (5, 4), (5, 5), (11, 3), (11, 23),
+    ]
+    .into_iter()
+    .map(|(mm, dd)| Date::from_ymd(yy, mm, dd).expect("Valid date"))
+    .collect();
+
+    national.push(coming_of_age_day(yy));
+
+    national.extend(
+        [
+            emperors_birthday(yy),
+            vernal_equinox_day(yy),
+            marine_day(yy),
+            mountain_day(yy),
+            respect_for_the_aged_day(yy),
+            autumnal_equinox_day(yy),
+            sports_day(yy),
+        ]
+        .into_iter()
+        .flatten(),
+    );
+
+    national.extend(
+        JP_SPECIAL_HOLIDAYS
+            .iter()
+            .filter(|((year, _, _), _)| *year == yy)
+            .map(|&((year, mm, dd), _)| Date::from_ymd(year, mm, dd).expect("Valid date")),
+    );
+
+    // substitute holidays follow at most two consecutive holidays, and citizen's holidays follow one
+    let mut dates = national.clone();
+    dates.extend(national.iter().flat_map(|date| (1..=3).map(|offset| date.advance_days(offset))));
+    dates.retain(|date| date.year() == yy);
+    dates
+}
+
+impl HolidayCalendar for JapanSettlement {
+    fn is_holiday(&self, date: Date) -> bool {
+        jp_holiday_name(date).is_some()
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        let mut dates = jp_holiday_candidates(year);
+        dates.retain(|&date| self.is_holiday(date));
+        Some(dates)
+    }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        jp_holiday_name(date)
+    }
+}
+
+fn jpx_holiday_name(date: Date) -> Option<&'static str> {
+    if let Some(name) = jp_holiday_name(date) {
+        return Some(name);
+    }
+
+    match (date.month(), date.day()) {
+        (12, 31) => Some("Year-end Holiday"),
+        (1, 2) | (1, 3) => Some("New Year Holiday"),
+        _ => None,
+    }
+}
+
+impl HolidayCalendar for JPX {
+    fn is_holiday(&self, date: Date) -> bool {
+        jpx_holiday_name(date).is_some()
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        let mut dates = jp_holiday_candidates(year);
+        dates.extend([(1, 2), (1, 3), (12, 31)].map(|(mm, dd)| Date::from_ymd(year, mm, dd).expect("Valid date")));
+        dates.retain(|&date| self.is_holiday(date));
+        Some(dates)
+    }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        jpx_holiday_name(date)
+    }
+}
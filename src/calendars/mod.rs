@@ -1,15 +1,255 @@
-use crate::{HolidayCalendar, is_weekday};
+use crate::{HolidayCalendar, weekdays_between};
 use crate::date::Date;
 
 /// Holiday Calendars for Brazil.
 pub mod brazil;
 
 /// Holiday Calendars for the United States.
+#[cfg_attr(test, allow(clippy::zero_prefixed_literal, clippy::bool_assert_comparison))]
 pub mod us;
 
 /// Holiday Calendars for Germany.
+#[cfg_attr(test, allow(clippy::bool_assert_comparison))]
 pub mod de;
 
+/// Holiday Calendars for the United Kingdom.
+pub mod uk;
+
+/// Holiday Calendars for the Eurozone.
+pub mod eu;
+
+/// Holiday Calendars for Japan.
+pub mod jp;
+
+/// Holiday Calendars for mainland China.
+pub mod cn;
+
+/// Holiday Calendars for Saudi Arabia.
+pub mod sa;
+
+/// Holiday Calendars for the United Arab Emirates.
+pub mod ae;
+
+/// Codes and descriptions of the built-in holiday calendars, as accepted by [from_code](from_code).
+pub const CALENDAR_CODES: &[(&str, &str)] = &[
+    ("WeekendsOnly", "Accounts only weekends"),
+    ("BRSettlement", "Brazilian banking holidays"),
+    ("BrazilExchange", "B3 Exchange holidays"),
+    ("BR-AC", "Brazil, Acre"),
+    ("BR-AL", "Brazil, Alagoas"),
+    ("BR-AP", "Brazil, Amapá"),
+    ("BR-AM", "Brazil, Amazonas"),
+    ("BR-BA", "Brazil, Bahia"),
+    ("BR-CE", "Brazil, Ceará"),
+    ("BR-DF", "Brazil, Distrito Federal"),
+    ("BR-ES", "Brazil, Espírito Santo"),
+    ("BR-GO", "Brazil, Goiás"),
+    ("BR-MA", "Brazil, Maranhão"),
+    ("BR-MT", "Brazil, Mato Grosso"),
+    ("BR-MS", "Brazil, Mato Grosso do Sul"),
+    ("BR-MG", "Brazil, Minas Gerais"),
+    ("BR-PA", "Brazil, Pará"),
+    ("BR-PB", "Brazil, Paraíba"),
+    ("BR-PR", "Brazil, Paraná"),
+    ("BR-PE", "Brazil, Pernambuco"),
+    ("BR-PI", "Brazil, Piauí"),
+    ("BR-RJ", "Brazil, Rio de Janeiro"),
+    ("BR-RN", "Brazil, Rio Grande do Norte"),
+    ("BR-RS", "Brazil, Rio Grande do Sul"),
+    ("BR-RO", "Brazil, Rondônia"),
+    ("BR-RR", "Brazil, Roraima"),
+    ("BR-SC", "Brazil, Santa Catarina"),
+    ("BR-SP", "Brazil, São Paulo"),
+    ("BR-SE", "Brazil, Sergipe"),
+    ("BR-TO", "Brazil, Tocantins"),
+    ("BR-SE-ARACAJU", "Brazil, Aracaju (SE)"),
+    ("BR-PA-BELEM", "Brazil, Belém (PA)"),
+    ("BR-MG-BELO_HORIZONTE", "Brazil, Belo Horizonte (MG)"),
+    ("BR-RR-BOA_VISTA", "Brazil, Boa Vista (RR)"),
+    ("BR-DF-BRASILIA", "Brazil, Brasília (DF)"),
+    ("BR-MS-CAMPO_GRANDE", "Brazil, Campo Grande (MS)"),
+    ("BR-MT-CUIABA", "Brazil, Cuiabá (MT)"),
+    ("BR-PR-CURITIBA", "Brazil, Curitiba (PR)"),
+    ("BR-SC-FLORIANOPOLIS", "Brazil, Florianópolis (SC)"),
+    ("BR-CE-FORTALEZA", "Brazil, Fortaleza (CE)"),
+    ("BR-GO-GOIANIA", "Brazil, Goiânia (GO)"),
+    ("BR-PB-JOAO_PESSOA", "Brazil, João Pessoa (PB)"),
+    ("BR-AP-MACAPA", "Brazil, Macapá (AP)"),
+    ("BR-AL-MACEIO", "Brazil, Maceió (AL)"),
+    ("BR-AM-MANAUS", "Brazil, Manaus (AM)"),
+    ("BR-RN-NATAL", "Brazil, Natal (RN)"),
+    ("BR-TO-PALMAS", "Brazil, Palmas (TO)"),
+    ("BR-RS-PORTO_ALEGRE", "Brazil, Porto Alegre (RS)"),
+    ("BR-RO-PORTO_VELHO", "Brazil, Porto Velho (RO)"),
+    ("BR-PE-RECIFE", "Brazil, Recife (PE)"),
+    ("BR-AC-RIO_BRANCO", "Brazil, Rio Branco (AC)"),
+    ("BR-RJ-RIO_DE_JANEIRO", "Brazil, Rio de Janeiro (RJ)"),
+    ("BR-BA-SALVADOR", "Brazil, Salvador (BA)"),
+    ("BR-MA-SAO_LUIS", "Brazil, São Luís (MA)"),
+    ("BR-SP-SAO_PAULO", "Brazil, São Paulo (SP)"),
+    ("BR-PI-TERESINA", "Brazil, Teresina (PI)"),
+    ("BR-ES-VITORIA", "Brazil, Vitória (ES)"),
+    ("USSettlement", "United States federal holidays"),
+    ("USSettlementDC", "United States federal holidays and Inauguration Day in the District of Columbia"),
+    ("NYSE", "New York Stock Exchange holidays"),
+    ("SIFMABond", "US government bond market holidays recommended by SIFMA"),
+    ("FederalReserve", "Federal Reserve Bank holidays"),
+    ("NERC", "NERC off-peak days"),
+    ("UKSettlement", "England and Wales bank holidays"),
+    ("ScotlandSettlement", "Scotland bank holidays"),
+    ("NorthernIrelandSettlement", "Northern Ireland bank holidays"),
+    ("LSE", "London Stock Exchange holidays"),
+    ("TARGET", "TARGET2 Eurozone settlement closing days"),
+    ("JapanSettlement", "Japanese national holidays"),
+    ("JPX", "Japan Exchange Group holidays"),
+    ("SSE", "Shanghai Stock Exchange holidays"),
+    ("CFETS", "China interbank market holidays, with working weekend days"),
+    ("Tadawul", "Saudi Exchange holidays, with a Friday and Saturday weekend"),
+    ("UAESettlement", "United Arab Emirates public holidays"),
+    ("DE", "Holidays in any German state"),
+    ("DE-BW", "Germany, Baden-Württemberg"),
+    ("DE-BY", "Germany, Bayern"),
+    ("DE-BE", "Germany, Berlin"),
+    ("DE-BB", "Germany, Brandenburg"),
+    ("DE-HB", "Germany, Bremen"),
+    ("DE-HH", "Germany, Hamburg"),
+    ("DE-HE", "Germany, Hessen"),
+    ("DE-MV", "Germany, Mecklenburg-Vorpommern"),
+    ("DE-NI", "Germany, Niedersachsen"),
+    ("DE-NW", "Germany, Nordrhein-Westfalen"),
+    ("DE-RP", "Germany, Rheinland-Pfalz"),
+    ("DE-SL", "Germany, Saarland"),
+    ("DE-SN", "Germany, Sachsen"),
+    ("DE-ST", "Germany, Sachsen-Anhalt"),
+    ("DE-SH", "Germany, Schleswig-Holstein"),
+    ("DE-TH", "Germany, Thüringen"),
+];
+
+/// Returns the built-in holiday calendar identified by `code`,
+/// or `None` if there's no such calendar. Codes are case-insensitive.
+/// See [CALENDAR_CODES](CALENDAR_CODES) for the available codes.
+///
+/// ```
+/// use bdays::HolidayCalendar;
+/// use bdays::date::Date;
+///
+/// let cal = bdays::calendars::from_code("BRSettlement").unwrap();
+/// assert!(cal.is_holiday(Date::from_ymd(2025, 11, 20).unwrap()));
+/// assert!(bdays::calendars::from_code("XX").is_none());
+/// ```
+pub fn from_code(code: &str) -> Option<Box<dyn HolidayCalendar + Send + Sync>> {
+    use brazil::{BrazilMunicipality, BrazilState};
+    use de::GermanState;
+
+    let calendar: Box<dyn HolidayCalendar + Send + Sync> = match code.to_ascii_uppercase().as_str() {
+        "WEEKENDSONLY" => Box::new(WeekendsOnly),
+        "BRSETTLEMENT" => Box::new(brazil::BRSettlement),
+        "BRAZILEXCHANGE" => Box::new(brazil::BrazilExchange),
+        "BR-AC" => Box::new(BrazilState::AC),
+        "BR-AL" => Box::new(BrazilState::AL),
+        "BR-AP" => Box::new(BrazilState::AP),
+        "BR-AM" => Box::new(BrazilState::AM),
+        "BR-BA" => Box::new(BrazilState::BA),
+        "BR-CE" => Box::new(BrazilState::CE),
+        "BR-DF" => Box::new(BrazilState::DF),
+        "BR-ES" => Box::new(BrazilState::ES),
+        "BR-GO" => Box::new(BrazilState::GO),
+        "BR-MA" => Box::new(BrazilState::MA),
+        "BR-MT" => Box::new(BrazilState::MT),
+        "BR-MS" => Box::new(BrazilState::MS),
+        "BR-MG" => Box::new(BrazilState::MG),
+        "BR-PA" => Box::new(BrazilState::PA),
+        "BR-PB" => Box::new(BrazilState::PB),
+        "BR-PR" => Box::new(BrazilState::PR),
+        "BR-PE" => Box::new(BrazilState::PE),
+        "BR-PI" => Box::new(BrazilState::PI),
+        "BR-RJ" => Box::new(BrazilState::RJ),
+        "BR-RN" => Box::new(BrazilState::RN),
+        "BR-RS" => Box::new(BrazilState::RS),
+        "BR-RO" => Box::new(BrazilState::RO),
+        "BR-RR" => Box::new(BrazilState::RR),
+        "BR-SC" => Box::new(BrazilState::SC),
+        "BR-SP" => Box::new(BrazilState::SP),
+        "BR-SE" => Box::new(BrazilState::SE),
+        "BR-TO" => Box::new(BrazilState::TO),
+        "BR-SE-ARACAJU" => Box::new(BrazilMunicipality::Aracaju),
+        "BR-PA-BELEM" => Box::new(BrazilMunicipality::Belem),
+        "BR-MG-BELO_HORIZONTE" => Box::new(BrazilMunicipality::BeloHorizonte),
+        "BR-RR-BOA_VISTA" => Box::new(BrazilMunicipality::BoaVista),
+        "BR-DF-BRASILIA" => Box::new(BrazilMunicipality::Brasilia),
+        "BR-MS-CAMPO_GRANDE" => Box::new(BrazilMunicipality::CampoGrande),
+        "BR-MT-CUIABA" => Box::new(BrazilMunicipality::Cuiaba),
+        "BR-PR-CURITIBA" => Box::new(BrazilMunicipality::Curitiba),
+        "BR-SC-FLORIANOPOLIS" => Box::new(BrazilMunicipality::Florianopolis),
+        "BR-CE-FORTALEZA" => Box::new(BrazilMunicipality::Fortaleza),
+        "BR-GO-GOIANIA" => Box::new(BrazilMunicipality::Goiania),
+        "BR-PB-JOAO_PESSOA" => Box::new(BrazilMunicipality::JoaoPessoa),
+        "BR-AP-MACAPA" => Box::new(BrazilMunicipality::Macapa),
+        "BR-AL-MACEIO" => Box::new(BrazilMunicipality::Maceio),
+        "BR-AM-MANAUS" => Box::new(BrazilMunicipality::Manaus),
+        "BR-RN-NATAL" => Box::new(BrazilMunicipality::Natal),
+        "BR-TO-PALMAS" => Box::new(BrazilMunicipality::Palmas),
+        "BR-RS-PORTO_ALEGRE" => Box::new(BrazilMunicipality::PortoAlegre),
+        "BR-RO-PORTO_VELHO" => Box::new(BrazilMunicipality::PortoVelho),
+        "BR-PE-RECIFE" => Box::new(BrazilMunicipality::Recife),
+        "BR-AC-RIO_BRANCO" => Box::new(BrazilMunicipality::RioBranco),
+        "BR-RJ-RIO_DE_JANEIRO" => Box::new(BrazilMunicipality::RioDeJaneiro),
+        "BR-BA-SALVADOR" => Box::new(BrazilMunicipality::Salvador),
+        "BR-MA-SAO_LUIS" => Box::new(BrazilMunicipality::SaoLuis),
+        "BR-SP-SAO_PAULO" => Box::new(BrazilMunicipality::SaoPaulo),
+        "BR-PI-TERESINA" => Box::new(BrazilMunicipality::Teresina),
+        "BR-ES-VITORIA" => Box::new(BrazilMunicipality::Vitoria),
+        "USSETTLEMENT" => Box::new(us::USSettlement),
+        "USSETTLEMENTDC" => Box::new(us::USSettlementDC),
+        "NYSE" => Box::new(us::NYSE),
+        "SIFMABOND" => Box::new(us::SIFMABond),
+        "FEDERALRESERVE" => Box::new(us::FederalReserve),
+        "NERC" => Box::new(us::NERC),
+        "UKSETTLEMENT" => Box::new(uk::UKSettlement),
+        "SCOTLANDSETTLEMENT" => Box::new(uk::ScotlandSettlement),
+        "NORTHERNIRELANDSETTLEMENT" => Box::new(uk::NorthernIrelandSettlement),
+        "LSE" => Box::new(uk::LSE),
+        "TARGET" => Box::new(eu::Target),
+        "JAPANSETTLEMENT" => Box::new(jp::JapanSettlement),
+        "JPX" => Box::new(jp::JPX),
+        "SSE" => Box::new(cn::SSE),
+        "CFETS" => Box::new(cn::CFETS),
+        "TADAWUL" => Box::new(sa::Tadawul::new()),
+        "UAESETTLEMENT" => Box::new(ae::UAESettlement::new()),
+        "DE" => Box::new(GermanState::ANY),
+        "DE-BW" => Box::new(GermanState::BW),
+        "DE-BY" => Box::new(GermanState::BY),
+        "DE-BE" => Box::new(GermanState::BE),
+        "DE-BB" => Box::new(GermanState::BB),
+        "DE-HB" => Box::new(GermanState::HB),
+        "DE-HH" => Box::new(GermanState::HH),
+        "DE-HE" => Box::new(GermanState::HE),
+        "DE-MV" => Box::new(GermanState::MV),
+        "DE-NI" => Box::new(GermanState::NI),
+        "DE-NW" => Box::new(GermanState::NW),
+        "DE-RP" => Box::new(GermanState::RP),
+        "DE-SL" => Box::new(GermanState::SL),
+        "DE-SN" => Box::new(GermanState::SN),
+        "DE-ST" => Box::new(GermanState::ST),
+        "DE-SH" => Box::new(GermanState::SH),
+        "DE-TH" => Box::new(GermanState::TH),
+        _ => return None,
+    };
+
+    Some(calendar)
+}
+
+#[test]
+fn test_calendar_codes() {
+    for (code, _) in CALENDAR_CODES {
+        assert!(from_code(code).is_some(), "{code}");
+        assert!(from_code(&code.to_lowercase()).is_some(), "{code}");
+    }
+
+    assert!(from_code("DE-XX").is_none());
+    assert!(from_code("").is_none());
+}
+
 /// The `WeekendsOnly` holiday calendar always returns `false` for method `is_holiday`.
 /// So `is_bday` method returns `false` only for weekend dates.
 pub struct WeekendsOnly;
@@ -20,41 +260,11 @@ impl HolidayCalendar for WeekendsOnly {
         false
     }
 
+    fn holidays_in_year(&self, _year: i32) -> Option<Vec<Date>> {
+        Some(Vec::new())
+    }
+
     fn bdays(&self, d0: Date, d1: Date) -> i32 {
-        if d0 == d1 {
-            0
-        } else {
-            let from: Date;
-            let to: Date;
-            let swapped: bool;
-
-            if d1 < d0 {
-                from = d1;
-                to = d0;
-                swapped = true;
-            } else {
-                from = d0;
-                to = d1;
-                swapped = false;
-            }
-
-            let whole_weeks = (to.julian_day_number() - from.julian_day_number()) / 7;
-            let mut result = whole_weeks * 5;
-            let mut current_date = from.advance_days(whole_weeks * 7);
-
-            while current_date < to {
-                if is_weekday(current_date) {
-                    result += 1;
-                }
-
-                current_date = current_date.next_date();
-            }
-
-            if swapped {
-                result = -result;
-            }
-
-            result
-        }
+        weekdays_between(d0, d1)
     }
 }
@@ -0,0 +1,168 @@
+use crate::HolidayCalendar;
+use crate::date::{Date, Weekday};
+use crate::hijri::{HijriCalendar, HijriError};
+
+/// Saudi Exchange holidays (<https://www.saudiexchange.sa>).
+///
+/// The exchange closes on the Eid al-Fitr and Eid al-Adha holidays set by the Labor Law,
+/// on National Day since 2005 and on Founding Day since 2022.
+/// Longer closures announced by the exchange in some years are not modelled.
+///
+/// The weekend is Thursday and Friday until 28 June 2013, and Friday and Saturday since then.
+///
+/// Hijri dates follow the month starts announced in Saudi Arabia from Ramadan 1438 AH (2017)
+/// to Dhu al-Hijjah 1447 AH (2026), and the tabular Islamic calendar otherwise,
+/// which may be a day or two apart from the announced dates.
+/// Further announcements can be added with [Tadawul::with_month_start].
+///
+/// ```
+/// use bdays::HolidayCalendar;
+/// use bdays::calendars::sa::Tadawul;
+/// use bdays::date::Date;
+///
+/// let cal = Tadawul::new();
+/// assert_eq!(cal.holiday_name(Date::from_ymd(2024, 4, 10).unwrap()), Some("Eid al-Fitr"));
+///
+/// // Friday is a weekend day, Sunday is a business day
+/// assert!(!cal.is_bday(Date::from_ymd(2024, 4, 19).unwrap()));
+/// assert!(cal.is_bday(Date::from_ymd(2024, 4, 21).unwrap()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tadawul {
+    hijri: HijriCalendar,
+}
+
+/// Year, month and day.
+type Ymd = (i32, i32, i32);
+
+/// Hijri year and month, and the Gregorian date of the first day of the month,
+/// as announced in Saudi Arabia for the months that hold the Eid holidays and for the Islamic New Year.
+pub(crate) const ANNOUNCED_MONTH_STARTS: [(i32, i32, Ymd); 33] = [
+    (1438, 9, (2017, 5, 27)),
+    (1438, 10, (2017, 6, 25)),
+    (1438, 12, (2017, 8, 23)),
+    (1439, 9, (2018, 5, 17)),
+    (1439, 10, (2018, 6, 15)),
+    (1439, 12, (2018, 8, 12)),
+    (1440, 9, (2019, 5, 6)),
+    (1440, 10, (2019, 6, 4)),
+    (1440, 12, (2019, 8, 2)),
+    (1441, 9, (2020, 4, 24)),
+    (1441, 10, (2020, 5, 24)),
+    (1441, 12, (2020, 7, 22)),
+    (1442, 9, (2021, 4, 13)),
+    (1442, 10, (2021, 5, 13)),
+    (1442, 12, (2021, 7, 11)),
+    (1443, 9, (2022, 4, 2)),
+    (1443, 10, (2022, 5, 2)),
+    (1443, 12, (2022, 6, 30)),
+    (1444, 9, (2023, 3, 23)),
+    (1444, 10, (2023, 4, 21)),
+    (1444, 12, (2023, 6, 19)),
+    (1445, 1, (2023, 7, 19)),
+    (1445, 9, (2024, 3, 11)),
+    (1445, 10, (2024, 4, 10)),
+    (1445, 12, (2024, 6, 7)),
+    (1446, 1, (2024, 7, 7)),
+    (1446, 9, (2025, 3, 1)),
+    (1446, 10, (2025, 3, 30)),
+    (1446, 12, (2025, 5, 28)),
+    (1447, 1, (2025, 6, 26)),
+    (1447, 9, (2026, 2, 18)),
+    (1447, 10, (2026, 3, 20)),
+    (1447, 12, (2026, 5, 18)),
+];
+
+/// Tabular Islamic calendar with the month starts in `ANNOUNCED_MONTH_STARTS`.
+pub(crate) fn announced_hijri_calendar() -> HijriCalendar {
+    ANNOUNCED_MONTH_STARTS
+        .iter()
+        .fold(HijriCalendar::new(), |calendar, &(year, month, (yy, mm, dd))| {
+            calendar
+                .with_month_start(year, month, Date::from_ymd(yy, mm, dd).expect("Valid date"))
+                .expect("Announced month start close to the tabular calendar")
+        })
+}
+
+impl Tadawul {
+
+    /// Creates the calendar with the month starts announced from 2017 to 2026.
+    pub fn new() -> Self {
+        Tadawul {
+            hijri: announced_hijri_calendar(),
+        }
+    }
+
+    /// Sets the first day of `month` of Hijri `year`, as announced.
+    /// See [HijriCalendar::with_month_start].
+    pub fn with_month_start(self, year: i32, month: i32, date: Date) -> Result<Self, HijriError> {
+        Ok(Tadawul {
+            hijri: self.hijri.with_month_start(year, month, date)?,
+        })
+    }
+
+    /// Returns the Hijri calendar used for the Eid holidays.
+    pub fn hijri_calendar(&self) -> &HijriCalendar {
+        &self.hijri
+    }
+
+    fn tadawul_holiday_name(&self, date: Date) -> Option<&'static str> {
+        let yy = date.year();
+
+        // since 2022, a holiday on Friday is observed on Thursday, and on Saturday on Sunday
+        let is_observed = |holiday: Date| {
+            date == holiday
+                || (yy >= 2022
+                    && match holiday.weekday() {
+                        Weekday::Friday => date == holiday.previous_date(),
+                        Weekday::Saturday => date == holiday.next_date(),
+                        _ => false,
+                    })
+        };
+
+        if yy >= 2005 && is_observed(Date::from_ymd(yy, 9, 23).expect("Valid date")) {
+            return Some("National Day");
+        } else if yy >= 2022 && is_observed(Date::from_ymd(yy, 2, 22).expect("Valid date")) {
+            return Some("Founding Day");
+        }
+
+        let hijri = self.hijri.to_hijri(date).ok()?;
+
+        match hijri.month {
+            9 | 10 => {
+                // the four days after 29 Ramadan
+                let days_from_ramadan_29 = date - self.hijri.to_date(hijri.year, 9, 29).ok()?;
+                (1..=4).contains(&days_from_ramadan_29).then_some("Eid al-Fitr")
+            }
+            12 if (9..=12).contains(&hijri.day) => Some("Eid al-Adha"),
+            _ => None,
+        }
+    }
+}
+
+impl Default for Tadawul {
+    fn default() -> Self {
+        Tadawul::new()
+    }
+}
+
+impl HolidayCalendar for Tadawul {
+    fn is_holiday(&self, date: Date) -> bool {
+        self.tadawul_holiday_name(date).is_some()
+    }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        self.tadawul_holiday_name(date)
+    }
+
+    fn is_weekend(&self, date: Date) -> bool {
+        let weekend_changed = Date::from_ymd_const(2013, 6, 29);
+
+        match date.weekday() {
+            Weekday::Thursday => date < weekend_changed,
+            Weekday::Friday => true,
+            Weekday::Saturday => date >= weekend_changed,
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use crate::easter;
+use crate::HolidayCalendar;
+use crate::calendars::us::find_weekday;
+use crate::date::{Date, Weekday};
+
+/// England and Wales bank holidays, on which GBP payments don't settle.
+/// Covers the bank holidays since 1971, as set by the Banking and Financial Dealings Act 1971.
+pub struct UKSettlement;
+
+/// Scotland bank holidays, since 1971.
+pub struct ScotlandSettlement;
+
+/// Northern Ireland bank holidays, since 1971.
+pub struct NorthernIrelandSettlement;
+
+/// London Stock Exchange holidays (<https://www.londonstockexchange.com>).
+/// The exchange closes on the England and Wales bank holidays.
+/// Christmas Eve and New Year's Eve are half trading days, so they are business days.
+pub struct LSE;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Region {
+    EnglandAndWales,
+    Scotland,
+    NorthernIreland,
+}
+
+/// A holiday that falls on a weekend is observed on the next Monday.
+fn substitute_day(date: Date) -> Date {
+    match date.weekday() {
+        Weekday::Saturday => date.advance_days(2),
+        Weekday::Sunday => date.next_date(),
+        _ => date,
+    }
+}
+
+/// Observed dates of two holidays on consecutive days, such as Christmas Day and Boxing Day.
+/// Either one on a weekend is observed two days later, which is the first weekday not taken by the other.
+fn substitute_days(first: Date) -> (Date, Date) {
+    let second = first.next_date();
+
+    let observed = |date: Date| match date.weekday() {
+        Weekday::Saturday | Weekday::Sunday => date.advance_days(2),
+        _ => date,
+    };
+
+    (observed(first), observed(second))
+}
+
+fn early_may_bank_holiday(yy: i32) -> Date {
+    match yy {
+        // moved to VE Day anniversaries
+        1995 | 2020 => Date::from_ymd(yy, 5, 8).expect("Valid date"),
+        _ => find_weekday(Weekday::Monday, yy, 5, 1, true),
+    }
+}
+
+fn spring_bank_holiday(yy: i32) -> Date {
+    match yy {
+        // moved next to the jubilee bank holidays
+        1977 => Date::from_ymd(yy, 6, 6).expect("Valid date"),
+        2002 | 2012 => Date::from_ymd(yy, 6, 4).expect("Valid date"),
+        2022 => Date::from_ymd(yy, 6, 2).expect("Valid date"),
+        _ => find_weekday(Weekday::Monday, yy, 5, 1, false),
+    }
+}
+
+fn summer_bank_holiday(yy: i32, region: Region) -> Date {
+    // first Monday of August in Scotland, last Monday elsewhere
+    find_weekday(Weekday::Monday, yy, 8, 1, region == Region::Scotland)
+}
+
+/// One-off bank holidays, across the United Kingdom.
+const UK_SPECIAL_HOLIDAYS: [((i32, i32, i32), &str); 10] = [
+    ((1973, 11, 14), "Wedding of Princess Anne and Mark Phillips"),
+    ((1977, 6, 7), "Silver Jubilee of Elizabeth II"),
+    ((1981, 7, 29), "Wedding of Prince Charles and Lady Diana Spencer"),
+    ((1999, 12, 31), "Millennium celebrations"),
+    ((2002, 6, 3), "Golden Jubilee of Elizabeth II"),
+    ((2011, 4, 29), "Wedding of Prince William and Catherine Middleton"),
+    ((2012, 6, 5), "Diamond Jubilee of Elizabeth II"),
+    ((2022, 6, 3), "Platinum Jubilee of Elizabeth II"),
+    ((2022, 9, 19), "State Funeral of Queen Elizabeth II"),
+    ((2023, 5, 8), "Coronation of King Charles III"),
+];
+
+fn uk_holiday_name(date: Date, region: Region) -> Option<&'static str> {
+    // only observed dates are holidays
+    if matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday) {
+        return None;
+    }
+
+    let ymd = date.to_ymd();
+
+    if let Some((_, name)) = UK_SPECIAL_HOLIDAYS.iter().find(|(special, _)| *special == ymd) {
+        return Some(name);
+    }
+
+    let (yy, _, _) = ymd;
+    let days_from_easter = date.num_days_from_ce() - easter::easter_num_days_from_ce(yy).ok()?;
+    let (christmas, boxing_day) = substitute_days(Date::from_ymd(yy, 12, 25).expect("Valid date"));
+
+    if region == Region::Scotland {
+        let (new_year, second_january) = substitute_days(Date::from_ymd(yy, 1, 1).expect("Valid date"));
+
+        if date == new_year {
+            return Some("New Year's Day");
+        } else if date == second_january {
+            return Some("2nd January");
+        }
+    } else if yy >= 1974 && date == substitute_day(Date::from_ymd(yy, 1, 1).expect("Valid date")) {
+        return Some("New Year's Day");
+    }
+
+    if region == Region::NorthernIreland && date == substitute_day(Date::from_ymd(yy, 3, 17).expect("Valid date")) {
+        Some("St Patrick's Day")
+    } else if days_from_easter == -2 {
+        Some("Good Friday")
+    } else if days_from_easter == 1 && region != Region::Scotland {
+        Some("Easter Monday")
+    } else if yy >= 1978 && date == early_may_bank_holiday(yy) {
+        Some("Early May bank holiday")
+    } else if yy >= 1971 && date == spring_bank_holiday(yy) {
+        Some("Spring bank holiday")
+    } else if region == Region::NorthernIreland && date == substitute_day(Date::from_ymd(yy, 7, 12).expect("Valid date")) {
+        Some("Battle of the Boyne")
+    } else if yy >= 1971 && date == summer_bank_holiday(yy, region) {
+        Some("Summer bank holiday")
+    } else if yy >= 2007 && region == Region::Scotland && date == substitute_day(Date::from_ymd(yy, 11, 30).expect("Valid date")) {
+        Some("St Andrew's Day")
+    } else if date == christmas {
+        Some("Christmas Day")
+    } else if date == boxing_day {
+        Some("Boxing Day")
+    } else {
+        None
+    }
+}
+
+/// Dates in `yy` that may be a holiday in any region, checked by `uk_holiday_name`.
+fn uk_holiday_candidates(yy: i32) -> Vec<Date> {
+    let mut dates: Vec<Date> = [
+        (1, 1), (1, 2), (1, 3), (1, 4),
+        (3, 17), (3, 18), (3, 19),
+        (7, 12), (7, 13), (7, 14),
+        (11, 30), (12, 1), (12, 2),
+        (12, 25), (12, 26), (12, 27), (12, 28),
+    ]
+    .into_iter()
+    .map(|(mm, dd)| Date::from_ymd(yy, mm, dd).expect("Valid date"))
+    .collect();
+
+    if let Ok(easter_date) = easter::easter_date(yy) {
+        dates.extend([-2, 1].map(|offset| easter_date.advance_days(offset)));
+    }
+
+    dates.extend([
+        early_may_bank_holiday(yy),
+        spring_bank_holiday(yy),
+        find_weekday(Weekday::Monday, yy, 8, 1, true),
+        find_weekday(Weekday::Monday, yy, 8, 1, false),
+    ]);
+
+    dates.extend(
+        UK_SPECIAL_HOLIDAYS
+            .iter()
+            .filter(|((year, _, _), _)| *year == yy)
+            .map(|&((year, mm, dd), _)| Date::from_ymd(year, mm, dd).expect("Valid date")),
+    );
+
+    dates
+}
+
+fn uk_holidays_in_year(year: i32, region: Region) -> Vec<Date> {
+    let mut dates = uk_holiday_candidates(year);
+    dates.retain(|&date| uk_holiday_name(date, region).is_some());
+    dates
+}
+
+impl HolidayCalendar for UKSettlement {
+    fn is_holiday(&self, date: Date) -> bool {
+        uk_holiday_name(date, Region::EnglandAndWales).is_some()
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        Some(uk_holidays_in_year(year, Region::EnglandAndWales))
+    }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        uk_holiday_name(date, Region::EnglandAndWales)
+    }
+}
+
+impl HolidayCalendar for ScotlandSettlement {
+    fn is_holiday(&self, date: Date) -> bool {
+        uk_holiday_name(date, Region::Scotland).is_some()
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        Some(uk_holidays_in_year(year, Region::Scotland))
+    }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        uk_holiday_name(date, Region::Scotland)
+    }
+}
+
+impl HolidayCalendar for NorthernIrelandSettlement {
+    fn is_holiday(&self, date: Date) -> bool {
+        uk_holiday_name(date, Region::NorthernIreland).is_some()
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        Some(uk_holidays_in_year(year, Region::NorthernIreland))
+    }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        uk_holiday_name(date, Region::NorthernIreland)
+    }
+}
+
+impl HolidayCalendar for LSE {
+    fn is_holiday(&self, date: Date) -> bool {
+        UKSettlement.is_holiday(date)
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        UKSettlement.holidays_in_year(year)
+    }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        UKSettlement.holiday_name(date)
+    }
+}
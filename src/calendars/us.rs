@@ -1,10 +1,33 @@
 use crate::date::{Date, Weekday};
+use crate::easter;
 use crate::HolidayCalendar;
 
-/// United States federal holidays.
+/// United States federal holidays, following the holiday laws in force each year.
 pub struct USSettlement;
 
-fn find_weekday(target_weekday: Weekday, yy: i32, mm: i32, occurrence: i32, ascending: bool) -> Date {
+/// United States federal holidays, and Inauguration Day,
+/// which is a holiday for federal employees in the District of Columbia.
+pub struct USSettlementDC;
+
+/// New York Stock Exchange holidays (<https://www.nyse.com/markets/hours-calendars>),
+/// since 1971, including the special closings.
+pub struct NYSE;
+
+/// US government bond market holidays, as recommended by SIFMA (<https://www.sifma.org>),
+/// including the special closings.
+///
+/// SIFMA also recommends early closes, which are business days. See [SIFMABond::is_early_close].
+pub struct SIFMABond;
+
+/// Federal Reserve Bank holidays (<https://www.frbservices.org/about/holiday-schedules>).
+/// Holidays on a Sunday are observed on the next Monday, and holidays on a Saturday are not moved.
+pub struct FederalReserve;
+
+/// NERC off-peak days for power trading (<https://www.nerc.com>).
+/// Holidays on a Sunday are observed on the next Monday, and holidays on a Saturday are not moved.
+pub struct NERC;
+
+pub(crate) fn find_weekday(target_weekday: Weekday, yy: i32, mm: i32, occurrence: i32, ascending: bool) -> Date {
 
     assert!(occurrence > 0);
 
@@ -18,7 +41,7 @@ fn find_weekday(target_weekday: Weekday, yy: i32, mm: i32, occurrence: i32, asce
         offset = ( anchor.weekday().number_from_monday() + 7 - target_weekday.number_from_monday() ) % 7;
     }
 
-    offset += (occurrence as i32 - 1) * 7;
+    offset += (occurrence - 1) * 7;
 
     if ascending {
         anchor.advance_days(offset)
@@ -72,7 +95,7 @@ fn test_find_weekday() {
 
 /// In the United States, if a holiday falls on Saturday, it's observed on the preceding Friday.
 /// If it falls on Sunday, it's observed on the next Monday.
-fn adjust_weekend_holidays_us(date: Date) -> Date {
+pub(crate) fn adjust_weekend_holidays_us(date: Date) -> Date {
     match date.weekday() {
         Weekday::Saturday => date.previous_date(),
         Weekday::Sunday => date.next_date(),
@@ -80,51 +103,493 @@ fn adjust_weekend_holidays_us(date: Date) -> Date {
     }
 }
 
-impl HolidayCalendar for USSettlement {
+/// Holidays on a Sunday are observed on the next Monday, and holidays on a Saturday are not moved.
+fn adjust_sunday_holidays_us(date: Date) -> Date {
+    match date.weekday() {
+        Weekday::Sunday => date.next_date(),
+        _ => date
+    }
+}
+
+/// Election Day, the Tuesday after the first Monday of November.
+fn election_day(yy: i32) -> Date {
+    find_weekday(Weekday::Monday, yy, 11, 1, true).next_date()
+}
+
+fn is_good_friday(date: Date) -> bool {
+    easter::easter_date(date.year()).is_ok_and(|easter_date| date == easter_date.advance_days(-2))
+}
+
+fn special_holiday_name(specials: &[((i32, i32, i32), &'static str)], date: Date) -> Option<&'static str> {
+    let ymd = date.to_ymd();
+    specials.iter().find(|(special, _)| *special == ymd).map(|(_, name)| *name)
+}
+
+/// Washington's Birthday, Memorial Day, Columbus Day and Veterans Day were moved to Mondays
+/// by the Uniform Monday Holiday Act, from 1971.
+const UNIFORM_MONDAY_HOLIDAY_ACT_YEAR: i32 = 1971;
+
+fn washingtons_birthday(yy: i32) -> Date {
+    if yy < UNIFORM_MONDAY_HOLIDAY_ACT_YEAR {
+        adjust_weekend_holidays_us(Date::from_ymd(yy, 2, 22).expect("Valid date"))
+    } else {
+        find_weekday(Weekday::Monday, yy, 2, 3, true)
+    }
+}
+
+fn memorial_day(yy: i32) -> Date {
+    if yy < UNIFORM_MONDAY_HOLIDAY_ACT_YEAR {
+        adjust_weekend_holidays_us(Date::from_ymd(yy, 5, 30).expect("Valid date"))
+    } else {
+        find_weekday(Weekday::Monday, yy, 5, 1, false)
+    }
+}
+
+fn columbus_day(yy: i32) -> Date {
+    if yy < UNIFORM_MONDAY_HOLIDAY_ACT_YEAR {
+        adjust_weekend_holidays_us(Date::from_ymd(yy, 10, 12).expect("Valid date"))
+    } else {
+        find_weekday(Weekday::Monday, yy, 10, 2, true)
+    }
+}
+
+fn veterans_day(yy: i32) -> Date {
+    // on the fourth Monday of October from 1971 to 1977, back to November 11 after that
+    if (UNIFORM_MONDAY_HOLIDAY_ACT_YEAR..1978).contains(&yy) {
+        find_weekday(Weekday::Monday, yy, 10, 4, true)
+    } else {
+        adjust_weekend_holidays_us(Date::from_ymd(yy, 11, 11).expect("Valid date"))
+    }
+}
+
+fn thanksgiving_day(yy: i32) -> Date {
+    match yy {
+        // the last Thursday of November until 1938, and the next to last one from 1939 to 1941
+        ..1939 => find_weekday(Weekday::Thursday, yy, 11, 1, false),
+        1939..1942 => find_weekday(Weekday::Thursday, yy, 11, 2, false),
+        _ => find_weekday(Weekday::Thursday, yy, 11, 4, true),
+    }
+}
+
+/// Inauguration Day is a holiday for federal employees in the District of Columbia since 1965,
+/// every four years on January 20, or on the next Monday if on a Sunday.
+fn inauguration_day(yy: i32) -> Option<Date> {
+    if yy >= 1965 && yy % 4 == 1 {
+        let date = Date::from_ymd(yy, 1, 20).expect("Valid date");
+
+        match date.weekday() {
+            Weekday::Saturday => None,
+            Weekday::Sunday => Some(date.next_date()),
+            _ => Some(date),
+        }
+    } else {
+        None
+    }
+}
+
+fn us_settlement_holiday_name(date: Date) -> Option<&'static str> {
+    let (yy, mm, dd) = date.to_ymd();
+
+    if
+        // New Year's Day
+        adjust_weekend_holidays_us(Date::from_ymd(yy, 1, 1).expect("Valid date")) == date
+        ||
+        // New Year's Day on the previous year when 1st Jan is Saturday
+        (mm == 12 && dd == 31 && date.weekday() == Weekday::Friday)
+    {
+        Some("New Year's Day")
+    } else if yy >= 1986 && adjust_weekend_holidays_us(find_weekday(Weekday::Monday, yy, 1, 3, true)) == date {
+        // signed into law in 1983, first observed in 1986
+        Some("Birthday of Martin Luther King, Jr.")
+    } else if washingtons_birthday(yy) == date {
+        Some("Washington's Birthday")
+    } else if memorial_day(yy) == date {
+        Some("Memorial Day")
+    } else if yy >= 2021 && adjust_weekend_holidays_us(Date::from_ymd(yy, 6, 19).expect("Valid date")) == date {
+        Some("Juneteenth National Independence Day")
+    } else if adjust_weekend_holidays_us(Date::from_ymd(yy, 7, 4).expect("Valid date")) == date {
+        Some("Independence Day")
+    } else if yy >= 1894 && find_weekday(Weekday::Monday, yy, 9, 1, true) == date {
+        Some("Labor Day")
+    } else if yy >= 1937 && columbus_day(yy) == date {
+        Some("Columbus Day")
+    } else if yy >= 1938 && veterans_day(yy) == date {
+        // Armistice Day until 1954
+        Some("Veterans Day")
+    } else if thanksgiving_day(yy) == date {
+        Some("Thanksgiving Day")
+    } else if adjust_weekend_holidays_us(Date::from_ymd(yy, 12, 25).expect("Valid date")) == date {
+        Some("Christmas Day")
+    } else {
+        None
+    }
+}
+
+/// Observed dates in `yy` of every holiday rule of `us_settlement_holiday_name`, checked by it.
+fn us_settlement_holiday_candidates(yy: i32) -> Vec<Date> {
+    let fixed = [(1, 1), (6, 19), (7, 4), (11, 11), (12, 25)]
+        .map(|(mm, dd)| adjust_weekend_holidays_us(Date::from_ymd(yy, mm, dd).expect("Valid date")));
+
+    let floating = [
+        (Weekday::Monday, 1, 3, true),
+        (Weekday::Monday, 2, 3, true),
+        (Weekday::Monday, 5, 1, false),
+        (Weekday::Monday, 9, 1, true),
+        (Weekday::Monday, 10, 2, true),
+        (Weekday::Thursday, 11, 4, true),
+    ]
+    .map(|(weekday, mm, occurrence, ascending)| find_weekday(weekday, yy, mm, occurrence, ascending));
+
+    let mut dates: Vec<Date> = fixed.into_iter().chain(floating).collect();
+
+    dates.extend([
+        washingtons_birthday(yy),
+        memorial_day(yy),
+        columbus_day(yy),
+        veterans_day(yy),
+        thanksgiving_day(yy),
+    ]);
+
+    // New Year's Day of the following year
+    dates.push(Date::from_ymd(yy, 12, 31).expect("Valid date"));
+    dates
+}
+
+/// Unscheduled NYSE closings.
+const NYSE_SPECIAL_CLOSINGS: &[((i32, i32, i32), &str)] = &[
+    ((1972, 12, 28), "Funeral of President Truman"),
+    ((1973, 1, 25), "Funeral of President Johnson"),
+    ((1977, 7, 14), "New York City blackout"),
+    ((1985, 9, 27), "Hurricane Gloria"),
+    ((1994, 4, 27), "Funeral of President Nixon"),
+    ((2001, 9, 11), "September 11 attacks"),
+    ((2001, 9, 12), "September 11 attacks"),
+    ((2001, 9, 13), "September 11 attacks"),
+    ((2001, 9, 14), "September 11 attacks"),
+    ((2004, 6, 11), "Funeral of President Reagan"),
+    ((2007, 1, 2), "Funeral of President Ford"),
+    ((2012, 10, 29), "Hurricane Sandy"),
+    ((2012, 10, 30), "Hurricane Sandy"),
+    ((2018, 12, 5), "Funeral of President George H. W. Bush"),
+    ((2025, 1, 9), "Funeral of President Carter"),
+];
+
+fn nyse_holiday_name(date: Date) -> Option<&'static str> {
+    let (yy, mm, dd) = date.to_ymd();
+
+    if let Some(name) = special_holiday_name(NYSE_SPECIAL_CLOSINGS, date) {
+        Some(name)
+    } else if adjust_sunday_holidays_us(Date::from_ymd(yy, 1, 1).expect("Valid date")) == date {
+        // not observed on the previous Friday when on a Saturday
+        Some("New Year's Day")
+    } else if yy >= 1998 && find_weekday(Weekday::Monday, yy, 1, 3, true) == date {
+        Some("Birthday of Martin Luther King, Jr.")
+    } else if find_weekday(Weekday::Monday, yy, 2, 3, true) == date {
+        Some("Washington's Birthday")
+    } else if mm <= 4 && is_good_friday(date) {
+        Some("Good Friday")
+    } else if find_weekday(Weekday::Monday, yy, 5, 1, false) == date {
+        Some("Memorial Day")
+    } else if yy >= 2022 && adjust_weekend_holidays_us(Date::from_ymd(yy, 6, 19).expect("Valid date")) == date {
+        Some("Juneteenth National Independence Day")
+    } else if adjust_weekend_holidays_us(Date::from_ymd(yy, 7, 4).expect("Valid date")) == date {
+        Some("Independence Day")
+    } else if find_weekday(Weekday::Monday, yy, 9, 1, true) == date {
+        Some("Labor Day")
+    } else if yy <= 1980 && yy % 4 == 0 && election_day(yy) == date {
+        Some("Presidential Election Day")
+    } else if find_weekday(Weekday::Thursday, yy, 11, 4, true) == date {
+        Some("Thanksgiving Day")
+    } else if mm == 12 && dd >= 24 && adjust_weekend_holidays_us(Date::from_ymd(yy, 12, 25).expect("Valid date")) == date {
+        Some("Christmas Day")
+    } else {
+        None
+    }
+}
+
+/// Unscheduled bond market closings recommended by SIFMA.
+const SIFMA_SPECIAL_CLOSINGS: &[((i32, i32, i32), &str)] = &[
+    ((2001, 9, 11), "September 11 attacks"),
+    ((2001, 9, 12), "September 11 attacks"),
+    ((2004, 6, 11), "Funeral of President Reagan"),
+    ((2007, 1, 2), "Funeral of President Ford"),
+    ((2012, 10, 30), "Hurricane Sandy"),
+    ((2018, 12, 5), "Funeral of President George H. W. Bush"),
+];
+
+/// Unscheduled early closes recommended by SIFMA.
+const SIFMA_SPECIAL_EARLY_CLOSES: &[(i32, i32, i32)] = &[
+    (2012, 10, 29),
+    (2025, 1, 9),
+];
+
+/// Years in which Good Friday is an early close instead of a holiday,
+/// as the employment report is released on that day.
+const SIFMA_GOOD_FRIDAY_EARLY_CLOSE_YEARS: &[i32] = &[2010, 2012, 2015, 2021, 2023, 2026];
+
+fn sifma_holiday_name(date: Date) -> Option<&'static str> {
+    let (yy, mm, _) = date.to_ymd();
+
+    if let Some(name) = special_holiday_name(SIFMA_SPECIAL_CLOSINGS, date) {
+        Some(name)
+    } else if adjust_sunday_holidays_us(Date::from_ymd(yy, 1, 1).expect("Valid date")) == date {
+        // not observed on the previous Friday when on a Saturday
+        Some("New Year's Day")
+    } else if yy >= 1986 && find_weekday(Weekday::Monday, yy, 1, 3, true) == date {
+        Some("Birthday of Martin Luther King, Jr.")
+    } else if find_weekday(Weekday::Monday, yy, 2, 3, true) == date {
+        Some("Washington's Birthday")
+    } else if mm <= 4 && !SIFMA_GOOD_FRIDAY_EARLY_CLOSE_YEARS.contains(&yy) && is_good_friday(date) {
+        Some("Good Friday")
+    } else if find_weekday(Weekday::Monday, yy, 5, 1, false) == date {
+        Some("Memorial Day")
+    } else if yy >= 2022 && adjust_weekend_holidays_us(Date::from_ymd(yy, 6, 19).expect("Valid date")) == date {
+        Some("Juneteenth National Independence Day")
+    } else if adjust_weekend_holidays_us(Date::from_ymd(yy, 7, 4).expect("Valid date")) == date {
+        Some("Independence Day")
+    } else if find_weekday(Weekday::Monday, yy, 9, 1, true) == date {
+        Some("Labor Day")
+    } else if find_weekday(Weekday::Monday, yy, 10, 2, true) == date {
+        Some("Columbus Day")
+    } else if adjust_weekend_holidays_us(Date::from_ymd(yy, 11, 11).expect("Valid date")) == date {
+        Some("Veterans Day")
+    } else if find_weekday(Weekday::Thursday, yy, 11, 4, true) == date {
+        Some("Thanksgiving Day")
+    } else if mm == 12 && adjust_weekend_holidays_us(Date::from_ymd(yy, 12, 25).expect("Valid date")) == date {
+        Some("Christmas Day")
+    } else {
+        None
+    }
+}
+
+impl SIFMABond {
+    /// Whether SIFMA recommends an early close of the bond market on `date`, usually at 2:00 p.m. Eastern Time.
+    ///
+    /// Early closes are recommended on the business day before Good Friday, Memorial Day, Independence Day,
+    /// Christmas Day and New Year's Day, on the day after Thanksgiving,
+    /// on Good Friday when it's not a holiday, and on some unscheduled dates.
+    ///
+    /// ```
+    /// use bdays::HolidayCalendar;
+    /// use bdays::calendars::us::SIFMABond;
+    /// use bdays::date::Date;
+    ///
+    /// let date = Date::from_ymd(2024, 11, 29).unwrap(); // day after Thanksgiving
+    /// assert!(SIFMABond.is_bday(date));
+    /// assert!(SIFMABond.is_early_close(date));
+    /// ```
+    pub fn is_early_close(&self, date: Date) -> bool {
+        if !self.is_bday(date) {
+            return false;
+        }
 
-    fn is_holiday(&self, date: Date) -> bool {
         let (yy, mm, dd) = date.to_ymd();
 
-        if
-            // New Year's Day
-            adjust_weekend_holidays_us(Date::from_ymd(yy, 1, 1).expect("Valid date")) == date
-            ||
-            // New Year's Day on the previous year when 1st Jan is Saturday
-            (mm == 12 && dd == 31 && date.weekday() == Weekday::Friday)
-            ||
-            // Birthday of Martin Luther King, Jr.
-            (yy >= 1983 && adjust_weekend_holidays_us(find_weekday(Weekday::Monday, yy, 1, 3, true)) == date)
-            ||
-            // Washington's Birthday
-            adjust_weekend_holidays_us(find_weekday(Weekday::Monday, yy, 2, 3, true)) == date
-            ||
-            // Memorial Day
-            adjust_weekend_holidays_us(find_weekday(Weekday::Monday, yy, 5, 1, false)) == date
-            ||
-            // Juneteenth
-            (yy >= 2021 && adjust_weekend_holidays_us(Date::from_ymd(yy, 6, 19).expect("Valid date")) == date )
-            ||
-            // Independence Day
-            adjust_weekend_holidays_us(Date::from_ymd(yy, 7, 4).expect("Valid date")) == date
-            ||
-            // Labor Day
-            adjust_weekend_holidays_us(find_weekday(Weekday::Monday, yy, 9, 1, true)) == date
-            ||
-            // Columbus Day
-            adjust_weekend_holidays_us(find_weekday(Weekday::Monday, yy, 10, 2, true)) == date
-            ||
-            // Veterans Day
-            adjust_weekend_holidays_us(Date::from_ymd(yy, 11, 11).expect("Valid date")) == date
-            ||
-            // Thanksgiving Day
-            adjust_weekend_holidays_us(find_weekday(Weekday::Thursday, yy, 11, 4, true)) == date
-            ||
-            // Christmas
-            adjust_weekend_holidays_us(Date::from_ymd(yy, 12, 25).expect("Valid date")) == date
-         {
-            return true
+        if SIFMA_SPECIAL_EARLY_CLOSES.contains(&(yy, mm, dd))
+            || find_weekday(Weekday::Thursday, yy, 11, 4, true).next_date() == date
+            || (mm <= 4 && is_good_friday(date))
+            || (mm <= 4 && is_good_friday(date.next_date()))
+        {
+            return true;
+        }
+
+        // the business day before the holidays
+        let mut next_date = date.next_date();
+
+        while !self.is_bday(next_date) {
+            if matches!(
+                sifma_holiday_name(next_date),
+                Some("Good Friday" | "Memorial Day" | "Independence Day" | "Christmas Day" | "New Year's Day")
+            ) {
+                return true;
+            }
+
+            next_date = next_date.next_date();
         }
 
         false
     }
 }
+
+fn federal_reserve_holiday_name(date: Date) -> Option<&'static str> {
+    let (yy, _, _) = date.to_ymd();
+
+    if adjust_sunday_holidays_us(Date::from_ymd(yy, 1, 1).expect("Valid date")) == date {
+        Some("New Year's Day")
+    } else if yy >= 1986 && find_weekday(Weekday::Monday, yy, 1, 3, true) == date {
+        Some("Birthday of Martin Luther King, Jr.")
+    } else if find_weekday(Weekday::Monday, yy, 2, 3, true) == date {
+        Some("Washington's Birthday")
+    } else if find_weekday(Weekday::Monday, yy, 5, 1, false) == date {
+        Some("Memorial Day")
+    } else if yy >= 2022 && adjust_sunday_holidays_us(Date::from_ymd(yy, 6, 19).expect("Valid date")) == date {
+        // enacted too close to June 19, 2021 to be observed that year
+        Some("Juneteenth National Independence Day")
+    } else if adjust_sunday_holidays_us(Date::from_ymd(yy, 7, 4).expect("Valid date")) == date {
+        Some("Independence Day")
+    } else if find_weekday(Weekday::Monday, yy, 9, 1, true) == date {
+        Some("Labor Day")
+    } else if find_weekday(Weekday::Monday, yy, 10, 2, true) == date {
+        Some("Columbus Day")
+    } else if adjust_sunday_holidays_us(Date::from_ymd(yy, 11, 11).expect("Valid date")) == date {
+        Some("Veterans Day")
+    } else if find_weekday(Weekday::Thursday, yy, 11, 4, true) == date {
+        Some("Thanksgiving Day")
+    } else if adjust_sunday_holidays_us(Date::from_ymd(yy, 12, 25).expect("Valid date")) == date {
+        Some("Christmas Day")
+    } else {
+        None
+    }
+}
+
+fn nerc_holiday_name(date: Date) -> Option<&'static str> {
+    let (yy, _, _) = date.to_ymd();
+
+    if adjust_sunday_holidays_us(Date::from_ymd(yy, 1, 1).expect("Valid date")) == date {
+        Some("New Year's Day")
+    } else if find_weekday(Weekday::Monday, yy, 5, 1, false) == date {
+        Some("Memorial Day")
+    } else if adjust_sunday_holidays_us(Date::from_ymd(yy, 7, 4).expect("Valid date")) == date {
+        Some("Independence Day")
+    } else if find_weekday(Weekday::Monday, yy, 9, 1, true) == date {
+        Some("Labor Day")
+    } else if find_weekday(Weekday::Thursday, yy, 11, 4, true) == date {
+        Some("Thanksgiving Day")
+    } else if adjust_sunday_holidays_us(Date::from_ymd(yy, 12, 25).expect("Valid date")) == date {
+        Some("Christmas Day")
+    } else {
+        None
+    }
+}
+
+/// Dates in `yy` that may be a holiday in any of the exchange calendars of this module.
+fn us_market_holiday_candidates(yy: i32) -> Vec<Date> {
+    let mut dates = us_settlement_holiday_candidates(yy);
+
+    // holidays on a Sunday fall on the same Monday in both adjustments,
+    // but holidays on a Saturday are not moved by `adjust_sunday_holidays_us`
+    dates.extend(
+        [(1, 1), (6, 19), (7, 4), (11, 11), (12, 25)]
+            .map(|(mm, dd)| Date::from_ymd(yy, mm, dd).expect("Valid date")),
+    );
+
+    if let Ok(easter_date) = easter::easter_date(yy) {
+        dates.push(easter_date.advance_days(-2));
+    }
+
+    dates.push(election_day(yy));
+
+    dates.extend(
+        NYSE_SPECIAL_CLOSINGS
+            .iter()
+            .chain(SIFMA_SPECIAL_CLOSINGS)
+            .filter(|((year, _, _), _)| *year == yy)
+            .map(|&((year, mm, dd), _)| Date::from_ymd(year, mm, dd).expect("Valid date")),
+    );
+
+    dates
+}
+
+impl HolidayCalendar for USSettlement {
+
+    fn is_holiday(&self, date: Date) -> bool {
+        us_settlement_holiday_name(date).is_some()
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        let mut dates = us_settlement_holiday_candidates(year);
+        dates.retain(|&date| date.year() == year && self.is_holiday(date));
+        Some(dates)
+    }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        us_settlement_holiday_name(date)
+    }
+}
+
+impl HolidayCalendar for USSettlementDC {
+
+    fn is_holiday(&self, date: Date) -> bool {
+        self.holiday_name(date).is_some()
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        let mut dates = us_settlement_holiday_candidates(year);
+        dates.extend(inauguration_day(year));
+        dates.retain(|&date| date.year() == year && self.is_holiday(date));
+        Some(dates)
+    }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        us_settlement_holiday_name(date)
+            .or_else(|| (inauguration_day(date.year()) == Some(date)).then_some("Inauguration Day"))
+    }
+}
+
+impl HolidayCalendar for NYSE {
+
+    fn is_holiday(&self, date: Date) -> bool {
+        nyse_holiday_name(date).is_some()
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        let mut dates = us_market_holiday_candidates(year);
+        dates.retain(|&date| date.year() == year && self.is_holiday(date));
+        Some(dates)
+    }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        nyse_holiday_name(date)
+    }
+}
+
+impl HolidayCalendar for SIFMABond {
+
+    fn is_holiday(&self, date: Date) -> bool {
+        sifma_holiday_name(date).is_some()
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        let mut dates = us_market_holiday_candidates(year);
+        dates.retain(|&date| date.year() == year && self.is_holiday(date));
+        Some(dates)
+    }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        sifma_holiday_name(date)
+    }
+}
+
+impl HolidayCalendar for FederalReserve {
+
+    fn is_holiday(&self, date: Date) -> bool {
+        federal_reserve_holiday_name(date).is_some()
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        let mut dates = us_market_holiday_candidates(year);
+        dates.retain(|&date| date.year() == year && self.is_holiday(date));
+        Some(dates)
+    }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        federal_reserve_holiday_name(date)
+    }
+}
+
+impl HolidayCalendar for NERC {
+
+    fn is_holiday(&self, date: Date) -> bool {
+        nerc_holiday_name(date).is_some()
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        let mut dates = us_market_holiday_candidates(year);
+        dates.retain(|&date| date.year() == year && self.is_holiday(date));
+        Some(dates)
+    }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        nerc_holiday_name(date)
+    }
+}
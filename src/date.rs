@@ -1,14 +1,26 @@
 use std::error;
 use std::fmt;
 use std::ops::Sub;
+use std::str::FromStr;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum Error {
     InvalidDate{
         year: i32,
         month: i32,
         day: i32,
-    }
+    },
+    InvalidOrdinalDate{
+        year: i32,
+        ordinal: i32,
+    },
+    InvalidIsoWeekDate{
+        year: i32,
+        week: i32,
+        weekday: Weekday,
+    },
+    InvalidFormat,
 }
 
 impl fmt::Display for Error {
@@ -19,7 +31,7 @@ impl fmt::Display for Error {
 
 impl error::Error for Error {}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Date {
     jdn: i32,
 }
@@ -37,7 +49,7 @@ pub enum Weekday {
 }
 
 impl Weekday {
-    pub fn from_u8(value: u8) -> Option<Self> {
+    pub const fn from_u8(value: u8) -> Option<Self> {
         match value {
             1 => Some(Weekday::Monday),
             2 => Some(Weekday::Tuesday),
@@ -50,18 +62,24 @@ impl Weekday {
         }
     }
 
-    pub fn number_from_monday(self) -> i32 {
+    pub const fn number_from_monday(self) -> i32 {
         self as i32
     }
 }
 
-fn is_leap_year(year: i32) -> bool {
+impl fmt::Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", *self)
+    }
+}
+
+const fn is_leap_year(year: i32) -> bool {
     (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
 }
 
 const DAYS_IN_MONTH: [i32; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
 
-fn days_in_month(year: i32, month: i32) -> i32 {
+const fn days_in_month(year: i32, month: i32) -> i32 {
     if month == 2 && is_leap_year(year) {
         29
     } else {
@@ -69,7 +87,43 @@ fn days_in_month(year: i32, month: i32) -> i32 {
     }
 }
 
-fn validate_date(year: i32, month: i32, day: i32) -> bool {
+const fn days_in_year(year: i32) -> i32 {
+    if is_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
+// Days in the year before the first day of `month`.
+const fn days_before_month(year: i32, month: i32) -> i32 {
+    let mut days = 0;
+    let mut m = 1;
+
+    while m < month {
+        days += days_in_month(year, m);
+        m += 1;
+    }
+
+    days
+}
+
+// Weekday of December 31 of `year`, as 0 for Sunday up to 6 for Saturday.
+const fn weekday_of_dec_31(year: i32) -> i32 {
+    let year = year as i64;
+    (year + year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400)).rem_euclid(7) as i32
+}
+
+// ISO 8601 years have 53 weeks when they start or end on a Thursday.
+const fn iso_weeks_in_year(year: i32) -> i32 {
+    if weekday_of_dec_31(year) == 4 || weekday_of_dec_31(year - 1) == 3 {
+        53
+    } else {
+        52
+    }
+}
+
+const fn validate_date(year: i32, month: i32, day: i32) -> bool {
     if month < 1 || month > 12 {
         return false;
     }
@@ -77,12 +131,20 @@ fn validate_date(year: i32, month: i32, day: i32) -> bool {
     day >= 1 && day <= days_in_month(year, month)
 }
 
+// Length of the 400-year Gregorian cycle, in days.
+const DAYS_IN_400_YEARS: i64 = 146_097;
+
 // Fliegel and van Flandern (1968) algorithm
 // https://aa.usno.navy.mil/faq/JD_formula
-fn jdn_to_ymd(jdn: i32) -> (i32, i32, i32) {
-    let mut l = (jdn as i64) + 68569;
+//
+// The algorithm only holds for non-negative JDNs, so earlier dates
+// are shifted forward by whole 400-year cycles and shifted back after.
+const fn jdn_to_ymd(jdn: i32) -> (i32, i32, i32) {
+    let cycles = if jdn < 0 { -(jdn as i64) / DAYS_IN_400_YEARS + 1 } else { 0 };
+
+    let mut l = (jdn as i64) + cycles * DAYS_IN_400_YEARS + 68569;
     let n = (4 * l) / 146097;
-    l = l - (146097 * n + 3) / 4;
+    l -= (146097 * n + 3) / 4;
     let i = (4000 * (l + 1)) / 1461001;
     l = l - (1461 * i) / 4 + 31;
     let j = (80 * l) / 2447;
@@ -90,111 +152,282 @@ fn jdn_to_ymd(jdn: i32) -> (i32, i32, i32) {
     let day = l - (2447 * j) / 80;
     l = j / 11;
     let month = j + 2 - 12 * l;
-    let year = 100 * (n - 49) + i + l;
+    let year = 100 * (n - 49) + i + l - 400 * cycles;
 
     (year as i32, month as i32, day as i32)
 }
 
-fn ymd_to_jdn(year: i32, month: i32, day: i32) -> i32 {
-    let year = year as i64;
+// Returns `None` if the date is out of the range of `Date`.
+const fn ymd_to_jdn(year: i32, month: i32, day: i32) -> Option<i32> {
     let month = month as i64;
     let day = day as i64;
 
     let a = (14 - month) / 12;
-    let y = year + 4800 - a;
+    let mut y = (year as i64) + 4800 - a;
     let m = month + 12 * a - 3;
 
+    // same as in `jdn_to_ymd`, the divisions below assume a non-negative `y`
+    let cycles = if y < 0 { -y / 400 + 1 } else { 0 };
+    y += 400 * cycles;
+
     let jdn = day
         + (153 * m + 2) / 5
         + 365 * y
         + y / 4
         - y / 100
         + y / 400
-        - 32045;
+        - 32045
+        - cycles * DAYS_IN_400_YEARS;
 
-    jdn.try_into().expect("Overflow")
+    if jdn < i32::MIN as i64 || jdn > i32::MAX as i64 {
+        None
+    } else {
+        Some(jdn as i32)
+    }
 }
 
 impl Date {
 
     const JDN_COMMON_ERA_OFFSET: i32 = 1721425;
 
-    pub fn from_ymd(year: i32, month: i32, day: i32) -> Result<Self, Error> {
+    /// The earliest representable date, `-5884323-05-15` in the proleptic Gregorian calendar.
+    pub const MIN: Date = Date { jdn: i32::MIN };
+
+    /// The latest representable date, `5874898-06-03` in the proleptic Gregorian calendar.
+    pub const MAX: Date = Date { jdn: i32::MAX };
+
+    /// Returns `Error::InvalidDate` if the date does not exist
+    /// or is out of the range `Date::MIN..=Date::MAX`.
+    pub const fn from_ymd(year: i32, month: i32, day: i32) -> Result<Self, Error> {
 
         if !validate_date(year, month, day) {
             return Err(Error::InvalidDate{year, month, day});
         }
 
-        Ok(
-            Date {
-                jdn: ymd_to_jdn(year, month, day),
-            }
-        )
+        match ymd_to_jdn(year, month, day) {
+            Some(jdn) => Ok(Date { jdn }),
+            None => Err(Error::InvalidDate{year, month, day}),
+        }
     }
 
-    pub(crate) fn from_julian_day_number(num_days: i32) -> Self {
+    /// Same as `from_ymd`, but panics if the date is invalid,
+    /// which is a compile error when used in a `const` context.
+    ///
+    /// ```
+    /// use bdays::date::Date;
+    ///
+    /// const SETTLE_CUTOVER: Date = Date::from_ymd_const(2022, 1, 3);
+    ///
+    /// static CLOSURES: [Date; 2] = [
+    ///     Date::from_ymd_const(2022, 9, 19),
+    ///     Date::from_ymd_const(2023, 5, 8),
+    /// ];
+    ///
+    /// assert_eq!(SETTLE_CUTOVER, Date::from_ymd(2022, 1, 3).unwrap());
+    /// assert!(CLOSURES.contains(&Date::from_ymd(2023, 5, 8).unwrap()));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use bdays::date::Date;
+    ///
+    /// const INVALID: Date = Date::from_ymd_const(2022, 2, 30);
+    /// ```
+    pub const fn from_ymd_const(year: i32, month: i32, day: i32) -> Self {
+        match Self::from_ymd(year, month, day) {
+            Ok(date) => date,
+            Err(_) => panic!("Invalid date"),
+        }
+    }
+
+    pub(crate) const fn from_julian_day_number(num_days: i32) -> Self {
         Date { jdn: num_days }
     }
 
-    pub fn from_num_days_from_ce(num_days: i32) -> Self {
+    pub const fn from_num_days_from_ce(num_days: i32) -> Self {
         Self::from_julian_day_number(num_days + Self::JDN_COMMON_ERA_OFFSET)
     }
 
-    pub(crate) fn julian_day_number(&self) -> i32 {
+    pub(crate) const fn julian_day_number(&self) -> i32 {
         self.jdn
     }
 
-    pub fn advance_days(&self, days: i32) -> Self {
-        Date{
-            jdn: self.jdn + days,
+    /// Panics if the result is out of the range `Date::MIN..=Date::MAX`.
+    /// See `checked_advance_days` and `saturating_advance_days`.
+    pub const fn advance_days(&self, days: i32) -> Self {
+        match self.checked_advance_days(days) {
+            Some(date) => date,
+            None => panic!("Date out of range"),
+        }
+    }
+
+    /// Returns `None` if the result is out of the range `Date::MIN..=Date::MAX`.
+    pub const fn checked_advance_days(&self, days: i32) -> Option<Self> {
+        match self.jdn.checked_add(days) {
+            Some(jdn) => Some(Date { jdn }),
+            None => None,
+        }
+    }
+
+    /// Clamps the result to `Date::MIN` or `Date::MAX`.
+    pub const fn saturating_advance_days(&self, days: i32) -> Self {
+        Date {
+            jdn: self.jdn.saturating_add(days),
         }
     }
 
-    pub fn previous_date(&self) -> Self {
+    /// Number of days from `other` to `self`, same as `self - other`,
+    /// or `None` if it does not fit in an `i32`.
+    pub const fn checked_sub(&self, other: Date) -> Option<i32> {
+        self.jdn.checked_sub(other.jdn)
+    }
+
+    pub const fn previous_date(&self) -> Self {
         self.advance_days(-1)
     }
 
-    pub fn next_date(&self) -> Self {
+    pub const fn next_date(&self) -> Self {
         self.advance_days(1)
     }
 
-    pub fn to_ymd(&self) -> (i32, i32, i32) {
+    pub const fn to_ymd(&self) -> (i32, i32, i32) {
         jdn_to_ymd(self.jdn)
     }
 
-    pub fn num_days_from_ce(&self) -> i32 {
+    pub const fn num_days_from_ce(&self) -> i32 {
         self.jdn - Self::JDN_COMMON_ERA_OFFSET
     }
 
-    pub fn weekday(&self) -> Weekday {
-        let weekday_number = self.jdn % 7 + 1;
+    pub const fn weekday(&self) -> Weekday {
+        let weekday_number = self.jdn.rem_euclid(7) + 1;
 
-        Weekday::from_u8(
-            weekday_number.try_into().unwrap(),
-        ).unwrap()
+        Weekday::from_u8(weekday_number as u8).unwrap()
     }
 
-    pub fn start_of_month(&self) -> Self {
+    pub const fn start_of_month(&self) -> Self {
         let (yy, mm, _) = self.to_ymd();
-        Self::from_ymd(yy, mm, 1).unwrap()
+        Self::from_ymd_const(yy, mm, 1)
     }
 
-    pub fn end_of_month(&self) -> Self {
+    pub const fn end_of_month(&self) -> Self {
         let (yy, mm, _) = self.to_ymd();
-        Self::from_ymd(yy, mm, days_in_month(yy, mm)).unwrap()
+        Self::from_ymd_const(yy, mm, days_in_month(yy, mm))
+    }
+
+    /// Returns `Error::InvalidOrdinalDate` if `ordinal` is not a day of `year`,
+    /// or if the date is out of the range `Date::MIN..=Date::MAX`.
+    pub const fn from_yo(year: i32, ordinal: i32) -> Result<Self, Error> {
+        if ordinal < 1 || ordinal > days_in_year(year) {
+            return Err(Error::InvalidOrdinalDate{year, ordinal});
+        }
+
+        let mut month = 1;
+        while ordinal > days_before_month(year, month) + days_in_month(year, month) {
+            month += 1;
+        }
+
+        match Self::from_ymd(year, month, ordinal - days_before_month(year, month)) {
+            Ok(date) => Ok(date),
+            Err(_) => Err(Error::InvalidOrdinalDate{year, ordinal}),
+        }
+    }
+
+    /// Day of the year, starting at 1 for January 1.
+    pub const fn ordinal(&self) -> i32 {
+        let (yy, mm, dd) = self.to_ymd();
+        days_before_month(yy, mm) + dd
+    }
+
+    /// Creates a date from an ISO 8601 week date, where week 1 is the week with the year's first Thursday.
+    ///
+    /// ```
+    /// use bdays::date::{Date, Weekday};
+    ///
+    /// let date = Date::from_isoywd(2025, 47, Weekday::Monday).unwrap();
+    /// assert_eq!(date, Date::from_ymd(2025, 11, 17).unwrap());
+    /// assert_eq!(date.iso_week(), (2025, 47, Weekday::Monday));
+    /// ```
+    ///
+    /// Returns `Error::InvalidIsoWeekDate` if `year` has no such week,
+    /// or if the date is out of the range `Date::MIN..=Date::MAX`.
+    pub const fn from_isoywd(year: i32, week: i32, weekday: Weekday) -> Result<Self, Error> {
+        if week < 1 || week > iso_weeks_in_year(year) {
+            return Err(Error::InvalidIsoWeekDate{year, week, weekday});
+        }
+
+        // days from the Monday of week 1 to January 1, which is between Dec 29 and Jan 4
+        let jan_1_offset = (weekday_of_dec_31(year - 1) + 3) % 7 - 3;
+        let ordinal = (week - 1) * 7 + weekday.number_from_monday() - jan_1_offset;
+
+        let year_jdn = match ymd_to_jdn(year, 1, 1) {
+            Some(jdn) => jdn as i64,
+            // January 1 may be out of range while the week date is not, so resolve from the end of the year
+            None => match ymd_to_jdn(year, 12, 31) {
+                Some(jdn) => jdn as i64 - days_in_year(year) as i64 + 1,
+                None => return Err(Error::InvalidIsoWeekDate{year, week, weekday}),
+            },
+        };
+
+        let jdn = year_jdn + ordinal as i64 - 1;
+
+        if jdn < i32::MIN as i64 || jdn > i32::MAX as i64 {
+            Err(Error::InvalidIsoWeekDate{year, week, weekday})
+        } else {
+            Ok(Date { jdn: jdn as i32 })
+        }
     }
 
-    pub fn year(&self) -> i32 {
+    /// Returns the ISO 8601 week date as `(iso_year, week, weekday)`.
+    /// The ISO year differs from `year()` for the days around January 1
+    /// that fall in a week of the adjacent year.
+    pub const fn iso_week(&self) -> (i32, i32, Weekday) {
+        let (yy, _, _) = self.to_ymd();
+        let weekday = self.weekday();
+        let week = (self.ordinal() - weekday.number_from_monday() + 10) / 7;
+
+        if week < 1 {
+            (yy - 1, iso_weeks_in_year(yy - 1), weekday)
+        } else if week > iso_weeks_in_year(yy) {
+            (yy + 1, 1, weekday)
+        } else {
+            (yy, week, weekday)
+        }
+    }
+
+    /// Quarter of the year, from 1 to 4.
+    pub const fn quarter(&self) -> i32 {
+        (self.month() - 1) / 3 + 1
+    }
+
+    pub const fn start_of_quarter(&self) -> Self {
+        let (yy, mm, _) = self.to_ymd();
+        Self::from_ymd_const(yy, mm - (mm - 1) % 3, 1)
+    }
+
+    pub const fn end_of_quarter(&self) -> Self {
+        let (yy, mm, _) = self.to_ymd();
+        let mm = mm - (mm - 1) % 3 + 2;
+        Self::from_ymd_const(yy, mm, days_in_month(yy, mm))
+    }
+
+    pub const fn start_of_year(&self) -> Self {
+        Self::from_ymd_const(self.year(), 1, 1)
+    }
+
+    pub const fn end_of_year(&self) -> Self {
+        Self::from_ymd_const(self.year(), 12, 31)
+    }
+
+    pub const fn year(&self) -> i32 {
         let (yy, _, _) = self.to_ymd();
         yy
     }
 
-    pub fn month(&self) -> i32 {
+    pub const fn month(&self) -> i32 {
         let (_, mm, _) = self.to_ymd();
         mm
     }
 
-    pub fn day(&self) -> i32 {
+    pub const fn day(&self) -> i32 {
         let (_, _, dd) = self.to_ymd();
         dd
     }
@@ -212,6 +445,28 @@ impl fmt::Display for Date {
     }
 }
 
+/// Parses dates in the `YYYY-MM-DD` format.
+impl FromStr for Date {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().splitn(3, '-');
+
+        let mut next_number = || -> Result<i32, Error> {
+            parts
+                .next()
+                .and_then(|part| part.parse::<i32>().ok())
+                .ok_or(Error::InvalidFormat)
+        };
+
+        let year = next_number()?;
+        let month = next_number()?;
+        let day = next_number()?;
+
+        Self::from_ymd(year, month, day)
+    }
+}
+
 #[test]
 fn test_sequential_jdn() {
     let mut previous_date: Option<Date> = None;
@@ -238,11 +493,16 @@ fn test_sequential_jdn() {
     }
 }
 
+/// Panics on overflow, which can only happen for dates more than `i32::MAX` days apart.
+/// See `Date::checked_sub`.
 impl Sub for Date {
     type Output = i32;
 
     fn sub(self, other: Self) -> Self::Output {
-        self.jdn - other.jdn
+        match self.checked_sub(other) {
+            Some(days) => days,
+            None => panic!("Overflow"),
+        }
     }
 }
 
@@ -257,7 +517,7 @@ fn test_sub() {
 
 #[test]
 fn test_reference_dates() {
-    assert_eq!(ymd_to_jdn(2000, 1, 1), 2451545);
+    assert_eq!(ymd_to_jdn(2000, 1, 1), Some(2451545));
 
     let date = Date::from_ymd(2000, 1, 1).unwrap();
     assert_eq!(date.julian_day_number(), 2451545);
@@ -283,6 +543,16 @@ fn test_dates_format() {
     assert_eq!(date.to_string(), "2026-06-22");
 }
 
+#[test]
+fn test_parse_date() {
+    assert_eq!("2026-06-22".parse::<Date>(), Ok(Date::from_ymd(2026, 6, 22).unwrap()));
+    assert_eq!(" 0001-01-01 ".parse::<Date>(), Ok(Date::from_ymd(1, 1, 1).unwrap()));
+    assert_eq!("2026-02-30".parse::<Date>(), Err(Error::InvalidDate{ year: 2026, month: 2, day: 30 }));
+    assert_eq!("2026-06".parse::<Date>(), Err(Error::InvalidFormat));
+    assert_eq!("2026/06/22".parse::<Date>(), Err(Error::InvalidFormat));
+    assert_eq!("".parse::<Date>(), Err(Error::InvalidFormat));
+}
+
 #[test]
 fn test_start_end_of_month() {
     assert_eq!( Date::from_ymd(2018, 11, 1).unwrap().end_of_month(), Date::from_ymd(2018, 11, 30).unwrap());
@@ -293,3 +563,253 @@ fn test_start_end_of_month() {
     assert_eq!( Date::from_ymd(2018, 12, 15).unwrap().start_of_month(), Date::from_ymd(2018, 12, 1).unwrap());
     assert_eq!( Date::from_ymd(2019, 1, 31).unwrap().start_of_month(), Date::from_ymd(2019, 1, 1).unwrap());
 }
+
+#[test]
+fn test_start_end_of_quarter_and_year() {
+    let date = Date::from_ymd(2024, 2, 29).unwrap();
+    assert_eq!(date.quarter(), 1);
+    assert_eq!(date.start_of_quarter(), Date::from_ymd(2024, 1, 1).unwrap());
+    assert_eq!(date.end_of_quarter(), Date::from_ymd(2024, 3, 31).unwrap());
+    assert_eq!(date.start_of_year(), Date::from_ymd(2024, 1, 1).unwrap());
+    assert_eq!(date.end_of_year(), Date::from_ymd(2024, 12, 31).unwrap());
+
+    let expected = [(1, 1), (1, 1), (1, 1), (2, 4), (2, 4), (2, 4), (3, 7), (3, 7), (3, 7), (4, 10), (4, 10), (4, 10)];
+
+    for (month, (quarter, first_month)) in (1..=12).zip(expected) {
+        let date = Date::from_ymd(2025, month, 15).unwrap();
+        assert_eq!(date.quarter(), quarter);
+        assert_eq!(date.start_of_quarter(), Date::from_ymd(2025, first_month, 1).unwrap());
+        assert_eq!(date.end_of_quarter(), Date::from_ymd(2025, first_month + 2, 1).unwrap().end_of_month());
+    }
+}
+
+#[test]
+fn test_ordinal() {
+    assert_eq!(Date::from_ymd(2025, 1, 1).unwrap().ordinal(), 1);
+    assert_eq!(Date::from_ymd(2025, 12, 31).unwrap().ordinal(), 365);
+    assert_eq!(Date::from_ymd(2024, 12, 31).unwrap().ordinal(), 366);
+    assert_eq!(Date::from_ymd(2024, 3, 1).unwrap().ordinal(), 61);
+    assert_eq!(Date::from_yo(2024, 61), Date::from_ymd(2024, 3, 1));
+    assert_eq!(Date::from_yo(2025, 366), Err(Error::InvalidOrdinalDate { year: 2025, ordinal: 366 }));
+    assert_eq!(Date::from_yo(2025, 0), Err(Error::InvalidOrdinalDate { year: 2025, ordinal: 0 }));
+
+    let mut date = Date::from_ymd(1899, 1, 1).unwrap();
+    let mut ordinal = 1;
+
+    while date.year() < 2101 {
+        assert_eq!(date.ordinal(), ordinal);
+        assert_eq!(Date::from_yo(date.year(), ordinal), Ok(date));
+
+        let next = date.next_date();
+        ordinal = if next.year() == date.year() { ordinal + 1 } else { 1 };
+        date = next;
+    }
+}
+
+#[test]
+fn test_iso_week() {
+    assert_eq!(Date::from_ymd(2025, 11, 19).unwrap().iso_week(), (2025, 47, Weekday::Wednesday));
+    assert_eq!(Date::from_ymd(2024, 12, 30).unwrap().iso_week(), (2025, 1, Weekday::Monday));
+    assert_eq!(Date::from_ymd(2021, 1, 3).unwrap().iso_week(), (2020, 53, Weekday::Sunday));
+    assert_eq!(Date::from_ymd(2026, 12, 31).unwrap().iso_week(), (2026, 53, Weekday::Thursday));
+    assert_eq!(Date::from_ymd(2027, 1, 1).unwrap().iso_week(), (2026, 53, Weekday::Friday));
+    assert_eq!(Date::from_isoywd(2026, 53, Weekday::Sunday), Date::from_ymd(2027, 1, 3));
+    assert_eq!(Date::from_isoywd(2020, 1, Weekday::Monday), Date::from_ymd(2019, 12, 30));
+    assert_eq!(
+        Date::from_isoywd(2025, 53, Weekday::Monday),
+        Err(Error::InvalidIsoWeekDate { year: 2025, week: 53, weekday: Weekday::Monday })
+    );
+    assert_eq!(
+        Date::from_isoywd(2025, 0, Weekday::Monday),
+        Err(Error::InvalidIsoWeekDate { year: 2025, week: 0, weekday: Weekday::Monday })
+    );
+
+    // weeks run from Monday to Sunday, and week 1 holds the year's first Thursday
+    let mut date = Date::from_ymd(1899, 1, 2).unwrap();
+    let mut expected = (1899, 1);
+
+    while date.year() < 2101 {
+        let (iso_year, week, weekday) = date.iso_week();
+        assert_eq!((iso_year, week), expected);
+        assert_eq!(weekday, date.weekday());
+        assert_eq!(Date::from_isoywd(iso_year, week, weekday), Ok(date));
+
+        if weekday == Weekday::Thursday {
+            assert_eq!(iso_year, date.year());
+        }
+
+        date = date.next_date();
+
+        if date.weekday() == Weekday::Monday {
+            let thursday = date.advance_days(3);
+            expected = if thursday.year() == iso_year { (iso_year, week + 1) } else { (iso_year + 1, 1) };
+        }
+    }
+}
+
+#[test]
+fn test_const_date() {
+    const DATE: Date = Date::from_ymd_const(2024, 2, 29);
+    const YMD: (i32, i32, i32) = DATE.to_ymd();
+    const WEEKDAY: Weekday = DATE.weekday();
+    const END_OF_MONTH: Date = DATE.start_of_month().advance_days(40).end_of_month();
+    const INVALID: Result<Date, Error> = Date::from_ymd(2023, 2, 29);
+
+    static TABLE: [Date; 3] = [
+        Date::from_ymd_const(2022, 1, 3),
+        Date::from_ymd_const(2022, 9, 19).next_date(),
+        Date::from_num_days_from_ce(738_000),
+    ];
+
+    assert_eq!(DATE, Date::from_ymd(2024, 2, 29).unwrap());
+    assert_eq!(YMD, (2024, 2, 29));
+    assert_eq!(WEEKDAY, Weekday::Thursday);
+    assert_eq!(END_OF_MONTH, Date::from_ymd(2024, 3, 31).unwrap());
+    assert_eq!(INVALID, Err(Error::InvalidDate { year: 2023, month: 2, day: 29 }));
+    assert_eq!(TABLE[1], Date::from_ymd(2022, 9, 20).unwrap());
+    assert_eq!(TABLE[2].num_days_from_ce(), 738_000);
+}
+
+#[test]
+#[should_panic(expected = "Invalid date")]
+fn test_from_ymd_const_invalid() {
+    let month = 13;
+    Date::from_ymd_const(2024, month, 1);
+}
+
+#[test]
+fn test_date_range() {
+    assert_eq!(Date::MIN.to_ymd(), (-5884323, 5, 15));
+    assert_eq!(Date::MAX.to_ymd(), (5874898, 6, 3));
+    assert_eq!(Date::from_ymd(-5884323, 5, 15), Ok(Date::MIN));
+    assert_eq!(Date::from_ymd(5874898, 6, 3), Ok(Date::MAX));
+    assert_eq!(Date::from_ymd(-5884323, 5, 14), Err(Error::InvalidDate { year: -5884323, month: 5, day: 14 }));
+    assert_eq!(Date::from_ymd(5874898, 6, 4), Err(Error::InvalidDate { year: 5874898, month: 6, day: 4 }));
+    assert_eq!(Date::from_ymd(i32::MAX, 12, 31), Err(Error::InvalidDate { year: i32::MAX, month: 12, day: 31 }));
+    assert_eq!(Date::from_ymd(i32::MIN, 1, 1), Err(Error::InvalidDate { year: i32::MIN, month: 1, day: 1 }));
+
+    // JDN 0 is Monday, November 24, 4714 BC
+    let jdn_zero = Date::from_julian_day_number(0);
+    assert_eq!(jdn_zero.to_ymd(), (-4713, 11, 24));
+    assert_eq!(jdn_zero.weekday(), Weekday::Monday);
+    assert_eq!(jdn_zero.previous_date().weekday(), Weekday::Sunday);
+    assert_eq!(jdn_zero.advance_days(-6).weekday(), Weekday::Tuesday);
+    assert_eq!(jdn_zero.advance_days(-7).weekday(), Weekday::Monday);
+    assert_eq!(Date::MIN.weekday(), Weekday::Saturday);
+    assert_eq!(Date::MAX.weekday(), Weekday::Tuesday);
+
+    let date = Date::from_ymd(2026, 6, 22).unwrap();
+    assert_eq!(date.checked_advance_days(10), Some(Date::from_ymd(2026, 7, 2).unwrap()));
+    assert_eq!(Date::MAX.checked_advance_days(1), None);
+    assert_eq!(Date::MIN.checked_advance_days(-1), None);
+    assert_eq!(Date::MAX.checked_advance_days(-1), Some(Date::MAX.previous_date()));
+    assert_eq!(date.saturating_advance_days(i32::MAX), Date::MAX);
+    assert_eq!(date.saturating_advance_days(i32::MIN), Date::from_julian_day_number(2461214 + i32::MIN));
+    assert_eq!(jdn_zero.advance_days(-2).saturating_advance_days(i32::MIN), Date::MIN);
+    assert_eq!(date.saturating_advance_days(-10), Date::from_ymd(2026, 6, 12).unwrap());
+
+    assert_eq!(Date::MAX.checked_sub(Date::MIN), None);
+    assert_eq!(Date::MIN.checked_sub(Date::MAX), None);
+    assert_eq!(Date::MIN.checked_sub(jdn_zero), Some(i32::MIN));
+    assert_eq!(Date::MAX.checked_sub(jdn_zero), Some(i32::MAX));
+}
+
+#[test]
+#[should_panic(expected = "Date out of range")]
+fn test_advance_days_out_of_range() {
+    Date::MAX.next_date();
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+proptest::proptest! {
+    #[test]
+    fn prop_ymd_round_trip(jdn: i32) {
+        let date = Date::from_julian_day_number(jdn);
+        let (y, m, d) = date.to_ymd();
+        assert!(validate_date(y, m, d));
+        assert_eq!(Date::from_ymd(y, m, d), Ok(date));
+    }
+
+    #[test]
+    fn prop_next_date_is_next_ymd(jdn in i32::MIN..i32::MAX) {
+        let date = Date::from_julian_day_number(jdn);
+        let (y, m, d) = date.to_ymd();
+
+        let expected = if d < days_in_month(y, m) {
+            (y, m, d + 1)
+        } else if m < 12 {
+            (y, m + 1, 1)
+        } else {
+            (y + 1, 1, 1)
+        };
+
+        assert_eq!(date.next_date().to_ymd(), expected);
+    }
+
+    #[test]
+    fn prop_weekday(jdn in i32::MIN..i32::MAX) {
+        let date = Date::from_julian_day_number(jdn);
+        let expected = date.weekday().number_from_monday() % 7 + 1;
+        assert_eq!(date.next_date().weekday().number_from_monday(), expected);
+    }
+
+    #[test]
+    fn prop_weekday_period(jdn: i32, weeks in -300_000_000i32..300_000_000) {
+        let date = Date::from_julian_day_number(jdn);
+
+        if let Some(other) = date.checked_advance_days(weeks * 7) {
+            assert_eq!(date.weekday(), other.weekday());
+        }
+    }
+
+    #[test]
+    fn prop_checked_advance_days(jdn: i32, days: i32) {
+        let date = Date::from_julian_day_number(jdn);
+        let expected = jdn as i64 + days as i64;
+
+        match date.checked_advance_days(days) {
+            Some(result) => {
+                assert_eq!(result.julian_day_number() as i64, expected);
+                assert_eq!(result.checked_sub(date), Some(days));
+                assert_eq!(result.saturating_advance_days(-days), date);
+            },
+            None => assert!(expected < i32::MIN as i64 || expected > i32::MAX as i64),
+        }
+
+        let saturated = date.saturating_advance_days(days);
+        assert_eq!(saturated.julian_day_number() as i64, expected.clamp(i32::MIN as i64, i32::MAX as i64));
+    }
+
+    #[test]
+    fn prop_checked_sub(jdn0: i32, jdn1: i32) {
+        let d0 = Date::from_julian_day_number(jdn0);
+        let d1 = Date::from_julian_day_number(jdn1);
+        let expected = jdn1 as i64 - jdn0 as i64;
+
+        match d1.checked_sub(d0) {
+            Some(days) => {
+                assert_eq!(days as i64, expected);
+                assert_eq!(d1 - d0, days);
+                assert_eq!(d0.advance_days(days), d1);
+            },
+            None => assert!(expected < i32::MIN as i64 || expected > i32::MAX as i64),
+        }
+    }
+
+    #[test]
+    fn prop_ordinal_and_iso_week_round_trip(jdn: i32) {
+        let date = Date::from_julian_day_number(jdn);
+        assert_eq!(Date::from_yo(date.year(), date.ordinal()), Ok(date));
+
+        let (iso_year, week, weekday) = date.iso_week();
+        assert_eq!(weekday, date.weekday());
+        assert_eq!(Date::from_isoywd(iso_year, week, weekday), Ok(date));
+    }
+
+    #[test]
+    fn prop_ordering_matches_ymd(jdn0: i32, jdn1: i32) {
+        let d0 = Date::from_julian_day_number(jdn0);
+        let d1 = Date::from_julian_day_number(jdn1);
+        assert_eq!(d0.cmp(&d1), d0.to_ymd().cmp(&d1.to_ymd()));
+    }
+}
@@ -28,14 +28,28 @@ impl fmt::Display for EasterError {
 
 impl error::Error for EasterError {}
 
-/// Returns easter date for year `y`
-/// as the number of days since January 1, Year 1 (aka Day 1) in the proleptic Gregorian calendar.
-pub fn easter_num_days_from_ce(y: i32) -> Result<i32, EasterError> {
-    // Algo R only works after 1582
-    if y < 1582 {
-        return Err(EasterError { y });
+/// First year of `EASTER_TABLE`.
+const TABLE_FIRST_YEAR: i32 = 1583;
+
+/// Last year of `EASTER_TABLE`.
+const TABLE_LAST_YEAR: i32 = 2999;
+
+/// Easter dates from `TABLE_FIRST_YEAR` to `TABLE_LAST_YEAR`,
+/// as the number of days since January 1, Year 1, computed at compile time.
+static EASTER_TABLE: [i32; (TABLE_LAST_YEAR - TABLE_FIRST_YEAR + 1) as usize] = {
+    let mut table = [0; (TABLE_LAST_YEAR - TABLE_FIRST_YEAR + 1) as usize];
+    let mut i = 0;
+
+    while i < table.len() {
+        table[i] = compute_easter_num_days_from_ce(TABLE_FIRST_YEAR + i as i32);
+        i += 1;
     }
 
+    table
+};
+
+/// Algo R, for years after 1582.
+const fn compute_easter_num_days_from_ce(y: i32) -> i32 {
     // Century
     let c = (y / 100) + 1;
 
@@ -48,13 +62,28 @@ pub fn easter_num_days_from_ce(y: i32) -> Result<i32, EasterError> {
     }
 
     // Paschal Moon
-    let p = Date::from_ymd(y, 4, 19)
-        .unwrap()
-        .num_days_from_ce()
-        - se;
+    let p = Date::from_ymd_const(y, 4, 19).num_days_from_ce() - se;
 
     // Easter: local the Sunday after the Paschal Moon
-    Ok(p + 7 - (p % 7))
+    p + 7 - (p % 7)
+}
+
+/// Returns easter date for year `y`
+/// as the number of days since January 1, Year 1 (aka Day 1) in the proleptic Gregorian calendar.
+///
+/// Years from 1583 to 2999 are looked up in a precomputed table,
+/// and other years are computed on every call.
+pub fn easter_num_days_from_ce(y: i32) -> Result<i32, EasterError> {
+    if (TABLE_FIRST_YEAR..=TABLE_LAST_YEAR).contains(&y) {
+        return Ok(EASTER_TABLE[(y - TABLE_FIRST_YEAR) as usize]);
+    }
+
+    // Algo R only works after 1582
+    if y < 1582 {
+        return Err(EasterError { y });
+    }
+
+    Ok(compute_easter_num_days_from_ce(y))
 }
 
 /// Returns easter date for year `y`
@@ -63,3 +92,28 @@ pub fn easter_date(y: i32) -> Result<Date, EasterError> {
     let rata = easter_num_days_from_ce(y)?;
     Ok(Date::from_num_days_from_ce(rata))
 }
+
+#[test]
+fn test_easter_table() {
+    // the table must match the algorithm as it was computed at runtime, with `Date`
+    fn easter_with_date(y: i32) -> i32 {
+        let c = (y / 100) + 1;
+        let mut se = (14 + 11 * (y % 19) - 3 * c / 4 + (5 + 8 * c) / 25) % 30;
+
+        if (se == 0) || ((se == 1) && (10 < (y % 19))) {
+            se += 1;
+        }
+
+        let p = Date::from_ymd(y, 4, 19).unwrap().num_days_from_ce() - se;
+        p + 7 - (p % 7)
+    }
+
+    for y in 1582..=3100 {
+        assert_eq!(easter_num_days_from_ce(y).unwrap(), easter_with_date(y), "{y}");
+    }
+
+    assert_eq!(easter_date(1583).unwrap(), Date::from_ymd(1583, 4, 10).unwrap());
+    assert_eq!(easter_date(2999).unwrap(), Date::from_ymd(2999, 3, 24).unwrap());
+    assert_eq!(easter_date(3000).unwrap(), Date::from_ymd(3000, 4, 13).unwrap());
+    assert!(easter_date(1581).is_err());
+}
@@ -0,0 +1,451 @@
+//! C ABI over the built-in calendars, for use from C and C++.
+//!
+//! A calendar is created from its code (see [calendars::CALENDAR_CODES](crate::calendars::CALENDAR_CODES))
+//! together with a range of dates, and is backed by a `HolidayCalendarCache` over that range.
+//! Dates are passed as `int32_t`, either as `YYYYMMDD` integers or as Julian Day Numbers,
+//! as chosen when creating the calendar.
+//!
+//! No function panics: every function returns a `BdaysStatus`,
+//! and results are written to the `out` pointer only on `BDAYS_OK`.
+//! The C header is `include/bdays.h`, generated with `cbindgen --config cbindgen.toml --output include/bdays.h`.
+
+use crate::calendars;
+use crate::date::Date;
+use crate::{HolidayCalendar, HolidayCalendarCache};
+use std::ffi::{CStr, c_char};
+
+/// Status code returned by every function.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BdaysStatus {
+    /// Success.
+    BdaysOk = 0,
+    /// A pointer argument is null.
+    BdaysNullPointer = 1,
+    /// There's no built-in calendar with the given code.
+    BdaysUnknownCalendar = 2,
+    /// A date argument is not a valid date.
+    BdaysInvalidDate = 3,
+    /// A date, or the resulting date, is out of the range of the calendar.
+    BdaysOutOfRange = 4,
+    /// The calendar code is not valid UTF-8, or the date format is unknown.
+    BdaysInvalidArgument = 5,
+}
+
+/// How dates are encoded as `int32_t`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BdaysDateFormat {
+    /// `year * 10000 + month * 100 + day`, such as `20251120`.
+    BdaysYyyymmdd = 0,
+    /// Julian Day Number, such as `2461000` for 2025-11-20.
+    BdaysJdn = 1,
+}
+
+impl BdaysDateFormat {
+    fn from_raw(value: i32) -> Option<Self> {
+        match value {
+            0 => Some(BdaysDateFormat::BdaysYyyymmdd),
+            1 => Some(BdaysDateFormat::BdaysJdn),
+            _ => None,
+        }
+    }
+}
+
+/// Opaque handle to a calendar, created by `bdays_calendar_new` and released by `bdays_calendar_free`.
+pub struct BdaysCalendar {
+    cache: HolidayCalendarCache,
+    date_format: BdaysDateFormat,
+}
+
+impl BdaysCalendar {
+    fn decode(&self, value: i32) -> Result<Date, BdaysStatus> {
+        decode_date(value, self.date_format)
+    }
+
+    fn encode(&self, date: Date) -> i32 {
+        match self.date_format {
+            BdaysDateFormat::BdaysYyyymmdd => {
+                let (year, month, day) = date.to_ymd();
+                year * 10000 + month * 100 + day
+            }
+            BdaysDateFormat::BdaysJdn => date.julian_day_number(),
+        }
+    }
+
+    fn decode_in_bounds(&self, value: i32) -> Result<Date, BdaysStatus> {
+        let date = self.decode(value)?;
+
+        if self.cache.is_date_in_bounds(date) {
+            Ok(date)
+        } else {
+            Err(BdaysStatus::BdaysOutOfRange)
+        }
+    }
+}
+
+// Years supported by the `YYYYMMDD` encoding, keeping every encoded date positive.
+const MIN_YEAR: i32 = 1;
+const MAX_YEAR: i32 = 9999;
+
+// Years of the range of a calendar, where every built-in calendar is defined.
+const MIN_CALENDAR_YEAR: i32 = 1583;
+
+fn check_calendar_range(date: Date) -> Result<Date, BdaysStatus> {
+    if date.year() >= MIN_CALENDAR_YEAR {
+        Ok(date)
+    } else {
+        Err(BdaysStatus::BdaysOutOfRange)
+    }
+}
+
+fn decode_date(value: i32, date_format: BdaysDateFormat) -> Result<Date, BdaysStatus> {
+    let date = match date_format {
+        BdaysDateFormat::BdaysYyyymmdd => {
+            let year = value / 10000;
+            let month = (value / 100) % 100;
+            let day = value % 100;
+
+            if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+                return Err(BdaysStatus::BdaysInvalidDate);
+            }
+
+            Date::from_ymd(year, month, day).map_err(|_| BdaysStatus::BdaysInvalidDate)?
+        }
+        BdaysDateFormat::BdaysJdn => Date::from_julian_day_number(value),
+    };
+
+    let min = Date::from_ymd(MIN_YEAR, 1, 1).expect("Valid date");
+    let max = Date::from_ymd(MAX_YEAR, 12, 31).expect("Valid date");
+
+    if min <= date && date <= max {
+        Ok(date)
+    } else {
+        Err(BdaysStatus::BdaysInvalidDate)
+    }
+}
+
+/// Writes `result` to `out`, or returns the error status.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+unsafe fn write_result<T>(result: Result<T, BdaysStatus>, out: *mut T) -> BdaysStatus {
+    if out.is_null() {
+        return BdaysStatus::BdaysNullPointer;
+    }
+
+    match result {
+        Ok(value) => {
+            unsafe { out.write(value) };
+            BdaysStatus::BdaysOk
+        }
+        Err(status) => status,
+    }
+}
+
+/// Creates a calendar from its `code`, such as `"BRSettlement"`,
+/// for dates in the range from `dt_min` to `dt_max`, encoded as `date_format`.
+/// On success, the new calendar is written to `out`.
+/// The range must be within the years 1583 to 9999, or `BDAYS_OUT_OF_RANGE` is returned.
+/// `date_format` is a `BdaysDateFormat` value, or `BDAYS_INVALID_ARGUMENT` is returned.
+///
+/// # Safety
+///
+/// `code` must be null or a NUL-terminated string, and `out` must be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn bdays_calendar_new(
+    code: *const c_char,
+    date_format: i32,
+    dt_min: i32,
+    dt_max: i32,
+    out: *mut *mut BdaysCalendar,
+) -> BdaysStatus {
+    if code.is_null() || out.is_null() {
+        return BdaysStatus::BdaysNullPointer;
+    }
+
+    let Ok(code) = unsafe { CStr::from_ptr(code) }.to_str() else {
+        return BdaysStatus::BdaysInvalidArgument;
+    };
+
+    let Some(date_format) = BdaysDateFormat::from_raw(date_format) else {
+        return BdaysStatus::BdaysInvalidArgument;
+    };
+
+    let result = calendars::from_code(code)
+        .ok_or(BdaysStatus::BdaysUnknownCalendar)
+        .and_then(|calendar| {
+            let dt_min = check_calendar_range(decode_date(dt_min, date_format)?)?;
+            let dt_max = check_calendar_range(decode_date(dt_max, date_format)?)?;
+
+            let calendar = BdaysCalendar {
+                cache: HolidayCalendarCache::new(calendar, dt_min, dt_max),
+                date_format,
+            };
+
+            Ok(Box::into_raw(Box::new(calendar)))
+        });
+
+    unsafe { write_result(result, out) }
+}
+
+/// Releases a calendar created by `bdays_calendar_new`. Does nothing if `calendar` is null.
+///
+/// # Safety
+///
+/// `calendar` must be null or a calendar created by `bdays_calendar_new` that was not released yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn bdays_calendar_free(calendar: *mut BdaysCalendar) {
+    if !calendar.is_null() {
+        drop(unsafe { Box::from_raw(calendar) });
+    }
+}
+
+/// Writes to `out` whether `date` is a holiday.
+///
+/// # Safety
+///
+/// `calendar` must be null or a live calendar, and `out` must be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn bdays_is_holiday(calendar: *const BdaysCalendar, date: i32, out: *mut bool) -> BdaysStatus {
+    let Some(calendar) = (unsafe { calendar.as_ref() }) else {
+        return BdaysStatus::BdaysNullPointer;
+    };
+
+    let result = calendar.decode_in_bounds(date).map(|date| calendar.cache.is_holiday(date));
+    unsafe { write_result(result, out) }
+}
+
+/// Writes to `out` whether `date` is a business day.
+///
+/// # Safety
+///
+/// `calendar` must be null or a live calendar, and `out` must be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn bdays_is_bday(calendar: *const BdaysCalendar, date: i32, out: *mut bool) -> BdaysStatus {
+    let Some(calendar) = (unsafe { calendar.as_ref() }) else {
+        return BdaysStatus::BdaysNullPointer;
+    };
+
+    let result = calendar.decode_in_bounds(date).map(|date| calendar.cache.is_bday(date));
+    unsafe { write_result(result, out) }
+}
+
+/// Writes to `out` the nearest business day to `date`,
+/// looking forward if `adjust_next` is true, backwards otherwise.
+///
+/// # Safety
+///
+/// `calendar` must be null or a live calendar, and `out` must be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn bdays_to_bday(
+    calendar: *const BdaysCalendar,
+    date: i32,
+    adjust_next: bool,
+    out: *mut i32,
+) -> BdaysStatus {
+    let Some(calendar) = (unsafe { calendar.as_ref() }) else {
+        return BdaysStatus::BdaysNullPointer;
+    };
+
+    let result = calendar.decode(date).and_then(|date| {
+        calendar
+            .cache
+            .checked_to_bday(date, adjust_next)
+            .map(|date| calendar.encode(date))
+            .ok_or(BdaysStatus::BdaysOutOfRange)
+    });
+
+    unsafe { write_result(result, out) }
+}
+
+/// Writes to `out` the date `bdays_count` business days after `date`,
+/// or before it if `bdays_count` is negative.
+///
+/// # Safety
+///
+/// `calendar` must be null or a live calendar, and `out` must be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn bdays_advance_bdays(
+    calendar: *const BdaysCalendar,
+    date: i32,
+    bdays_count: i32,
+    out: *mut i32,
+) -> BdaysStatus {
+    let Some(calendar) = (unsafe { calendar.as_ref() }) else {
+        return BdaysStatus::BdaysNullPointer;
+    };
+
+    let result = calendar.decode(date).and_then(|date| {
+        calendar
+            .cache
+            .checked_advance_bdays(date, bdays_count)
+            .map(|date| calendar.encode(date))
+            .ok_or(BdaysStatus::BdaysOutOfRange)
+    });
+
+    unsafe { write_result(result, out) }
+}
+
+/// Writes to `out` the number of business days between `d0` and `d1`.
+///
+/// # Safety
+///
+/// `calendar` must be null or a live calendar, and `out` must be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn bdays_bdays(calendar: *const BdaysCalendar, d0: i32, d1: i32, out: *mut i32) -> BdaysStatus {
+    let Some(calendar) = (unsafe { calendar.as_ref() }) else {
+        return BdaysStatus::BdaysNullPointer;
+    };
+
+    let result = calendar.decode(d0).and_then(|d0| {
+        let d1 = calendar.decode(d1)?;
+
+        calendar
+            .cache
+            .checked_bdays(d0, d1)
+            .ok_or(BdaysStatus::BdaysOutOfRange)
+    });
+
+    unsafe { write_result(result, out) }
+}
+
+/// Returns a static, NUL-terminated description of `status`.
+#[unsafe(no_mangle)]
+pub extern "C" fn bdays_status_message(status: BdaysStatus) -> *const c_char {
+    let message: &'static CStr = match status {
+        BdaysStatus::BdaysOk => c"ok",
+        BdaysStatus::BdaysNullPointer => c"null pointer argument",
+        BdaysStatus::BdaysUnknownCalendar => c"unknown calendar code",
+        BdaysStatus::BdaysInvalidDate => c"invalid date",
+        BdaysStatus::BdaysOutOfRange => c"date out of the range of the calendar",
+        BdaysStatus::BdaysInvalidArgument => c"invalid argument",
+    };
+
+    message.as_ptr()
+}
+
+#[test]
+fn test_ffi_yyyymmdd() {
+    let mut calendar: *mut BdaysCalendar = std::ptr::null_mut();
+
+    unsafe {
+        assert_eq!(
+            bdays_calendar_new(c"BRSettlement".as_ptr(), BdaysDateFormat::BdaysYyyymmdd as i32, 20000101, 20301231, &mut calendar),
+            BdaysStatus::BdaysOk
+        );
+        assert!(!calendar.is_null());
+
+        let mut flag = false;
+        assert_eq!(bdays_is_holiday(calendar, 20251120, &mut flag), BdaysStatus::BdaysOk);
+        assert!(flag);
+        assert_eq!(bdays_is_bday(calendar, 20251119, &mut flag), BdaysStatus::BdaysOk);
+        assert!(flag);
+        assert_eq!(bdays_is_bday(calendar, 20251122, &mut flag), BdaysStatus::BdaysOk);
+        assert!(!flag);
+
+        let mut value = 0;
+        assert_eq!(bdays_to_bday(calendar, 20251120, true, &mut value), BdaysStatus::BdaysOk);
+        assert_eq!(value, 20251121);
+        assert_eq!(bdays_to_bday(calendar, 20251120, false, &mut value), BdaysStatus::BdaysOk);
+        assert_eq!(value, 20251119);
+        assert_eq!(bdays_advance_bdays(calendar, 20251119, 2, &mut value), BdaysStatus::BdaysOk);
+        assert_eq!(value, 20251124);
+        assert_eq!(bdays_advance_bdays(calendar, 20251124, -2, &mut value), BdaysStatus::BdaysOk);
+        assert_eq!(value, 20251119);
+        assert_eq!(bdays_bdays(calendar, 20130206, 20130214, &mut value), BdaysStatus::BdaysOk);
+        assert_eq!(value, 4);
+
+        // errors leave `out` untouched
+        value = -1;
+        assert_eq!(bdays_bdays(calendar, 20130230, 20130214, &mut value), BdaysStatus::BdaysInvalidDate);
+        assert_eq!(bdays_advance_bdays(calendar, 0, 1, &mut value), BdaysStatus::BdaysInvalidDate);
+        assert_eq!(bdays_is_bday(calendar, 19991231, &mut flag), BdaysStatus::BdaysOutOfRange);
+        assert_eq!(bdays_advance_bdays(calendar, 20301220, 30, &mut value), BdaysStatus::BdaysOutOfRange);
+        assert_eq!(bdays_to_bday(calendar, 20000101, false, &mut value), BdaysStatus::BdaysOutOfRange);
+        assert_eq!(bdays_bdays(calendar, 20130206, 20130214, std::ptr::null_mut()), BdaysStatus::BdaysNullPointer);
+        assert_eq!(value, -1);
+
+        bdays_calendar_free(calendar);
+    }
+}
+
+#[test]
+fn test_ffi_jdn() {
+    let mut calendar: *mut BdaysCalendar = std::ptr::null_mut();
+
+    unsafe {
+        assert_eq!(
+            bdays_calendar_new(c"usSettlement".as_ptr(), BdaysDateFormat::BdaysJdn as i32, 2451545, 2469807, &mut calendar),
+            BdaysStatus::BdaysOk
+        );
+
+        // 2025-11-27, Thanksgiving Day
+        let thanksgiving = Date::from_ymd(2025, 11, 27).unwrap().julian_day_number();
+
+        let mut flag = false;
+        assert_eq!(bdays_is_holiday(calendar, thanksgiving, &mut flag), BdaysStatus::BdaysOk);
+        assert!(flag);
+
+        let mut value = 0;
+        assert_eq!(bdays_advance_bdays(calendar, thanksgiving - 1, 1, &mut value), BdaysStatus::BdaysOk);
+        assert_eq!(value, thanksgiving + 1);
+        assert_eq!(bdays_is_bday(calendar, 2451544, &mut flag), BdaysStatus::BdaysOutOfRange);
+
+        bdays_calendar_free(calendar);
+    }
+}
+
+#[test]
+fn test_ffi_errors() {
+    let mut calendar: *mut BdaysCalendar = std::ptr::null_mut();
+
+    unsafe {
+        assert_eq!(
+            bdays_calendar_new(c"XX".as_ptr(), BdaysDateFormat::BdaysYyyymmdd as i32, 20000101, 20301231, &mut calendar),
+            BdaysStatus::BdaysUnknownCalendar
+        );
+        assert_eq!(
+            bdays_calendar_new(c"BRSettlement".as_ptr(), BdaysDateFormat::BdaysYyyymmdd as i32, 20000101, 20301331, &mut calendar),
+            BdaysStatus::BdaysInvalidDate
+        );
+        assert_eq!(
+            bdays_calendar_new(c"BRSettlement".as_ptr(), BdaysDateFormat::BdaysJdn as i32, 0, 2451545, &mut calendar),
+            BdaysStatus::BdaysInvalidDate
+        );
+        // Easter-based calendars are not defined before 1583
+        assert_eq!(
+            bdays_calendar_new(c"BRSettlement".as_ptr(), BdaysDateFormat::BdaysYyyymmdd as i32, 15000101, 15001231, &mut calendar),
+            BdaysStatus::BdaysOutOfRange
+        );
+        assert_eq!(
+            bdays_calendar_new(c"UKSettlement".as_ptr(), BdaysDateFormat::BdaysYyyymmdd as i32, 15821231, 20001231, &mut calendar),
+            BdaysStatus::BdaysOutOfRange
+        );
+        assert_eq!(
+            bdays_calendar_new(c"BRSettlement".as_ptr(), BdaysDateFormat::BdaysJdn as i32, 2000000, 2451545, &mut calendar),
+            BdaysStatus::BdaysOutOfRange
+        );
+        assert_eq!(
+            bdays_calendar_new(std::ptr::null(), BdaysDateFormat::BdaysYyyymmdd as i32, 20000101, 20301231, &mut calendar),
+            BdaysStatus::BdaysNullPointer
+        );
+        assert_eq!(
+            bdays_calendar_new(c"BRSettlement".as_ptr(), 2, 20000101, 20301231, &mut calendar),
+            BdaysStatus::BdaysInvalidArgument
+        );
+        assert_eq!(
+            bdays_calendar_new(c"BRSettlement".as_ptr(), -1, 20000101, 20301231, &mut calendar),
+            BdaysStatus::BdaysInvalidArgument
+        );
+        assert!(calendar.is_null());
+
+        let mut flag = false;
+        assert_eq!(bdays_is_bday(std::ptr::null(), 20000101, &mut flag), BdaysStatus::BdaysNullPointer);
+        bdays_calendar_free(std::ptr::null_mut());
+
+        let message = CStr::from_ptr(bdays_status_message(BdaysStatus::BdaysOutOfRange));
+        assert_eq!(message.to_str().unwrap(), "date out of the range of the calendar");
+    }
+}
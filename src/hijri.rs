@@ -0,0 +1,350 @@
+use crate::date::Date;
+use std::error;
+use std::fmt;
+
+/// Error type for Hijri calendar conversion functions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HijriError {
+    /// The Hijri year is before 1 AH or after `LAST_YEAR`.
+    YearOutOfRange(i32),
+    /// The month or the day doesn't exist in the Hijri year, such as the 30th day of a 29-day month.
+    InvalidDate { year: i32, month: i32, day: i32 },
+    /// The first day of a month is more than `MAX_MONTH_START_DAYS` apart from the tabular calendar.
+    MonthStartTooFar { year: i32, month: i32, date: Date },
+}
+
+impl fmt::Display for HijriError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            HijriError::YearOutOfRange(year) => write!(
+                f,
+                "Couldn't convert Hijri date for year {year}. Only years 1 to {LAST_YEAR} AH are supported."
+            ),
+            HijriError::InvalidDate { year, month, day } => {
+                write!(f, "Invalid Hijri date: day {day} of month {month} of year {year}.")
+            }
+            HijriError::MonthStartTooFar { year, month, date } => write!(
+                f,
+                "Start of Hijri month {month} of year {year} on {date} is more than {MAX_MONTH_START_DAYS} days apart from the tabular calendar."
+            ),
+        }
+    }
+}
+
+impl error::Error for HijriError {}
+
+/// Last supported Hijri year, in the 10th millennium of the Gregorian calendar.
+pub const LAST_YEAR: i32 = 9666;
+
+/// Maximum number of days between a month start set in a [HijriCalendar]
+/// and the first day of the month in the tabular calendar.
+pub const MAX_MONTH_START_DAYS: i32 = 3;
+
+/// 1 Muharram 1 AH of the tabular calendar: 16 July 622 in the Julian calendar.
+const EPOCH: Date = Date::from_ymd_const(622, 7, 19);
+
+/// A date in the Hijri calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HijriDate {
+    pub year: i32,
+    /// From 1 (Muharram) to 12 (Dhu al-Hijjah).
+    pub month: i32,
+    pub day: i32,
+}
+
+impl fmt::Display for HijriDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02} AH", self.year, self.month, self.day)
+    }
+}
+
+fn check_year(year: i32) -> Result<(), HijriError> {
+    if (1..=LAST_YEAR).contains(&year) {
+        Ok(())
+    } else {
+        Err(HijriError::YearOutOfRange(year))
+    }
+}
+
+/// Returns `true` if `year` is a leap year of the tabular calendar,
+/// with 355 days instead of 354.
+/// Years 2, 5, 7, 10, 13, 16, 18, 21, 24, 26 and 29 of every 30-year cycle are leap years.
+pub fn is_tabular_leap_year(year: i32) -> bool {
+    (14 + 11 * year).rem_euclid(30) < 11
+}
+
+/// Days from `EPOCH` to the first day of `month` of `year` in the tabular calendar.
+fn tabular_month_offset(year: i32, month: i32) -> i32 {
+    29 * (month - 1) + (6 * month - 1) / 11 + (year - 1) * 354 + (3 + 11 * year).div_euclid(30)
+}
+
+fn tabular_month_days(year: i32, month: i32) -> i32 {
+    if month % 2 == 1 || (month == 12 && is_tabular_leap_year(year)) {
+        30
+    } else {
+        29
+    }
+}
+
+/// Returns the date of `day` of `month` of `year` in the tabular Islamic calendar,
+/// an arithmetical calendar with alternating months of 30 and 29 days, and a leap day
+/// at the end of 11 years of every 30-year cycle.
+/// The tabular calendar usually differs by a day or two from the calendars based on moon sightings,
+/// see [HijriCalendar] to use officially announced dates.
+///
+/// ```
+/// use bdays::date::Date;
+/// use bdays::hijri;
+///
+/// assert_eq!(hijri::tabular_date(1, 1, 1).unwrap(), Date::from_ymd(622, 7, 19).unwrap());
+/// assert_eq!(hijri::tabular_date(1445, 10, 1).unwrap(), Date::from_ymd(2024, 4, 10).unwrap());
+/// ```
+pub fn tabular_date(year: i32, month: i32, day: i32) -> Result<Date, HijriError> {
+    check_year(year)?;
+
+    if !(1..=12).contains(&month) || !(1..=tabular_month_days(year, month)).contains(&day) {
+        return Err(HijriError::InvalidDate { year, month, day });
+    }
+
+    Ok(EPOCH.advance_days(tabular_month_offset(year, month) + day - 1))
+}
+
+/// Returns the date of the tabular Islamic calendar that falls on `date`.
+/// See [tabular_date].
+pub fn to_tabular(date: Date) -> Result<HijriDate, HijriError> {
+    // days from the epoch don't fit in i32 near `Date::MIN` and `Date::MAX`, but years do
+    let offset = date.julian_day_number() as i64 - EPOCH.julian_day_number() as i64;
+    let year = (30 * offset + 10646).div_euclid(10631) as i32;
+    check_year(year)?;
+
+    // the year is supported, so the days from the epoch fit in i32
+    let offset = offset as i32;
+
+    let days_in_year = offset - tabular_month_offset(year, 1);
+    let month = (11 * days_in_year + 330) / 325;
+    let day = offset - tabular_month_offset(year, month) + 1;
+
+    Ok(HijriDate { year, month, day })
+}
+
+/// Converts between Hijri and Gregorian dates with the tabular Islamic calendar,
+/// except for the months whose first day was set from an official announcement,
+/// such as the Umm al-Qura calendar of Saudi Arabia, or a moon sighting.
+///
+/// A month ends on the day before the next month starts, so the month before
+/// an overridden month start may have 28 to 31 days.
+///
+/// ```
+/// use bdays::date::Date;
+/// use bdays::hijri::{HijriCalendar, HijriDate};
+///
+/// // Ramadan 1444 AH started on 23 March 2023 and lasted 29 days
+/// let calendar = HijriCalendar::new()
+///     .with_month_start(1444, 9, Date::from_ymd(2023, 3, 23).unwrap())?
+///     .with_month_start(1444, 10, Date::from_ymd(2023, 4, 21).unwrap())?;
+///
+/// assert_eq!(calendar.to_date(1444, 10, 1).unwrap(), Date::from_ymd(2023, 4, 21).unwrap());
+/// assert_eq!(
+///     calendar.to_hijri(Date::from_ymd(2023, 4, 20).unwrap()).unwrap(),
+///     HijriDate { year: 1444, month: 9, day: 29 }
+/// );
+/// # Ok::<(), bdays::hijri::HijriError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct HijriCalendar {
+    /// Sorted by year and month.
+    month_starts: Vec<(i32, i32, Date)>,
+}
+
+impl HijriCalendar {
+
+    /// Creates a calendar that follows the tabular Islamic calendar.
+    pub fn new() -> Self {
+        HijriCalendar {
+            month_starts: Vec::new(),
+        }
+    }
+
+    /// Sets the first day of `month` of `year`, replacing a previous one.
+    ///
+    /// Fails if the month doesn't exist, or if `date` is more than `MAX_MONTH_START_DAYS` days apart
+    /// from the first day of the month in the tabular calendar.
+    pub fn with_month_start(mut self, year: i32, month: i32, date: Date) -> Result<Self, HijriError> {
+        let tabular_start = tabular_date(year, month, 1)?;
+
+        if date.checked_sub(tabular_start).is_none_or(|days| days.abs() > MAX_MONTH_START_DAYS) {
+            return Err(HijriError::MonthStartTooFar { year, month, date });
+        }
+
+        match self.month_starts.binary_search_by_key(&(year, month), |&(y, m, _)| (y, m)) {
+            Ok(index) => self.month_starts[index].2 = date,
+            Err(index) => self.month_starts.insert(index, (year, month, date)),
+        }
+
+        Ok(self)
+    }
+
+    /// Returns the first day of `month` of `year`.
+    pub fn month_start(&self, year: i32, month: i32) -> Result<Date, HijriError> {
+        match self.month_starts.binary_search_by_key(&(year, month), |&(y, m, _)| (y, m)) {
+            Ok(index) => Ok(self.month_starts[index].2),
+            Err(_) => tabular_date(year, month, 1),
+        }
+    }
+
+    fn next_month_start(&self, year: i32, month: i32) -> Result<Date, HijriError> {
+        let next_month = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+
+        match self.month_starts.binary_search_by_key(&next_month, |&(y, m, _)| (y, m)) {
+            Ok(index) => Ok(self.month_starts[index].2),
+            Err(_) => Ok(tabular_date(year, month, 1)?.advance_days(tabular_month_days(year, month))),
+        }
+    }
+
+    /// Returns the number of days of `month` of `year`.
+    pub fn month_days(&self, year: i32, month: i32) -> Result<i32, HijriError> {
+        if !(1..=12).contains(&month) {
+            return Err(HijriError::InvalidDate { year, month, day: 1 });
+        }
+
+        Ok(self.next_month_start(year, month)? - self.month_start(year, month)?)
+    }
+
+    /// Returns the date of `day` of `month` of `year`.
+    pub fn to_date(&self, year: i32, month: i32, day: i32) -> Result<Date, HijriError> {
+        if !(1..=self.month_days(year, month)?).contains(&day) {
+            return Err(HijriError::InvalidDate { year, month, day });
+        }
+
+        Ok(self.month_start(year, month)?.advance_days(day - 1))
+    }
+
+    /// Returns the Hijri date that falls on `date`.
+    pub fn to_hijri(&self, date: Date) -> Result<HijriDate, HijriError> {
+        let tabular = to_tabular(date)?;
+
+        // month starts are at most 3 days apart from the tabular calendar,
+        // so `date` is in the same month as in the tabular calendar, or in an adjacent one
+        let (mut year, mut month) = (tabular.year, tabular.month);
+
+        if date < self.month_start(year, month)? {
+            (year, month) = if month == 1 { (year - 1, 12) } else { (year, month - 1) };
+        } else if date >= self.next_month_start(year, month)? {
+            (year, month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+        }
+
+        let day = date - self.month_start(year, month)? + 1;
+        Ok(HijriDate { year, month, day })
+    }
+}
+
+#[test]
+fn test_tabular() {
+    assert_eq!(to_tabular(EPOCH).unwrap(), HijriDate { year: 1, month: 1, day: 1 });
+    assert_eq!(to_tabular(EPOCH.previous_date()), Err(HijriError::YearOutOfRange(0)));
+    assert!(to_tabular(Date::MIN).is_err());
+    assert!(to_tabular(Date::MIN.advance_days(200)).is_err());
+    assert!(to_tabular(Date::MAX).is_err());
+    assert!(HijriCalendar::new().to_hijri(Date::MIN.advance_days(200)).is_err());
+    assert!(tabular_date(0, 12, 29).is_err());
+
+    let leap_years: Vec<i32> = (1..=30).filter(|&year| is_tabular_leap_year(year)).collect();
+    assert_eq!(leap_years, vec![2, 5, 7, 10, 13, 16, 18, 21, 24, 26, 29]);
+
+    // 10631 days in a 30-year cycle
+    assert_eq!(tabular_date(31, 1, 1).unwrap() - EPOCH, 10631);
+    assert_eq!(tabular_date(1445, 1, 1).unwrap(), Date::from_ymd(2023, 7, 19).unwrap());
+    assert_eq!(tabular_date(1446, 12, 30), Err(HijriError::InvalidDate { year: 1446, month: 12, day: 30 }));
+    assert!(tabular_date(1445, 12, 30).is_ok());
+    assert!(tabular_date(1447, 13, 1).is_err());
+
+    // round trip
+    let mut date = Date::from_ymd(1900, 1, 1).unwrap();
+    let mut previous = to_tabular(date.previous_date()).unwrap();
+
+    while date.year() <= 2200 {
+        let hijri = to_tabular(date).unwrap();
+        assert_eq!(tabular_date(hijri.year, hijri.month, hijri.day).unwrap(), date);
+        assert!(previous < hijri);
+        previous = hijri;
+        date = date.next_date();
+    }
+
+    let last = tabular_date(LAST_YEAR, 12, 29).unwrap();
+    assert_eq!(to_tabular(last).unwrap(), HijriDate { year: LAST_YEAR, month: 12, day: 29 });
+    assert_eq!(HijriCalendar::new().to_date(LAST_YEAR, 12, 29), Ok(last));
+    assert_eq!(HijriCalendar::new().to_date(LAST_YEAR + 1, 1, 1), Err(HijriError::YearOutOfRange(LAST_YEAR + 1)));
+    assert_eq!(HijriDate { year: 1445, month: 9, day: 1 }.to_string(), "1445-09-01 AH");
+}
+
+#[test]
+fn test_hijri_calendar_overrides() {
+    // Ramadan and Shawwal 1444 AH, as announced in Saudi Arabia
+    let calendar = HijriCalendar::new()
+        .with_month_start(1444, 9, Date::from_ymd(2023, 3, 23).unwrap())
+        .and_then(|calendar| calendar.with_month_start(1444, 10, Date::from_ymd(2023, 4, 21).unwrap()))
+        .unwrap();
+
+    // the tabular calendar starts Shawwal on 22 April
+    assert_eq!(to_tabular(Date::from_ymd(2023, 4, 21).unwrap()).unwrap(), HijriDate { year: 1444, month: 9, day: 30 });
+    assert_eq!(calendar.month_start(1444, 10).unwrap(), Date::from_ymd(2023, 4, 21).unwrap());
+    assert_eq!(calendar.month_start(1444, 11).unwrap(), tabular_date(1444, 11, 1).unwrap());
+
+    assert_eq!(calendar.month_days(1444, 9).unwrap(), 29);
+    assert_eq!(calendar.month_days(1444, 10).unwrap(), 30);
+    assert_eq!(calendar.to_date(1444, 10, 30).unwrap(), Date::from_ymd(2023, 5, 20).unwrap());
+    assert!(calendar.to_date(1444, 9, 30).is_err());
+    assert!(calendar.to_date(1444, 13, 1).is_err());
+    assert!(calendar.month_days(1444, 0).is_err());
+
+    assert_eq!(
+        calendar.to_hijri(Date::from_ymd(2023, 4, 21).unwrap()).unwrap(),
+        HijriDate { year: 1444, month: 10, day: 1 }
+    );
+    assert_eq!(
+        calendar.to_hijri(Date::from_ymd(2023, 4, 20).unwrap()).unwrap(),
+        HijriDate { year: 1444, month: 9, day: 29 }
+    );
+
+    // replaces an override
+    let calendar = calendar.with_month_start(1444, 10, Date::from_ymd(2023, 4, 22).unwrap()).unwrap();
+    assert_eq!(calendar.month_days(1444, 9).unwrap(), 30);
+
+    // round trip, across the overrides
+    let calendar = calendar
+        .with_month_start(1444, 12, Date::from_ymd(2023, 6, 19).unwrap())
+        .and_then(|calendar| calendar.with_month_start(1445, 1, Date::from_ymd(2023, 7, 19).unwrap()))
+        .unwrap();
+    let mut date = Date::from_ymd(2023, 1, 1).unwrap();
+
+    while date.year() == 2023 {
+        let hijri = calendar.to_hijri(date).unwrap();
+        assert_eq!(calendar.to_date(hijri.year, hijri.month, hijri.day).unwrap(), date);
+        date = date.next_date();
+    }
+}
+
+#[test]
+fn test_hijri_calendar_override_too_far() {
+    let date = Date::from_ymd(2024, 4, 20).unwrap();
+    assert_eq!(
+        HijriCalendar::new().with_month_start(1445, 10, date),
+        Err(HijriError::MonthStartTooFar { year: 1445, month: 10, date })
+    );
+    assert_eq!(
+        HijriCalendar::new().with_month_start(1445, 10, Date::MIN),
+        Err(HijriError::MonthStartTooFar { year: 1445, month: 10, date: Date::MIN })
+    );
+    assert_eq!(
+        HijriCalendar::new().with_month_start(1445, 13, date),
+        Err(HijriError::InvalidDate { year: 1445, month: 13, day: 1 })
+    );
+    assert_eq!(
+        HijriCalendar::new().with_month_start(0, 1, date),
+        Err(HijriError::YearOutOfRange(0))
+    );
+
+    let tabular_start = tabular_date(1445, 10, 1).unwrap();
+    assert!(HijriCalendar::new().with_month_start(1445, 10, tabular_start.advance_days(-MAX_MONTH_START_DAYS)).is_ok());
+    assert!(HijriCalendar::new().with_month_start(1445, 10, tabular_start.advance_days(MAX_MONTH_START_DAYS + 1)).is_err());
+}
@@ -0,0 +1,876 @@
+use crate::HolidayCalendar;
+use crate::date::{Date, Weekday};
+use crate::rules::{DateRule, HolidayRule, RuleCalendar};
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+const PRODID: &str = "-//bdays//Holiday Calendar//EN";
+
+/// Maximum length of a content line in octets, excluding the line break.
+const MAX_LINE_OCTETS: usize = 75;
+
+/// Formats `date` as an iCalendar `DATE` value (`YYYYMMDD`).
+fn format_date(date: Date) -> String {
+    let (y, m, d) = date.to_ymd();
+    format!("{y:04}{m:02}{d:02}")
+}
+
+/// Escapes a `TEXT` value.
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Derives the identifier used in event UIDs from the calendar name.
+fn uid_slug(calendar_name: &str) -> String {
+    let mut slug = String::with_capacity(calendar_name.len());
+
+    for c in calendar_name.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+
+    while slug.ends_with('-') {
+        slug.pop();
+    }
+
+    if slug.is_empty() {
+        slug.push_str("calendar");
+    }
+
+    slug
+}
+
+/// Writes a content line, folding it at 75 octets without splitting UTF-8 characters.
+fn write_line<W: Write>(writer: &mut W, line: &str) -> io::Result<()> {
+    let mut rest = line;
+    let mut limit = MAX_LINE_OCTETS;
+
+    while rest.len() > limit {
+        let mut split = limit;
+        while !rest.is_char_boundary(split) {
+            split -= 1;
+        }
+
+        writer.write_all(&rest.as_bytes()[..split])?;
+        writer.write_all(b"\r\n ")?;
+        rest = &rest[split..];
+
+        // continuation lines start with a space
+        limit = MAX_LINE_OCTETS - 1;
+    }
+
+    writer.write_all(rest.as_bytes())?;
+    writer.write_all(b"\r\n")
+}
+
+/// Writes the holidays of `calendar` from `d0` to `d1`, inclusive, as an iCalendar (RFC 5545) file.
+///
+/// Each holiday is written as an all-day `VEVENT` whose summary is the holiday name,
+/// when the calendar knows it. Event UIDs are derived from `calendar_name` and the date,
+/// so they are stable across exports, and the output is deterministic.
+pub fn write_ics<H, W>(
+    writer: &mut W,
+    calendar: &H,
+    calendar_name: &str,
+    mut d0: Date,
+    mut d1: Date,
+) -> io::Result<()>
+where
+    H: HolidayCalendar + ?Sized,
+    W: Write,
+{
+    if d0 > d1 {
+        (d0, d1) = (d1, d0);
+    }
+
+    let slug = uid_slug(calendar_name);
+
+    write_line(writer, "BEGIN:VCALENDAR")?;
+    write_line(writer, "VERSION:2.0")?;
+    write_line(writer, &format!("PRODID:{PRODID}"))?;
+    write_line(writer, "CALSCALE:GREGORIAN")?;
+    write_line(writer, "METHOD:PUBLISH")?;
+    write_line(writer, &format!("X-WR-CALNAME:{}", escape_text(calendar_name)))?;
+
+    let mut date = d0;
+    while date <= d1 {
+        if calendar.is_holiday(date) {
+            let start = format_date(date);
+            let summary = calendar.holiday_name(date).unwrap_or("Holiday");
+
+            write_line(writer, "BEGIN:VEVENT")?;
+            write_line(writer, &format!("UID:{start}-{slug}@bdays"))?;
+            // DTSTAMP is required. It's derived from the date to keep the output deterministic.
+            write_line(writer, &format!("DTSTAMP:{start}T000000Z"))?;
+            write_line(writer, &format!("DTSTART;VALUE=DATE:{start}"))?;
+            write_line(writer, &format!("DTEND;VALUE=DATE:{}", format_date(date.next_date())))?;
+            write_line(writer, &format!("SUMMARY:{}", escape_text(summary)))?;
+            write_line(writer, "TRANSP:TRANSPARENT")?;
+            write_line(writer, "END:VEVENT")?;
+        }
+
+        date = date.next_date();
+    }
+
+    write_line(writer, "END:VCALENDAR")
+}
+
+/// Returns the holidays of `calendar` from `d0` to `d1`, inclusive, as an iCalendar (RFC 5545) string.
+/// See [write_ics](write_ics) for details.
+pub fn to_ics<H>(calendar: &H, calendar_name: &str, d0: Date, d1: Date) -> String
+where
+    H: HolidayCalendar + ?Sized,
+{
+    let mut buffer: Vec<u8> = Vec::new();
+
+    write_ics(&mut buffer, calendar, calendar_name, d0, d1)
+        .expect("Writing to a Vec never fails");
+
+    String::from_utf8(buffer).expect("Output is valid UTF-8")
+}
+
+/// Error type for importing iCalendar files.
+#[derive(Debug)]
+pub enum ImportError {
+    /// The file couldn't be read.
+    Io(io::Error),
+    /// The content is not valid iCalendar.
+    Invalid { line: usize, message: String },
+    /// The content uses an iCalendar feature that can't be represented as holidays.
+    Unsupported { line: usize, message: String },
+}
+
+impl ImportError {
+    fn invalid(line: usize, message: impl Into<String>) -> Self {
+        ImportError::Invalid {
+            line,
+            message: message.into(),
+        }
+    }
+
+    fn unsupported(line: usize, message: impl Into<String>) -> Self {
+        ImportError::Unsupported {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ImportError::Io(err) => write!(f, "couldn't read iCalendar file: {err}"),
+            ImportError::Invalid { line, message } => write!(f, "line {line}: invalid iCalendar: {message}"),
+            ImportError::Unsupported { line, message } => write!(f, "line {line}: unsupported iCalendar feature: {message}"),
+        }
+    }
+}
+
+impl error::Error for ImportError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ImportError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ImportError {
+    fn from(err: io::Error) -> Self {
+        ImportError::Io(err)
+    }
+}
+
+/// A content line, after unfolding.
+struct ContentLine<'a> {
+    line: usize,
+    name: String,
+    params: Vec<(String, &'a str)>,
+    value: &'a str,
+}
+
+impl<'a> ContentLine<'a> {
+
+    fn parse(line: usize, text: &'a str) -> Result<Self, ImportError> {
+        // the value starts at the first colon that is not inside a quoted parameter value
+        let mut in_quotes = false;
+        let colon = text
+            .char_indices()
+            .find(|&(_, c)| {
+                if c == '"' {
+                    in_quotes = !in_quotes;
+                }
+                c == ':' && !in_quotes
+            })
+            .map(|(index, _)| index)
+            .ok_or_else(|| ImportError::invalid(line, format!("expected `NAME:value`, found {text}")))?;
+
+        let mut parts = text[..colon].split(';');
+        let name = parts.next().unwrap_or_default().to_ascii_uppercase();
+
+        if name.is_empty() {
+            return Err(ImportError::invalid(line, format!("missing property name in {text}")));
+        }
+
+        let mut params = Vec::new();
+        for param in parts {
+            let Some((key, value)) = param.split_once('=') else {
+                return Err(ImportError::invalid(line, format!("invalid parameter {param}")));
+            };
+
+            params.push((key.to_ascii_uppercase(), value.trim_matches('"')));
+        }
+
+        Ok(ContentLine {
+            line,
+            name,
+            params,
+            value: &text[colon + 1..],
+        })
+    }
+
+    fn param(&self, key: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| *value)
+    }
+
+    /// Parses the value as an all-day `DATE`.
+    fn date(&self) -> Result<Date, ImportError> {
+        if self.value.len() != 8 || self.param("VALUE").is_some_and(|v| !v.eq_ignore_ascii_case("DATE")) {
+            return Err(ImportError::unsupported(
+                self.line,
+                format!("{} must be an all-day date (VALUE=DATE), found {}", self.name, self.value),
+            ));
+        }
+
+        parse_date(self.value)
+            .ok_or_else(|| ImportError::invalid(self.line, format!("invalid date {}", self.value)))
+    }
+}
+
+/// Parses `YYYYMMDD`.
+fn parse_date(text: &str) -> Option<Date> {
+    if text.len() != 8 || !text.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let year = text[0..4].parse().ok()?;
+    let month = text[4..6].parse().ok()?;
+    let day = text[6..8].parse().ok()?;
+
+    Date::from_ymd(year, month, day).ok()
+}
+
+/// Reverts `TEXT` escaping.
+fn unescape_text(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') | Some('N') => unescaped.push('\n'),
+                Some(other) => unescaped.push(other),
+                None => unescaped.push('\\'),
+            }
+        } else {
+            unescaped.push(c);
+        }
+    }
+
+    unescaped
+}
+
+fn parse_weekday(code: &str) -> Option<Weekday> {
+    match code {
+        "MO" => Some(Weekday::Monday),
+        "TU" => Some(Weekday::Tuesday),
+        "WE" => Some(Weekday::Wednesday),
+        "TH" => Some(Weekday::Thursday),
+        "FR" => Some(Weekday::Friday),
+        "SA" => Some(Weekday::Saturday),
+        "SU" => Some(Weekday::Sunday),
+        _ => None,
+    }
+}
+
+/// Converts a yearly `RRULE` starting at `dtstart` into a holiday rule.
+fn recurrence_rule(rrule: &ContentLine, name: &str, dtstart: Date) -> Result<HolidayRule, ImportError> {
+    let line = rrule.line;
+    let (start_year, start_month, start_day) = dtstart.to_ymd();
+
+    let mut freq = None;
+    let mut month = None;
+    let mut month_day = None;
+    let mut weekday = None;
+    let mut until = None;
+    let mut count = None;
+
+    for part in rrule.value.split(';') {
+        let Some((key, value)) = part.split_once('=') else {
+            return Err(ImportError::invalid(line, format!("invalid RRULE part {part}")));
+        };
+
+        let number = |value: &str| {
+            value
+                .parse::<i32>()
+                .map_err(|_| ImportError::invalid(line, format!("invalid {key} value {value}")))
+        };
+
+        if value.contains(',') && key.starts_with("BY") {
+            return Err(ImportError::unsupported(line, format!("multiple values in {part}")));
+        }
+
+        match key.to_ascii_uppercase().as_str() {
+            "FREQ" => freq = Some(value.to_ascii_uppercase()),
+            "INTERVAL" => {
+                if number(value)? != 1 {
+                    return Err(ImportError::unsupported(line, format!("{part}, only yearly recurrence is supported")));
+                }
+            }
+            "BYMONTH" => month = Some(number(value)?),
+            "BYMONTHDAY" => month_day = Some(number(value)?),
+            "BYDAY" => weekday = Some(value.to_ascii_uppercase()),
+            "UNTIL" => {
+                until = Some(
+                    value
+                        .get(..8)
+                        .and_then(parse_date)
+                        .ok_or_else(|| ImportError::invalid(line, format!("invalid UNTIL value {value}")))?,
+                );
+            }
+            "COUNT" => count = Some(number(value)?),
+            "WKST" => {} // doesn't affect yearly recurrence by month
+            _ => return Err(ImportError::unsupported(line, format!("RRULE part {part}"))),
+        }
+    }
+
+    match freq.as_deref() {
+        Some("YEARLY") => {}
+        Some(other) => return Err(ImportError::unsupported(line, format!("FREQ={other}, only FREQ=YEARLY is supported"))),
+        None => return Err(ImportError::invalid(line, "RRULE without FREQ")),
+    }
+
+    let month = month.unwrap_or(start_month);
+    if !(1..=12).contains(&month) {
+        return Err(ImportError::invalid(line, format!("invalid BYMONTH value {month}")));
+    }
+
+    let date_rule = match (weekday, month_day) {
+        (Some(_), Some(_)) => {
+            return Err(ImportError::unsupported(line, "BYDAY combined with BYMONTHDAY"));
+        }
+        (Some(byday), None) => {
+            let invalid_byday = || ImportError::invalid(line, format!("invalid BYDAY value {byday}"));
+
+            // the weekday code is the last two characters, which may not be a char boundary in invalid input
+            let (ordinal, code) = byday
+                .split_at_checked(byday.len().saturating_sub(2))
+                .ok_or_else(invalid_byday)?;
+            let weekday = parse_weekday(code).ok_or_else(invalid_byday)?;
+
+            match ordinal.trim_start_matches('+').parse::<i32>() {
+                Ok(-1) => DateRule::LastWeekday { month, weekday },
+                Ok(nth) if (1..=5).contains(&nth) => DateRule::NthWeekday { month, weekday, nth },
+                _ => return Err(ImportError::unsupported(line, format!("BYDAY={byday}, expected an ordinal from 1 to 5 or -1"))),
+            }
+        }
+        (None, Some(day)) if (1..=31).contains(&day) => DateRule::Fixed { month, day },
+        (None, Some(day)) => {
+            return Err(ImportError::unsupported(line, format!("BYMONTHDAY={day}")));
+        }
+        (None, None) => DateRule::Fixed { month, day: start_day },
+    };
+
+    if date_rule.date_in_year(start_year) != Some(dtstart) {
+        return Err(ImportError::unsupported(
+            line,
+            format!("RRULE whose first occurrence is not DTSTART {dtstart}"),
+        ));
+    }
+
+    let mut rule = HolidayRule::new(name, date_rule).since(start_year);
+
+    match (until, count) {
+        (Some(_), Some(_)) => return Err(ImportError::invalid(line, "RRULE with both UNTIL and COUNT")),
+        (Some(until), None) => {
+            let mut last_year = until.year();
+            if date_rule.date_in_year(last_year).is_none_or(|date| date > until) {
+                last_year -= 1;
+            }
+            rule = rule.until(last_year);
+        }
+        (None, Some(count)) => match count.checked_sub(1).and_then(|years| start_year.checked_add(years)) {
+            Some(last_year) if count > 0 => rule = rule.until(last_year),
+            _ => return Err(ImportError::invalid(line, format!("invalid COUNT value {count}"))),
+        },
+        (None, None) => {}
+    }
+
+    Ok(rule)
+}
+
+/// Longest event imported, in days. Each day of an event becomes a one-off rule.
+const MAX_EVENT_DAYS: i32 = 366;
+
+/// Properties of a `VEVENT` that determine its dates.
+#[derive(Default)]
+struct Event<'a> {
+    line: usize,
+    summary: Option<String>,
+    dtstart: Option<Date>,
+    dtend: Option<(Date, usize)>,
+    rrule: Option<ContentLine<'a>>,
+    cancelled: bool,
+}
+
+impl Event<'_> {
+    fn into_rules(self, rules: &mut Vec<HolidayRule>) -> Result<(), ImportError> {
+        if self.cancelled {
+            return Ok(());
+        }
+
+        let dtstart = self
+            .dtstart
+            .ok_or_else(|| ImportError::invalid(self.line, "VEVENT without DTSTART"))?;
+
+        let name = self.summary.as_deref().unwrap_or("Holiday");
+
+        let num_days = match self.dtend {
+            None => 1,
+            Some((dtend, _)) if dtend > dtstart => dtend - dtstart,
+            Some((_, line)) => return Err(ImportError::invalid(line, "DTEND is not after DTSTART")),
+        };
+
+        if num_days > MAX_EVENT_DAYS {
+            let line = self.dtend.map_or(self.line, |(_, line)| line);
+            return Err(ImportError::unsupported(line, format!("event spanning more than {MAX_EVENT_DAYS} days")));
+        }
+
+        match self.rrule {
+            Some(rrule) if num_days == 1 => rules.push(recurrence_rule(&rrule, name, dtstart)?),
+            Some(rrule) => {
+                return Err(ImportError::unsupported(rrule.line, "RRULE on an event spanning multiple days"));
+            }
+            None => {
+                for offset in 0..num_days {
+                    rules.push(HolidayRule::new(name, DateRule::OneOff(dtstart.advance_days(offset))));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses the all-day events of an iCalendar (RFC 5545) file into a `RuleCalendar`.
+///
+/// Each `VEVENT` must have an all-day `DTSTART;VALUE=DATE`, and may span up to 366 days
+/// through `DTEND` or `DURATION`. Recurring events are supported for `RRULE`s with `FREQ=YEARLY`
+/// and, optionally, `BYMONTH`, `BYMONTHDAY`, `BYDAY` with an ordinal (such as `3MO` or `-1MO`),
+/// `UNTIL` and `COUNT`. Cancelled events are skipped.
+///
+/// Other constructs that affect dates, such as timed events, `EXDATE` or
+/// monthly recurrence, are reported as `ImportError::Unsupported`.
+///
+/// ```
+/// use bdays::HolidayCalendar;
+/// use bdays::date::Date;
+///
+/// let ics = "BEGIN:VCALENDAR\r\n\
+///     VERSION:2.0\r\n\
+///     BEGIN:VEVENT\r\n\
+///     SUMMARY:Memorial Day\r\n\
+///     DTSTART;VALUE=DATE:20000529\r\n\
+///     RRULE:FREQ=YEARLY;BYMONTH=5;BYDAY=-1MO\r\n\
+///     END:VEVENT\r\n\
+///     END:VCALENDAR\r\n";
+///
+/// let cal = bdays::ical::parse_ics(ics).unwrap();
+/// assert!(cal.is_holiday(Date::from_ymd(2025, 5, 26).unwrap()));
+/// assert_eq!(cal.holiday_name(Date::from_ymd(2025, 5, 26).unwrap()), Some("Memorial Day"));
+/// ```
+pub fn parse_ics(text: &str) -> Result<RuleCalendar, ImportError> {
+    // unfolds content lines, keeping the line number where each one starts
+    let mut lines: Vec<(usize, String)> = Vec::new();
+
+    for (index, raw_line) in text.lines().enumerate() {
+        if let Some(continuation) = raw_line.strip_prefix([' ', '\t']) {
+            match lines.last_mut() {
+                Some((_, line)) => line.push_str(continuation),
+                None => return Err(ImportError::invalid(index + 1, "file starts with a continuation line")),
+            }
+        } else if !raw_line.is_empty() {
+            lines.push((index + 1, raw_line.to_string()));
+        }
+    }
+
+    let mut calendar_name: Option<String> = None;
+    let mut rules: Vec<HolidayRule> = Vec::new();
+    let mut in_calendar = false;
+    let mut event: Option<Event> = None;
+
+    // components nested in the current one that are skipped, such as VTIMEZONE or VALARM
+    let mut skipped: Vec<String> = Vec::new();
+
+    for (line, text) in &lines {
+        let content = ContentLine::parse(*line, text)?;
+        let value = content.value.to_ascii_uppercase();
+
+        match content.name.as_str() {
+            "BEGIN" if !skipped.is_empty() || (in_calendar && (event.is_some() || value != "VEVENT")) => {
+                skipped.push(value);
+            }
+            "END" if !skipped.is_empty() => {
+                if skipped.pop() != Some(value) {
+                    return Err(ImportError::invalid(*line, format!("unexpected END:{}", content.value)));
+                }
+            }
+            _ if !skipped.is_empty() => {}
+            "BEGIN" if value == "VCALENDAR" && !in_calendar => in_calendar = true,
+            "BEGIN" if value == "VEVENT" && in_calendar => {
+                event = Some(Event {
+                    line: *line,
+                    ..Event::default()
+                });
+            }
+            "END" if value == "VEVENT" && event.is_some() => {
+                event.take().expect("event is open").into_rules(&mut rules)?;
+            }
+            "END" if value == "VCALENDAR" && in_calendar => {
+                return Ok(finish_calendar(calendar_name, rules));
+            }
+            "BEGIN" | "END" => {
+                return Err(ImportError::invalid(*line, format!("unexpected {}:{}", content.name, content.value)));
+            }
+            _ if !in_calendar => {
+                return Err(ImportError::invalid(*line, "content outside of BEGIN:VCALENDAR"));
+            }
+            "X-WR-CALNAME" if event.is_none() => calendar_name = Some(unescape_text(content.value)),
+            _ if event.is_none() => {} // other calendar properties don't affect holidays
+            property => {
+                let current = event.as_mut().expect("event is open");
+
+                match property {
+                    "SUMMARY" => current.summary = Some(unescape_text(content.value)),
+                    "DTSTART" => current.dtstart = Some(content.date()?),
+                    "DTEND" => current.dtend = Some((content.date()?, content.line)),
+                    "DURATION" => {
+                        let days = content
+                            .value
+                            .strip_prefix("P")
+                            .and_then(|days| days.strip_suffix('D'))
+                            .and_then(|days| days.parse::<i32>().ok())
+                            .ok_or_else(|| ImportError::unsupported(
+                                content.line,
+                                format!("DURATION:{}, only whole days are supported", content.value),
+                            ))?;
+
+                        let dtstart = current
+                            .dtstart
+                            .ok_or_else(|| ImportError::unsupported(content.line, "DURATION before DTSTART"))?;
+
+                        let dtend = dtstart
+                            .checked_advance_days(days)
+                            .ok_or_else(|| ImportError::invalid(content.line, format!("invalid DURATION:{}", content.value)))?;
+
+                        current.dtend = Some((dtend, content.line));
+                    }
+                    "RRULE" if current.rrule.is_none() => current.rrule = Some(content),
+                    "RRULE" => return Err(ImportError::unsupported(*line, "multiple RRULEs")),
+                    "STATUS" => current.cancelled = value == "CANCELLED",
+                    "RDATE" | "EXDATE" | "EXRULE" | "RECURRENCE-ID" => {
+                        return Err(ImportError::unsupported(*line, format!("{property} property")));
+                    }
+                    _ => {} // descriptive properties, such as UID or DESCRIPTION
+                }
+            }
+        }
+    }
+
+    match lines.last() {
+        Some((line, _)) => Err(ImportError::invalid(*line, "missing END:VCALENDAR")),
+        None => Err(ImportError::invalid(1, "empty file")),
+    }
+}
+
+fn finish_calendar(name: Option<String>, rules: Vec<HolidayRule>) -> RuleCalendar {
+    let calendar = RuleCalendar::new(rules);
+
+    match name {
+        Some(name) => calendar.with_name(name),
+        None => calendar,
+    }
+}
+
+/// Reads an iCalendar (RFC 5545) file from `path`. See [parse_ics](parse_ics) for details.
+pub fn read_ics_file<P: AsRef<Path>>(path: P) -> Result<RuleCalendar, ImportError> {
+    let text = fs::read_to_string(path)?;
+    parse_ics(&text)
+}
+
+#[test]
+fn test_export_us_settlement() {
+    let cal = crate::calendars::us::USSettlement;
+    let ics = to_ics(
+        &cal,
+        "US Settlement",
+        Date::from_ymd(2021, 12, 1).unwrap(),
+        Date::from_ymd(2022, 1, 31).unwrap(),
+    );
+
+    let expected = "\
+BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:-//bdays//Holiday Calendar//EN\r
+CALSCALE:GREGORIAN\r
+METHOD:PUBLISH\r
+X-WR-CALNAME:US Settlement\r
+BEGIN:VEVENT\r
+UID:20211224-us-settlement@bdays\r
+DTSTAMP:20211224T000000Z\r
+DTSTART;VALUE=DATE:20211224\r
+DTEND;VALUE=DATE:20211225\r
+SUMMARY:Christmas Day\r
+TRANSP:TRANSPARENT\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:20211231-us-settlement@bdays\r
+DTSTAMP:20211231T000000Z\r
+DTSTART;VALUE=DATE:20211231\r
+DTEND;VALUE=DATE:20220101\r
+SUMMARY:New Year's Day\r
+TRANSP:TRANSPARENT\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:20220117-us-settlement@bdays\r
+DTSTAMP:20220117T000000Z\r
+DTSTART;VALUE=DATE:20220117\r
+DTEND;VALUE=DATE:20220118\r
+SUMMARY:Birthday of Martin Luther King\\, Jr.\r
+TRANSP:TRANSPARENT\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+    assert_eq!(ics, expected);
+
+    // reversed bounds yield the same output
+    assert_eq!(
+        to_ics(&cal, "US Settlement", Date::from_ymd(2022, 1, 31).unwrap(), Date::from_ymd(2021, 12, 1).unwrap()),
+        expected
+    );
+}
+
+#[test]
+fn test_export_brazil_exchange() {
+    let cal = crate::calendars::brazil::BrazilExchange;
+    let ics = to_ics(
+        &cal,
+        "B3 / BrazilExchange",
+        Date::from_ymd(2025, 1, 1).unwrap(),
+        Date::from_ymd(2025, 12, 31).unwrap(),
+    );
+
+    // includes holidays on weekends, such as 7 September 2025
+    assert_eq!(ics.matches("BEGIN:VEVENT").count(), 15);
+    assert!(ics.contains("UID:20250303-b3-brazilexchange@bdays\r\n"));
+    assert!(ics.contains("SUMMARY:Segunda-feira de Carnaval\r\n"));
+    assert!(ics.contains("SUMMARY:Último dia útil do ano\r\n"));
+}
+
+#[test]
+fn test_export_unnamed_holidays() {
+    struct Closures;
+
+    impl HolidayCalendar for Closures {
+        fn is_holiday(&self, date: Date) -> bool {
+            date.day() == 13
+        }
+    }
+
+    let ics = to_ics(&Closures, "", Date::from_ymd(2025, 6, 1).unwrap(), Date::from_ymd(2025, 6, 30).unwrap());
+    assert!(ics.contains("UID:20250613-calendar@bdays\r\nDTSTAMP"));
+    assert!(ics.contains("SUMMARY:Holiday\r\n"));
+}
+
+#[test]
+fn test_line_folding() {
+    let mut buffer: Vec<u8> = Vec::new();
+    let line = format!("SUMMARY:{}", "ã".repeat(60));
+    write_line(&mut buffer, &line).unwrap();
+
+    let written = String::from_utf8(buffer).unwrap();
+    let lines: Vec<&str> = written.split("\r\n").collect();
+
+    assert_eq!(lines.len(), 3);
+    assert!(lines.iter().all(|line| line.len() <= MAX_LINE_OCTETS));
+    assert!(lines[1].starts_with(' '));
+    assert_eq!(lines[2], "");
+    assert_eq!(written.replace("\r\n ", ""), format!("{line}\r\n"));
+}
+
+#[test]
+fn test_import_round_trip() {
+    let b3 = crate::calendars::brazil::BrazilExchange;
+    let d0 = Date::from_ymd(2024, 1, 1).unwrap();
+    let d1 = Date::from_ymd(2026, 12, 31).unwrap();
+
+    let cal = parse_ics(&to_ics(&b3, "B3", d0, d1)).unwrap();
+    assert_eq!(cal.name(), Some("B3"));
+
+    let mut date = d0;
+    while date <= d1 {
+        assert_eq!(cal.is_holiday(date), b3.is_holiday(date), "{date}");
+        assert_eq!(cal.holiday_name(date), b3.holiday_name(date), "{date}");
+        date = date.next_date();
+    }
+}
+
+#[test]
+fn test_import_recurring_events() {
+    let ics = "\
+BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:-//Example//Trading Holidays//EN\r
+BEGIN:VTIMEZONE\r
+TZID:America/New_York\r
+BEGIN:STANDARD\r
+DTSTART:19701101T020000\r
+END:STANDARD\r
+END:VTIMEZONE\r
+BEGIN:VEVENT\r
+UID:1@example.com\r
+SUMMARY:Independence Day\r
+DTSTART;VALUE=DATE:19760704\r
+RRULE:FREQ=YEARLY\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:2@example.com\r
+SUMMARY:Thanksgiving\\, observed\r
+DTSTART;VALUE=DATE:20001123\r
+DTEND;VALUE=DATE:20001124\r
+RRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=4TH;UNTIL=20201126\r
+BEGIN:VALARM\r
+TRIGGER:-PT15M\r
+END:VALARM\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:3@example.com\r
+SUMMARY:Founders' Day\r
+DTSTART;VALUE=DATE:20100315\r
+RRULE:FREQ=YEARLY;BYMONTH=3;BYMONTHDAY=15;COUNT=3\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:4@example.com\r
+SUMMARY:Office move with a long description that is folded over two content\r
+  lines\r
+DTSTART;VALUE=DATE:20230102\r
+DURATION:P2D\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:5@example.com\r
+SUMMARY:Cancelled closure\r
+DTSTART;VALUE=DATE:20230105\r
+STATUS:CANCELLED\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+    let cal = parse_ics(ics).unwrap();
+    let is_holiday = |y, m, d| cal.is_holiday(Date::from_ymd(y, m, d).unwrap());
+
+    assert_eq!(cal.name(), None);
+    assert!(is_holiday(1976, 7, 4));
+    assert!(is_holiday(2021, 7, 4));
+    assert!(!is_holiday(1975, 7, 4));
+
+    assert!(is_holiday(2000, 11, 23));
+    assert!(is_holiday(2020, 11, 26));
+    assert!(!is_holiday(2021, 11, 25));
+    assert_eq!(cal.holiday_name(Date::from_ymd(2015, 11, 26).unwrap()), Some("Thanksgiving, observed"));
+
+    assert!(is_holiday(2012, 3, 15));
+    assert!(!is_holiday(2013, 3, 15));
+
+    assert!(is_holiday(2023, 1, 2));
+    assert!(is_holiday(2023, 1, 3));
+    assert!(!is_holiday(2023, 1, 4));
+    assert!(!is_holiday(2023, 1, 5));
+    assert_eq!(
+        cal.holiday_name(Date::from_ymd(2023, 1, 3).unwrap()),
+        Some("Office move with a long description that is folded over two content lines")
+    );
+}
+
+#[test]
+fn test_import_errors() {
+    let event = |properties: &str| {
+        format!("BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nSUMMARY:X\r\n{properties}END:VEVENT\r\nEND:VCALENDAR\r\n")
+    };
+
+    let unsupported_line = |properties: &str| match parse_ics(&event(properties)) {
+        Err(ImportError::Unsupported { line, .. }) => line,
+        other => panic!("expected unsupported feature, got {other:?}"),
+    };
+
+    assert_eq!(unsupported_line("DTSTART:20230101T090000Z\r\n"), 4);
+    assert_eq!(unsupported_line("DTSTART;TZID=Europe/London:20230101T090000\r\n"), 4);
+    assert_eq!(unsupported_line("DTSTART;VALUE=DATE:20230101\r\nRRULE:FREQ=MONTHLY\r\n"), 5);
+    assert_eq!(unsupported_line("DTSTART;VALUE=DATE:20230101\r\nRRULE:FREQ=YEARLY;INTERVAL=2\r\n"), 5);
+    assert_eq!(unsupported_line("DTSTART;VALUE=DATE:20230101\r\nRRULE:FREQ=YEARLY;BYMONTH=1,7\r\n"), 5);
+    assert_eq!(unsupported_line("DTSTART;VALUE=DATE:20230102\r\nRRULE:FREQ=YEARLY;BYMONTH=1;BYDAY=MO\r\n"), 5);
+    assert_eq!(unsupported_line("DTSTART;VALUE=DATE:20230101\r\nRRULE:FREQ=YEARLY;BYYEARDAY=1\r\n"), 5);
+    assert_eq!(unsupported_line("DTSTART;VALUE=DATE:20230103\r\nRRULE:FREQ=YEARLY;BYMONTH=1;BYDAY=1MO\r\n"), 5);
+    assert_eq!(unsupported_line("DTSTART;VALUE=DATE:20230101\r\nEXDATE;VALUE=DATE:20240101\r\n"), 5);
+    assert_eq!(unsupported_line("DTSTART;VALUE=DATE:20230101\r\nDURATION:PT1H\r\n"), 5);
+    assert_eq!(unsupported_line("DTSTART;VALUE=DATE:20230101\r\nDTEND;VALUE=DATE:20500101\r\n"), 5);
+    assert_eq!(unsupported_line("DTSTART;VALUE=DATE:20230101\r\nDURATION:P367D\r\n"), 5);
+
+    let invalid_line = |text: &str| match parse_ics(text) {
+        Err(ImportError::Invalid { line, .. }) => line,
+        other => panic!("expected invalid iCalendar, got {other:?}"),
+    };
+
+    assert_eq!(invalid_line(&event("DTSTART;VALUE=DATE:20230230\r\n")), 4);
+    assert_eq!(invalid_line(&event("DTEND;VALUE=DATE:20230102\r\n")), 2);
+    assert_eq!(invalid_line(&event("DTSTART;VALUE=DATE:20230102\r\nDTEND;VALUE=DATE:20230102\r\n")), 5);
+    assert_eq!(invalid_line("BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20230101\r\nEND:VEVENT\r\n"), 4);
+    assert_eq!(invalid_line("SUMMARY:X\r\n"), 1);
+    assert_eq!(invalid_line("BEGIN:VCALENDAR\r\nnot a property\r\n"), 2);
+    assert_eq!(invalid_line(""), 1);
+    assert_eq!(invalid_line(&event("DTSTART;VALUE=DATE:20230102\r\nRRULE:FREQ=YEARLY;BYMONTH=1;BYDAY=éa\r\n")), 5);
+    assert_eq!(invalid_line(&event("DTSTART;VALUE=DATE:20230102\r\nRRULE:FREQ=YEARLY;BYMONTH=1;BYDAY=1Mé\r\n")), 5);
+    assert_eq!(invalid_line(&event("DTSTART;VALUE=DATE:20230101\r\nRRULE:FREQ=YEARLY;COUNT=2147483647\r\n")), 5);
+    assert_eq!(invalid_line(&event("DTSTART;VALUE=DATE:20230101\r\nRRULE:FREQ=YEARLY;COUNT=-2147483648\r\n")), 5);
+    assert_eq!(invalid_line(&event("DTSTART;VALUE=DATE:20230101\r\nDURATION:P2147483647D\r\n")), 5);
+
+    let err = parse_ics(&event("DTSTART;VALUE=DATE:20230101\r\nRRULE:FREQ=WEEKLY\r\n")).unwrap_err();
+    assert_eq!(err.to_string(), "line 5: unsupported iCalendar feature: FREQ=WEEKLY, only FREQ=YEARLY is supported");
+
+    match read_ics_file("/nonexistent/holidays.ics") {
+        Err(ImportError::Io(_)) => {}
+        other => panic!("expected io error, got {other:?}"),
+    }
+}
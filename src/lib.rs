@@ -3,14 +3,43 @@
 pub mod date;
 
 use date::{Date, Weekday};
+use std::collections::HashMap;
 
 /// Algorithms to calculate easter dates.
 pub mod easter;
 
+/// Conversion of Chinese lunisolar dates, for holidays such as Lunar New Year.
+pub mod lunar;
+
+/// Conversion of Hijri dates, with officially announced month starts, for holidays such as Eid al-Fitr.
+pub mod hijri;
+
 /// A set of holiday calendars built into bdays crate.
 pub mod calendars;
 
+/// Declarative, rule-based holiday calendars.
+pub mod rules;
+
+/// iCalendar (RFC 5545) export and import of holiday calendars.
+pub mod ical;
+
+/// Batch business days calculation, such as settlement dates for many trades.
+pub mod batch;
+
+/// C ABI over the built-in calendars.
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+/// WebAssembly bindings over the built-in calendars.
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+/// Python bindings over the built-in calendars.
+#[cfg(feature = "python")]
+pub mod python;
+
 #[cfg(test)]
+#[allow(clippy::zero_prefixed_literal, clippy::bool_assert_comparison, clippy::bool_comparison)]
 mod tests;
 
 /// Returns `true` if `date` occurs on a Saturday or a Sunday.
@@ -23,16 +52,162 @@ pub fn is_weekday(date: Date) -> bool {
     !is_weekend(date)
 }
 
+/// Returns the number of weekdays from `d0` (inclusive) to `d1` (exclusive),
+/// negative if `d1` is before `d0`, counting whole weeks at once.
+pub(crate) fn weekdays_between(d0: Date, d1: Date) -> i32 {
+    let (from, to, sign) = if d0 <= d1 {
+        (d0, d1, 1)
+    } else {
+        (d1, d0, -1)
+    };
+
+    let whole_weeks = (to - from) / 7;
+    let mut result = whole_weeks * 5;
+    let mut current_date = from.advance_days(whole_weeks * 7);
+
+    while current_date < to {
+        if is_weekday(current_date) {
+            result += 1;
+        }
+
+        current_date = current_date.next_date();
+    }
+
+    sign * result
+}
+
+/// Returns the number of dates from `from` (inclusive) to `to` (exclusive) that are not
+/// on a weekend of `calendar`. Counts whole weeks at once when the first and the last
+/// whole week have the same weekend days, and checks one date at a time otherwise.
+fn calendar_weekdays_between<C: HolidayCalendar + ?Sized>(calendar: &C, from: Date, to: Date) -> i32 {
+    let is_weekday = |date: Date| !calendar.is_weekend(date);
+    let weekdays_in_week = |start: Date| -> [bool; 7] {
+        std::array::from_fn(|offset| is_weekday(start.advance_days(offset as i32)))
+    };
+
+    let whole_weeks = (to - from) / 7;
+    let mut current_date = from;
+    let mut result = 0;
+
+    if whole_weeks > 0 {
+        let first_week = weekdays_in_week(from);
+
+        if first_week == weekdays_in_week(from.advance_days((whole_weeks - 1) * 7)) {
+            result = whole_weeks * first_week.iter().filter(|is_weekday| **is_weekday).count() as i32;
+            current_date = from.advance_days(whole_weeks * 7);
+        }
+    }
+
+    while current_date < to {
+        if is_weekday(current_date) {
+            result += 1;
+        }
+
+        current_date = current_date.next_date();
+    }
+
+    result
+}
+
+/// Counts business days in closed form: weekdays from `from` (inclusive) to `to` (exclusive)
+/// minus the holidays on weekdays plus the working weekend days in between,
+/// using `HolidayCalendar::holidays_in_year` and `HolidayCalendar::working_weekends_in_year`.
+/// Weekdays are counted one year at a time, so the weekend may change from one year to the next.
+/// Returns `None` if the calendar doesn't enumerate its holidays or its working weekend days.
+fn bdays_by_enumeration<C: HolidayCalendar + ?Sized>(calendar: &C, from: Date, to: Date) -> Option<i32> {
+    let mut weekdays_count = 0;
+    let mut holidays_count = 0;
+    let mut working_weekends_count = 0;
+
+    for year in from.year()..=to.year() {
+        let year_start = from.max(Date::from_ymd(year, 1, 1).ok()?);
+        let year_end = to.min(Date::from_ymd(year, 12, 31).ok()?.next_date());
+
+        if year_start < year_end {
+            weekdays_count += calendar_weekdays_between(calendar, year_start, year_end);
+        }
+
+        let mut holidays = calendar.holidays_in_year(year)?;
+        holidays.sort();
+        holidays.dedup();
+
+        let mut working_weekends = calendar.working_weekends_in_year(year)?;
+        working_weekends.sort();
+        working_weekends.dedup();
+
+        working_weekends_count += working_weekends
+            .into_iter()
+            .filter(|&date| from <= date && date < to && calendar.is_weekend(date) && holidays.binary_search(&date).is_err())
+            .count() as i32;
+
+        holidays_count += holidays
+            .into_iter()
+            .filter(|&holiday| from <= holiday && holiday < to && !calendar.is_weekend(holiday))
+            .count() as i32;
+    }
+
+    Some(weekdays_count - holidays_count + working_weekends_count)
+}
+
 /// Abstraction for a Holiday Calendar.
 pub trait HolidayCalendar {
 
     /// Returns `true` if `date` is a holiday.
     fn is_holiday(&self, date: Date) -> bool;
 
+    /// Returns the name of the holiday on `date`,
+    /// or `None` if `date` is not a holiday or the calendar doesn't know its name.
+    fn holiday_name(&self, _date: Date) -> Option<&str> {
+        None
+    }
+
+    /// Returns the holidays in `year`, in any order, or `None` if the calendar
+    /// can't enumerate them without checking every date. This is the default.
+    ///
+    /// When provided, `bdays` counts business days in closed form,
+    /// as the number of weekdays minus the holidays on weekdays plus the working weekend days,
+    /// so the dates returned must be exactly those where `is_holiday` returns `true`,
+    /// and `is_bday` must keep its default definition.
+    fn holidays_in_year(&self, _year: i32) -> Option<Vec<Date>> {
+        None
+    }
+
+    /// Returns `false` if the calendar doesn't know the holidays in `year`,
+    /// such as the years out of the schedules published by an exchange.
+    /// The default is `true`, for calendars defined by rules.
+    fn covers_year(&self, _year: i32) -> bool {
+        true
+    }
+
+    /// Returns `true` if `date` falls on a weekend of the calendar.
+    /// The default is Saturday and Sunday; calendars with other weekends override it,
+    /// such as the Friday and Saturday weekend in Saudi Arabia.
+    /// The weekend may change over time, at most once a year.
+    fn is_weekend(&self, date: Date) -> bool {
+        is_weekend(date)
+    }
+
+    /// Returns `true` if `date` is a weekend day designated as a working day,
+    /// such as the weekend days worked in China to make up for longer holidays.
+    /// The default is `false`, so weekends are never business days.
+    fn is_working_weekend(&self, _date: Date) -> bool {
+        false
+    }
+
+    /// Returns the working weekend days in `year`, in any order, or `None` if the calendar
+    /// can't enumerate them without checking every date.
+    ///
+    /// Used with `holidays_in_year` to count business days in closed form,
+    /// so the dates returned must be exactly those where `is_working_weekend` returns `true`.
+    /// The default returns no dates, which matches the default `is_working_weekend`.
+    fn working_weekends_in_year(&self, _year: i32) -> Option<Vec<Date>> {
+        Some(Vec::new())
+    }
+
     /// Returns `true` if `date` is a Business Day.
-    /// A Business Day is defined as a weekday that is not a holiday.
+    /// A Business Day is defined as a weekday or a working weekend day that is not a holiday.
     fn is_bday(&self, date: Date) -> bool {
-        !(is_weekend(date) || self.is_holiday(date))
+        !self.is_holiday(date) && (!self.is_weekend(date) || self.is_working_weekend(date))
     }
 
     /// Adjusts `date` to the last/next business day if it's not a business day.
@@ -93,6 +268,10 @@ pub trait HolidayCalendar {
             to = d0
         }
 
+        if let Some(bdays_count) = bdays_by_enumeration(self, from, to) {
+            return inc * bdays_count;
+        }
+
         while from < to {
             from = self.advance_bdays(from, 1);
             bdays_count += inc;
@@ -100,14 +279,101 @@ pub trait HolidayCalendar {
 
         bdays_count
     }
+
+    /// Returns the number of business days between each pair of dates of `dates`,
+    /// writing them to the matching position of `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dates` and `out` have different lengths.
+    fn bdays_many(&self, dates: &[(Date, Date)], out: &mut [i32]) {
+        assert_eq!(dates.len(), out.len(), "`dates` and `out` must have the same length");
+
+        for (&(d0, d1), result) in dates.iter().zip(out.iter_mut()) {
+            *result = self.bdays(d0, d1);
+        }
+    }
+
+    /// Advances each date of `dates` by its number of business days,
+    /// writing the resulting dates to the matching position of `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dates` and `out` have different lengths.
+    fn advance_bdays_many(&self, dates: &[(Date, i32)], out: &mut [Date]) {
+        assert_eq!(dates.len(), out.len(), "`dates` and `out` must have the same length");
+
+        for (&(date, bdays_count), result) in dates.iter().zip(out.iter_mut()) {
+            *result = self.advance_bdays(date, bdays_count);
+        }
+    }
+}
+
+/// Forwards every method to the boxed calendar,
+/// so a `Box<dyn HolidayCalendar>` can be used as a calendar, and cached.
+impl<T: HolidayCalendar + ?Sized> HolidayCalendar for Box<T> {
+
+    fn is_holiday(&self, date: Date) -> bool {
+        (**self).is_holiday(date)
+    }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        (**self).holiday_name(date)
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        (**self).holidays_in_year(year)
+    }
+
+    fn covers_year(&self, year: i32) -> bool {
+        (**self).covers_year(year)
+    }
+
+    fn is_weekend(&self, date: Date) -> bool {
+        (**self).is_weekend(date)
+    }
+
+    fn is_working_weekend(&self, date: Date) -> bool {
+        (**self).is_working_weekend(date)
+    }
+
+    fn working_weekends_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        (**self).working_weekends_in_year(year)
+    }
+
+    fn is_bday(&self, date: Date) -> bool {
+        (**self).is_bday(date)
+    }
+
+    fn to_bday(&self, date: Date, adjust_next: bool) -> Date {
+        (**self).to_bday(date, adjust_next)
+    }
+
+    fn advance_bdays(&self, date: Date, bdays_count: i32) -> Date {
+        (**self).advance_bdays(date, bdays_count)
+    }
+
+    fn bdays(&self, d0: Date, d1: Date) -> i32 {
+        (**self).bdays(d0, d1)
+    }
+
+    fn bdays_many(&self, dates: &[(Date, Date)], out: &mut [i32]) {
+        (**self).bdays_many(dates, out)
+    }
+
+    fn advance_bdays_many(&self, dates: &[(Date, i32)], out: &mut [Date]) {
+        (**self).advance_bdays_many(dates, out)
+    }
 }
 
 /// Caches business days calculation for a given holiday calendar
 /// and a given range of dates. Implements the `HolidayCalendar` trait.
 pub struct HolidayCalendarCache {
     is_holiday_vec: Vec<bool>,
+    is_weekend_vec: Vec<bool>,
     is_bday_vec: Vec<bool>,
     bdays_counter_vec: Vec<i32>,
+    holiday_names: HashMap<Date, String>,
     dt_min: Date,
     dt_max: Date,
 }
@@ -128,10 +394,12 @@ impl HolidayCalendarCache {
 
         let len = (dt_max.julian_day_number() - dt_min.julian_day_number() + 1) as usize;
         let mut is_holiday_vec: Vec<bool> = Vec::with_capacity(len);
+        let mut is_weekend_vec: Vec<bool> = Vec::with_capacity(len);
         let mut is_bday_vec: Vec<bool> = Vec::with_capacity(len);
         let mut bdays_counter_vec: Vec<i32> = Vec::with_capacity(len);
 
         is_holiday_vec.push(calendar.is_holiday(dt_min));
+        is_weekend_vec.push(calendar.is_weekend(dt_min));
         is_bday_vec.push(calendar.is_bday(dt_min));
 
         let mut bdays_counter = 0;
@@ -142,6 +410,7 @@ impl HolidayCalendarCache {
             let dt_is_bday = calendar.is_bday(dt);
             is_bday_vec.push(dt_is_bday);
             is_holiday_vec.push(calendar.is_holiday(dt));
+            is_weekend_vec.push(calendar.is_weekend(dt));
 
             if dt_is_bday {
                 bdays_counter += 1;
@@ -154,11 +423,107 @@ impl HolidayCalendarCache {
         // lengths must match
         debug_assert_eq!(is_bday_vec.len(), bdays_counter_vec.len());
         debug_assert_eq!(is_holiday_vec.len(), bdays_counter_vec.len());
+        debug_assert_eq!(is_weekend_vec.len(), bdays_counter_vec.len());
+
+        let holiday_names = holiday_names(&calendar, dt_min, &is_holiday_vec);
+
+        HolidayCalendarCache {
+            is_holiday_vec,
+            is_weekend_vec,
+            is_bday_vec,
+            bdays_counter_vec,
+            holiday_names,
+            dt_min,
+            dt_max,
+        }
+    }
+
+    /// Same as `new`, but evaluates the calendar in year-sized chunks of dates in parallel,
+    /// and then stitches the business days counters of every chunk together.
+    /// The result is identical to `new`.
+    #[cfg(feature = "rayon")]
+    pub fn par_new<T: HolidayCalendar + Sync>(
+        calendar: T,
+        mut dt_min: Date,
+        mut dt_max: Date,
+    ) -> HolidayCalendarCache {
+        use rayon::prelude::*;
+
+        if dt_min > dt_max {
+            (dt_min, dt_max) = (dt_max, dt_min);
+        }
+
+        let mut chunks: Vec<(Date, Date)> = Vec::new();
+        let mut chunk_start = dt_min;
+
+        while chunk_start <= dt_max {
+            let chunk_end = Date::from_ymd(chunk_start.year(), 12, 31).expect("Valid date").min(dt_max);
+            chunks.push((chunk_start, chunk_end));
+            chunk_start = chunk_end.next_date();
+        }
+
+        // for each chunk: is_holiday, is_weekend, is_bday, and the number of business days up to each date in the chunk
+        type Chunk = (Vec<bool>, Vec<bool>, Vec<bool>, Vec<i32>);
+
+        let chunks: Vec<Chunk> = chunks
+            .into_par_iter()
+            .map(|(chunk_start, chunk_end)| {
+                let mut is_holiday_vec: Vec<bool> = Vec::new();
+                let mut is_weekend_vec: Vec<bool> = Vec::new();
+                let mut is_bday_vec: Vec<bool> = Vec::new();
+                let mut bdays_counter_vec: Vec<i32> = Vec::new();
+                let mut bdays_counter = 0;
+
+                let mut dt = chunk_start;
+                while dt <= chunk_end {
+                    let dt_is_bday = calendar.is_bday(dt);
+                    is_bday_vec.push(dt_is_bday);
+                    is_holiday_vec.push(calendar.is_holiday(dt));
+                    is_weekend_vec.push(calendar.is_weekend(dt));
+
+                    if dt_is_bday {
+                        bdays_counter += 1;
+                    }
+
+                    bdays_counter_vec.push(bdays_counter);
+                    dt = dt.next_date();
+                }
+
+                (is_holiday_vec, is_weekend_vec, is_bday_vec, bdays_counter_vec)
+            })
+            .collect();
+
+        let len = (dt_max.julian_day_number() - dt_min.julian_day_number() + 1) as usize;
+        let mut is_holiday_vec: Vec<bool> = Vec::with_capacity(len);
+        let mut is_weekend_vec: Vec<bool> = Vec::with_capacity(len);
+        let mut is_bday_vec: Vec<bool> = Vec::with_capacity(len);
+        let mut bdays_counter_vec: Vec<i32> = Vec::with_capacity(len);
+
+        // like `new`, the counter doesn't include dt_min itself
+        let mut offset = -i32::from(chunks[0].2[0]);
+
+        for (chunk_is_holiday, chunk_is_weekend, chunk_is_bday, chunk_counter) in chunks {
+            let chunk_total = *chunk_counter.last().expect("Chunks are not empty");
+
+            is_holiday_vec.extend(chunk_is_holiday);
+            is_weekend_vec.extend(chunk_is_weekend);
+            is_bday_vec.extend(chunk_is_bday);
+            bdays_counter_vec.extend(chunk_counter.into_iter().map(|counter| counter + offset));
+
+            offset += chunk_total;
+        }
+
+        debug_assert_eq!(is_bday_vec.len(), len);
+        debug_assert_eq!(bdays_counter_vec.len(), len);
+
+        let holiday_names = holiday_names(&calendar, dt_min, &is_holiday_vec);
 
         HolidayCalendarCache {
             is_holiday_vec,
+            is_weekend_vec,
             is_bday_vec,
             bdays_counter_vec,
+            holiday_names,
             dt_min,
             dt_max,
         }
@@ -172,6 +537,65 @@ impl HolidayCalendarCache {
         self.dt_min <= date && date <= self.dt_max
     }
 
+    /// Same as `to_bday`, but returns `None` instead of panicking
+    /// if no business day is found within the bounds of the cache.
+    pub fn checked_to_bday(&self, mut date: Date, adjust_next: bool) -> Option<Date> {
+        let inc = if adjust_next {
+            1
+        } else {
+            -1
+        };
+
+        while self.is_date_in_bounds(date) {
+            if self.is_bday_vec[self.row_index(date)] {
+                return Some(date);
+            }
+
+            date = date.advance_days(inc);
+        }
+
+        None
+    }
+
+    /// Same as `advance_bdays`, but returns `None` instead of panicking
+    /// if `date` or the resulting date is out of the bounds of the cache.
+    pub fn checked_advance_bdays(&self, date: Date, bdays_count: i32) -> Option<Date> {
+        let date = self.checked_to_bday(date, true)?;
+        let target = self.bdays_counter_vec[self.row_index(date)].checked_add(bdays_count)?;
+
+        // the counter increments on every business day,
+        // so the first date where it reaches the target is the business day we're looking for
+        let index = self.bdays_counter_vec.partition_point(|counter| *counter < target);
+
+        if index < self.bdays_counter_vec.len()
+            && self.bdays_counter_vec[index] == target
+            && self.is_bday_vec[index]
+        {
+            Some(self.dt_min.advance_days(index as i32))
+        } else {
+            None
+        }
+    }
+
+    /// Same as `bdays`, but returns `None` instead of panicking
+    /// if the dates are out of the bounds of the cache.
+    pub fn checked_bdays(&self, d0: Date, d1: Date) -> Option<i32> {
+        let d0 = self.checked_to_bday(d0, true)?;
+        let d1 = self.checked_to_bday(d1, true)?;
+
+        Some(self.bdays_counter_vec[self.row_index(d1)] - self.bdays_counter_vec[self.row_index(d0)])
+    }
+
+    /// Value of the business days counter at `date`, adjusted to the next business day,
+    /// without looking for that business day.
+    fn bdays_counter(&self, date: Date) -> i32 {
+        self.assert_in_bounds(date);
+        let index = self.row_index(date);
+
+        // the counter only increments on the next business day
+        self.bdays_counter_vec[index] + i32::from(!self.is_bday_vec[index])
+    }
+
     fn assert_in_bounds(&self, date: Date) {
         if !self.is_date_in_bounds(date) {
             panic!(
@@ -182,6 +606,19 @@ impl HolidayCalendarCache {
     }
 }
 
+/// Names of the holidays flagged in `is_holiday_vec`, which starts at `dt_min`.
+fn holiday_names<T: HolidayCalendar>(calendar: &T, dt_min: Date, is_holiday_vec: &[bool]) -> HashMap<Date, String> {
+    is_holiday_vec
+        .iter()
+        .enumerate()
+        .filter(|(_, is_holiday)| **is_holiday)
+        .filter_map(|(index, _)| {
+            let date = dt_min.advance_days(index as i32);
+            calendar.holiday_name(date).map(|name| (date, name.to_string()))
+        })
+        .collect()
+}
+
 impl HolidayCalendar for HolidayCalendarCache {
 
     fn is_holiday(&self, date: Date) -> bool {
@@ -189,15 +626,50 @@ impl HolidayCalendar for HolidayCalendarCache {
         self.is_holiday_vec[self.row_index(date)]
     }
 
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        self.assert_in_bounds(date);
+        self.holiday_names.get(&date).map(String::as_str)
+    }
+
     fn is_bday(&self, date: Date) -> bool {
         self.assert_in_bounds(date);
         self.is_bday_vec[self.row_index(date)]
     }
 
+    fn is_weekend(&self, date: Date) -> bool {
+        self.assert_in_bounds(date);
+        self.is_weekend_vec[self.row_index(date)]
+    }
+
+    fn is_working_weekend(&self, date: Date) -> bool {
+        self.is_weekend(date) && self.is_bday(date)
+    }
+
     fn bdays(&self, mut d0: Date, mut d1: Date) -> i32 {
         d0 = self.to_bday(d0, true);
         d1 = self.to_bday(d1, true);
 
         self.bdays_counter_vec[self.row_index(d1)] - self.bdays_counter_vec[self.row_index(d0)]
     }
+
+    fn bdays_many(&self, dates: &[(Date, Date)], out: &mut [i32]) {
+        assert_eq!(dates.len(), out.len(), "`dates` and `out` must have the same length");
+
+        for (&(d0, d1), result) in dates.iter().zip(out.iter_mut()) {
+            *result = self.bdays_counter(d1) - self.bdays_counter(d0);
+        }
+    }
+
+    fn advance_bdays_many(&self, dates: &[(Date, i32)], out: &mut [Date]) {
+        assert_eq!(dates.len(), out.len(), "`dates` and `out` must have the same length");
+
+        for (&(date, bdays_count), result) in dates.iter().zip(out.iter_mut()) {
+            *result = self.checked_advance_bdays(date, bdays_count).unwrap_or_else(|| {
+                panic!(
+                    "Advancing {} business days from {} goes out of bounds of holiday calendar cache. [{}, {}].",
+                    bdays_count, date, self.dt_min, self.dt_max
+                )
+            });
+        }
+    }
 }
@@ -0,0 +1,284 @@
+use crate::date::Date;
+use std::error;
+use std::fmt;
+
+/// Error type for lunar calendar conversion functions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LunarError {
+    /// The lunar year is not covered by the tables.
+    YearOutOfRange(i32),
+    /// The month or the day doesn't exist in the lunar year,
+    /// such as the 30th day of a 29-day month, or a leap month in a year without one.
+    InvalidDate { year: i32, month: i32, day: i32, leap: bool },
+}
+
+impl fmt::Display for LunarError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LunarError::YearOutOfRange(year) => write!(
+                f,
+                "Couldn't convert lunar date for year {year}. Lunar tables only cover years {TABLE_FIRST_YEAR} to {TABLE_LAST_YEAR}."
+            ),
+            LunarError::InvalidDate { year, month, day, leap: false } => {
+                write!(f, "Invalid lunar date: day {day} of month {month} of year {year}.")
+            }
+            LunarError::InvalidDate { year, month, day, leap: true } => {
+                write!(f, "Invalid lunar date: day {day} of leap month {month} of year {year}.")
+            }
+        }
+    }
+}
+
+impl error::Error for LunarError {}
+
+/// First year of `LUNAR_TABLE`.
+const TABLE_FIRST_YEAR: i32 = 1900;
+
+/// Last year of `LUNAR_TABLE`.
+const TABLE_LAST_YEAR: i32 = 2100;
+
+const TABLE_LEN: usize = (TABLE_LAST_YEAR - TABLE_FIRST_YEAR + 1) as usize;
+
+/// Lengths of the months of the Chinese lunisolar years from `TABLE_FIRST_YEAR` to `TABLE_LAST_YEAR`.
+///
+/// Bits 15 to 4 are set for the months 1 to 12 that have 30 days instead of 29,
+/// bits 3 to 0 hold the leap month, 0 if there's none,
+/// and bit 16 is set if the leap month has 30 days.
+const LUNAR_TABLE: [u32; TABLE_LEN] = [
+    0x04bd8, 0x04ae0, 0x0a570, 0x054d5, 0x0d260, 0x0d950, 0x16554, 0x056a0, 0x09ad0, 0x055d2, // 1900
+    0x04ae0, 0x0a5b6, 0x0a4d0, 0x0d250, 0x1d255, 0x0b540, 0x0d6a0, 0x0ada2, 0x095b0, 0x14977, // 1910
+    0x04970, 0x0a4b0, 0x0b4b5, 0x06a50, 0x06d40, 0x1ab54, 0x02b60, 0x09570, 0x052f2, 0x04970, // 1920
+    0x06566, 0x0d4a0, 0x0ea50, 0x16a95, 0x05ad0, 0x02b60, 0x186e3, 0x092e0, 0x1c8d7, 0x0c950, // 1930
+    0x0d4a0, 0x1d8a6, 0x0b550, 0x056a0, 0x1a5b4, 0x025d0, 0x092d0, 0x0d2b2, 0x0a950, 0x0b557, // 1940
+    0x06ca0, 0x0b550, 0x15355, 0x04da0, 0x0a5b0, 0x14573, 0x052b0, 0x0a9a8, 0x0e950, 0x06aa0, // 1950
+    0x0aea6, 0x0ab50, 0x04b60, 0x0aae4, 0x0a570, 0x05260, 0x0f263, 0x0d950, 0x05b57, 0x056a0, // 1960
+    0x096d0, 0x04dd5, 0x04ad0, 0x0a4d0, 0x0d4d4, 0x0d250, 0x0d558, 0x0b540, 0x0b6a0, 0x195a6, // 1970
+    0x095b0, 0x049b0, 0x0a974, 0x0a4b0, 0x0b27a, 0x06a50, 0x06d40, 0x0af46, 0x0ab60, 0x09570, // 1980
+    0x04af5, 0x04970, 0x064b0, 0x074a3, 0x0ea50, 0x06b58, 0x05ac0, 0x0ab60, 0x096d5, 0x092e0, // 1990
+    0x0c960, 0x0d954, 0x0d4a0, 0x0da50, 0x07552, 0x056a0, 0x0abb7, 0x025d0, 0x092d0, 0x0cab5, // 2000
+    0x0a950, 0x0b4a0, 0x0baa4, 0x0ad50, 0x055d9, 0x04ba0, 0x0a5b0, 0x15176, 0x052b0, 0x0a930, // 2010
+    0x07954, 0x06aa0, 0x0ad50, 0x05b52, 0x04b60, 0x0a6e6, 0x0a4e0, 0x0d260, 0x0ea65, 0x0d530, // 2020
+    0x05aa0, 0x076a3, 0x096d0, 0x04afb, 0x04ad0, 0x0a4d0, 0x1d0b6, 0x0d250, 0x0d520, 0x0dd45, // 2030
+    0x0b5a0, 0x056d0, 0x055b2, 0x049b0, 0x0a577, 0x0a4b0, 0x0aa50, 0x1b255, 0x06d20, 0x0ada0, // 2040
+    0x14b63, 0x09370, 0x049f8, 0x04970, 0x064b0, 0x168a6, 0x0ea50, 0x06b20, 0x1a6c4, 0x0aae0, // 2050
+    0x092e0, 0x0d2e3, 0x0c960, 0x0d557, 0x0d4a0, 0x0da50, 0x05d55, 0x056a0, 0x0a6d0, 0x055d4, // 2060
+    0x052d0, 0x0a9b8, 0x0a950, 0x0b4a0, 0x0b6a6, 0x0ad50, 0x055a0, 0x0aba4, 0x0a5b0, 0x052b0, // 2070
+    0x0b273, 0x06930, 0x07337, 0x06aa0, 0x0ad50, 0x14b55, 0x04b60, 0x0a570, 0x054e4, 0x0d160, // 2080
+    0x0e968, 0x0d520, 0x0daa0, 0x16aa6, 0x056d0, 0x04ae0, 0x0a9d4, 0x0a2d0, 0x0d150, 0x0f252, // 2090
+    0x0d520, // 2100
+];
+
+/// Returns the leap month of a year of `LUNAR_TABLE`, or 0 if there's none.
+const fn table_leap_month(info: u32) -> i32 {
+    (info & 0xf) as i32
+}
+
+const fn table_month_days(info: u32, month: i32) -> i32 {
+    if info & (0x10000 >> month) != 0 {
+        30
+    } else {
+        29
+    }
+}
+
+const fn table_leap_month_days(info: u32) -> i32 {
+    if table_leap_month(info) == 0 {
+        0
+    } else if info & 0x10000 != 0 {
+        30
+    } else {
+        29
+    }
+}
+
+const fn table_year_days(info: u32) -> i32 {
+    let mut days = table_leap_month_days(info);
+    let mut month = 1;
+
+    while month <= 12 {
+        days += table_month_days(info, month);
+        month += 1;
+    }
+
+    days
+}
+
+/// Lunar New Year's Day from `TABLE_FIRST_YEAR` to `TABLE_LAST_YEAR + 1`,
+/// as the number of days since January 1, Year 1, computed at compile time.
+static LUNAR_NEW_YEAR_TABLE: [i32; TABLE_LEN + 1] = {
+    let mut table = [0; TABLE_LEN + 1];
+
+    // Lunar New Year's Day of 1900
+    table[0] = Date::from_ymd_const(1900, 1, 31).num_days_from_ce();
+
+    let mut i = 0;
+
+    while i < TABLE_LEN {
+        table[i + 1] = table[i] + table_year_days(LUNAR_TABLE[i]);
+        i += 1;
+    }
+
+    table
+};
+
+fn table_index(year: i32) -> Result<usize, LunarError> {
+    if (TABLE_FIRST_YEAR..=TABLE_LAST_YEAR).contains(&year) {
+        Ok((year - TABLE_FIRST_YEAR) as usize)
+    } else {
+        Err(LunarError::YearOutOfRange(year))
+    }
+}
+
+/// Returns the leap month of lunar year `year`, which follows the regular month with the same number,
+/// or `None` if the year has 12 months.
+pub fn leap_month(year: i32) -> Result<Option<i32>, LunarError> {
+    let leap = table_leap_month(LUNAR_TABLE[table_index(year)?]);
+    Ok((leap != 0).then_some(leap))
+}
+
+/// Returns the number of days of `month` in lunar year `year`, or of its leap month if `leap` is `true`.
+pub fn month_days(year: i32, month: i32, leap: bool) -> Result<i32, LunarError> {
+    let info = LUNAR_TABLE[table_index(year)?];
+    let invalid = LunarError::InvalidDate { year, month, day: 1, leap };
+
+    if !(1..=12).contains(&month) {
+        Err(invalid)
+    } else if !leap {
+        Ok(table_month_days(info, month))
+    } else if table_leap_month(info) == month {
+        Ok(table_leap_month_days(info))
+    } else {
+        Err(invalid)
+    }
+}
+
+fn lunar_num_days_from_ce(year: i32, month: i32, day: i32, leap: bool) -> Result<i32, LunarError> {
+    let index = table_index(year)?;
+    let info = LUNAR_TABLE[index];
+
+    let invalid = LunarError::InvalidDate { year, month, day, leap };
+    let days_in_month = month_days(year, month, leap).map_err(|_| invalid.clone())?;
+
+    if !(1..=days_in_month).contains(&day) {
+        return Err(invalid);
+    }
+
+    let mut days = LUNAR_NEW_YEAR_TABLE[index];
+
+    for m in 1..month {
+        days += table_month_days(info, m);
+    }
+
+    // the leap month follows the regular month with the same number
+    let leap_month = table_leap_month(info);
+
+    if leap_month != 0 && (leap_month < month || leap) {
+        days += if leap {
+            table_month_days(info, month)
+        } else {
+            table_leap_month_days(info)
+        };
+    }
+
+    Ok(days + day - 1)
+}
+
+/// Returns the date of `day` of `month` in the Chinese lunisolar year `year`,
+/// which starts on Lunar New Year's Day, in January or February of `year`.
+/// Years from 1900 to 2100 are looked up in precomputed tables.
+///
+/// ```
+/// use bdays::date::Date;
+/// use bdays::lunar;
+///
+/// // Lunar New Year and Mid-Autumn Festival
+/// assert_eq!(lunar::lunar_date(2024, 1, 1).unwrap(), Date::from_ymd(2024, 2, 10).unwrap());
+/// assert_eq!(lunar::lunar_date(2024, 8, 15).unwrap(), Date::from_ymd(2024, 9, 17).unwrap());
+/// assert!(lunar::lunar_date(2101, 1, 1).is_err());
+/// ```
+pub fn lunar_date(year: i32, month: i32, day: i32) -> Result<Date, LunarError> {
+    let rata = lunar_num_days_from_ce(year, month, day, false)?;
+    Ok(Date::from_num_days_from_ce(rata))
+}
+
+/// Same as `lunar_date`, but for the leap month that follows `month`.
+/// Fails if `month` is not the leap month of `year`.
+pub fn leap_lunar_date(year: i32, month: i32, day: i32) -> Result<Date, LunarError> {
+    let rata = lunar_num_days_from_ce(year, month, day, true)?;
+    Ok(Date::from_num_days_from_ce(rata))
+}
+
+/// Returns Lunar New Year's Day, the first day of the first month, of lunar year `year`.
+pub fn lunar_new_year(year: i32) -> Result<Date, LunarError> {
+    Ok(Date::from_num_days_from_ce(LUNAR_NEW_YEAR_TABLE[table_index(year)?]))
+}
+
+#[test]
+fn test_lunar_new_year() {
+    // Lunar New Year's Day, from the Hong Kong Observatory
+    let new_years = [
+        (1900, 1, 31), (1901, 2, 19), (1912, 2, 18), (1920, 2, 20), (1933, 1, 26), (1949, 1, 29),
+        (1966, 1, 21), (1976, 1, 31), (1985, 2, 20), (1996, 2, 19), (2000, 2, 5), (2001, 1, 24),
+        (2004, 1, 22), (2010, 2, 14), (2015, 2, 19), (2020, 1, 25), (2023, 1, 22), (2024, 2, 10),
+        (2025, 1, 29), (2026, 2, 17), (2027, 2, 6), (2033, 1, 31), (2034, 2, 19), (2050, 1, 23),
+    ];
+
+    for (yy, mm, dd) in new_years {
+        assert_eq!(lunar_new_year(yy).unwrap(), Date::from_ymd(yy, mm, dd).unwrap(), "{yy}");
+    }
+
+    for year in TABLE_FIRST_YEAR..=TABLE_LAST_YEAR {
+        let new_year = lunar_new_year(year).unwrap();
+        assert_eq!(new_year.year(), year);
+        assert!((1, 21) <= (new_year.month(), new_year.day()) && (new_year.month(), new_year.day()) <= (2, 20), "{year}");
+        assert_eq!(lunar_date(year, 1, 1).unwrap(), new_year);
+
+        // years have 12 or 13 months
+        let year_days = LUNAR_NEW_YEAR_TABLE[(year - TABLE_FIRST_YEAR + 1) as usize] - new_year.num_days_from_ce();
+        assert!((353..=355).contains(&year_days) || (383..=385).contains(&year_days), "{year}");
+    }
+
+    assert_eq!(lunar_new_year(1899), Err(LunarError::YearOutOfRange(1899)));
+    assert_eq!(lunar_new_year(2101), Err(LunarError::YearOutOfRange(2101)));
+}
+
+#[test]
+fn test_lunar_date() {
+    // Dragon Boat Festival (5/5) and Mid-Autumn Festival (8/15)
+    assert_eq!(lunar_date(2020, 5, 5).unwrap(), Date::from_ymd(2020, 6, 25).unwrap());
+    assert_eq!(lunar_date(2020, 8, 15).unwrap(), Date::from_ymd(2020, 10, 1).unwrap());
+    assert_eq!(lunar_date(2023, 5, 5).unwrap(), Date::from_ymd(2023, 6, 22).unwrap());
+    assert_eq!(lunar_date(2023, 8, 15).unwrap(), Date::from_ymd(2023, 9, 29).unwrap());
+    assert_eq!(lunar_date(2025, 5, 5).unwrap(), Date::from_ymd(2025, 5, 31).unwrap());
+    assert_eq!(lunar_date(2025, 8, 15).unwrap(), Date::from_ymd(2025, 10, 6).unwrap());
+
+    // Buddha's Birthday (4/8)
+    assert_eq!(lunar_date(2024, 4, 8).unwrap(), Date::from_ymd(2024, 5, 15).unwrap());
+
+    // leap months
+    assert_eq!(leap_month(2020).unwrap(), Some(4));
+    assert_eq!(leap_month(2023).unwrap(), Some(2));
+    assert_eq!(leap_month(2025).unwrap(), Some(6));
+    assert_eq!(leap_month(2033).unwrap(), Some(11));
+    assert_eq!(leap_month(2024).unwrap(), None);
+    assert_eq!(leap_lunar_date(2020, 4, 1).unwrap(), Date::from_ymd(2020, 5, 23).unwrap());
+    assert_eq!(lunar_date(2020, 5, 1).unwrap(), leap_lunar_date(2020, 4, 1).unwrap().advance_days(month_days(2020, 4, true).unwrap()));
+    assert_eq!(leap_lunar_date(2024, 4, 1), Err(LunarError::InvalidDate { year: 2024, month: 4, day: 1, leap: true }));
+
+    // the last day of the year is the eve of the next Lunar New Year
+    for year in TABLE_FIRST_YEAR..TABLE_LAST_YEAR {
+        let eve = lunar_date(year, 12, month_days(year, 12, false).unwrap()).unwrap();
+        assert_eq!(eve.next_date(), lunar_new_year(year + 1).unwrap(), "{year}");
+    }
+
+    assert!(lunar_date(2024, 13, 1).is_err());
+    assert!(lunar_date(2024, 1, 0).is_err());
+    assert_eq!(
+        lunar_date(2024, 1, 31).unwrap_err().to_string(),
+        "Invalid lunar date: day 31 of month 1 of year 2024."
+    );
+    assert_eq!(
+        lunar_date(1899, 1, 1).unwrap_err().to_string(),
+        "Couldn't convert lunar date for year 1899. Lunar tables only cover years 1900 to 2100."
+    );
+}
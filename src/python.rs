@@ -0,0 +1,470 @@
+//! Python bindings over the built-in calendars, through PyO3.
+//!
+//! Dates are accepted either as `bdays.Date` or as `datetime.date`, and returned as `datetime.date`.
+//! The vectorised methods `bdays_many` and `advance_bdays_many` run without holding the GIL.
+//!
+//! ```python
+//! import datetime
+//! import bdays
+//!
+//! cal = bdays.CachedCalendar("BRSettlement", datetime.date(2000, 1, 1), datetime.date(2030, 12, 31))
+//! cal.advance_bdays(datetime.date(2025, 11, 19), 2)  # datetime.date(2025, 11, 24)
+//! cal.bdays_many([datetime.date(2025, 1, 1)] * 2, [datetime.date(2025, 6, 30), datetime.date(2025, 12, 31)])
+//! ```
+
+use crate::calendars;
+use crate::date::Date;
+use crate::{HolidayCalendar, HolidayCalendarCache};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{self, PyDateAccess};
+
+// Years supported by the bindings, where every built-in calendar is defined.
+const MIN_YEAR: i32 = 1583;
+const MAX_YEAR: i32 = 9999;
+
+/// Error for dates out of the range of a `CachedCalendar`.
+struct OutOfRange {
+    date: Date,
+    dt_min: Date,
+    dt_max: Date,
+}
+
+impl From<OutOfRange> for PyErr {
+    fn from(err: OutOfRange) -> PyErr {
+        PyValueError::new_err(format!(
+            "date {} out of the range of the calendar [{}, {}]",
+            err.date, err.dt_min, err.dt_max
+        ))
+    }
+}
+
+/// A date argument, either a `bdays.Date` or a `datetime.date`.
+struct DateArg(Date);
+
+impl<'py> FromPyObject<'_, 'py> for DateArg {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        let date = if let Ok(date) = obj.cast::<PyDate>() {
+            date.get().0
+        } else if let Ok(date) = obj.cast::<types::PyDate>() {
+            Date::from_ymd(date.get_year(), date.get_month() as i32, date.get_day() as i32)
+                .map_err(|err| PyValueError::new_err(err.to_string()))?
+        } else {
+            return Err(PyTypeError::new_err("expected a bdays.Date or a datetime.date"));
+        };
+
+        check_year(date)?;
+        Ok(DateArg(date))
+    }
+}
+
+fn check_year(date: Date) -> PyResult<()> {
+    if (MIN_YEAR..=MAX_YEAR).contains(&date.year()) {
+        Ok(())
+    } else {
+        Err(PyValueError::new_err(format!(
+            "date {date} out of the supported range of years [{MIN_YEAR}, {MAX_YEAR}]"
+        )))
+    }
+}
+
+fn to_py_date(py: Python<'_>, date: Date) -> PyResult<Bound<'_, types::PyDate>> {
+    let (year, month, day) = date.to_ymd();
+    types::PyDate::new(py, year, month as u8, day as u8)
+}
+
+/// A number of business days, or one per date.
+#[derive(FromPyObject)]
+enum BdaysCounts {
+    One(i32),
+    Many(Vec<i32>),
+}
+
+/// A date in the proleptic Gregorian calendar.
+#[pyclass(name = "Date", module = "bdays", frozen, eq, ord, hash, skip_from_py_object)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PyDate(Date);
+
+#[pymethods]
+impl PyDate {
+    #[new]
+    fn new(year: i32, month: i32, day: i32) -> PyResult<Self> {
+        let date = Date::from_ymd(year, month, day).map_err(|err| PyValueError::new_err(err.to_string()))?;
+        Ok(PyDate(date))
+    }
+
+    /// Parses a date in the format `YYYY-MM-DD`.
+    #[staticmethod]
+    fn parse(text: &str) -> PyResult<Self> {
+        let date = text.parse().map_err(|err: crate::date::Error| PyValueError::new_err(err.to_string()))?;
+        Ok(PyDate(date))
+    }
+
+    /// Converts a `datetime.date`.
+    #[staticmethod]
+    fn from_date(date: DateArg) -> Self {
+        PyDate(date.0)
+    }
+
+    /// Converts to a `datetime.date`.
+    #[pyo3(name = "to_date")]
+    fn as_py_date<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, types::PyDate>> {
+        to_py_date(py, self.0)
+    }
+
+    #[getter]
+    fn year(&self) -> i32 {
+        self.0.year()
+    }
+
+    #[getter]
+    fn month(&self) -> i32 {
+        self.0.month()
+    }
+
+    #[getter]
+    fn day(&self) -> i32 {
+        self.0.day()
+    }
+
+    /// ISO weekday, from 1 for Monday to 7 for Sunday.
+    fn isoweekday(&self) -> u8 {
+        self.0.weekday() as u8
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("bdays.Date({}, {}, {})", self.0.year(), self.0.month(), self.0.day())
+    }
+}
+
+/// The calculations behind `Calendar` and `CachedCalendar`.
+enum Engine {
+    Direct(Box<dyn HolidayCalendar + Send + Sync>),
+    Cached {
+        calendar: Box<dyn HolidayCalendar + Send + Sync>,
+        cache: HolidayCalendarCache,
+    },
+}
+
+impl Engine {
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        match self {
+            Engine::Direct(calendar) | Engine::Cached { calendar, .. } => calendar.holiday_name(date),
+        }
+    }
+
+    fn out_of_range(cache: &HolidayCalendarCache, date: Date) -> OutOfRange {
+        OutOfRange {
+            date,
+            dt_min: cache.dt_min,
+            dt_max: cache.dt_max,
+        }
+    }
+
+    fn check_bounds(&self, date: Date) -> Result<(), OutOfRange> {
+        match self {
+            Engine::Cached { cache, .. } if !cache.is_date_in_bounds(date) => Err(Engine::out_of_range(cache, date)),
+            _ => Ok(()),
+        }
+    }
+
+    fn is_holiday(&self, date: Date) -> Result<bool, OutOfRange> {
+        self.check_bounds(date)?;
+
+        Ok(match self {
+            Engine::Direct(calendar) => calendar.is_holiday(date),
+            Engine::Cached { cache, .. } => cache.is_holiday(date),
+        })
+    }
+
+    fn is_bday(&self, date: Date) -> Result<bool, OutOfRange> {
+        self.check_bounds(date)?;
+
+        Ok(match self {
+            Engine::Direct(calendar) => calendar.is_bday(date),
+            Engine::Cached { cache, .. } => cache.is_bday(date),
+        })
+    }
+
+    fn to_bday(&self, date: Date, adjust_next: bool) -> Result<Date, OutOfRange> {
+        match self {
+            Engine::Direct(calendar) => Ok(calendar.to_bday(date, adjust_next)),
+            Engine::Cached { cache, .. } => cache
+                .checked_to_bday(date, adjust_next)
+                .ok_or_else(|| Engine::out_of_range(cache, date)),
+        }
+    }
+
+    fn advance_bdays(&self, date: Date, bdays_count: i32) -> Result<Date, OutOfRange> {
+        match self {
+            Engine::Direct(calendar) => Ok(calendar.advance_bdays(date, bdays_count)),
+            Engine::Cached { cache, .. } => cache
+                .checked_advance_bdays(date, bdays_count)
+                .ok_or_else(|| Engine::out_of_range(cache, date)),
+        }
+    }
+
+    fn bdays(&self, d0: Date, d1: Date) -> Result<i32, OutOfRange> {
+        match self {
+            Engine::Direct(calendar) => Ok(calendar.bdays(d0, d1)),
+            Engine::Cached { cache, .. } => {
+                self.check_bounds(d0)?;
+                self.check_bounds(d1)?;
+                cache.checked_bdays(d0, d1).ok_or_else(|| Engine::out_of_range(cache, d1))
+            }
+        }
+    }
+}
+
+fn calendar_from_code(code: &str) -> PyResult<Box<dyn HolidayCalendar + Send + Sync>> {
+    calendars::from_code(code).ok_or_else(|| PyValueError::new_err(format!("unknown calendar `{code}`")))
+}
+
+fn check_lengths(left: usize, right: usize) -> PyResult<()> {
+    if left == right {
+        Ok(())
+    } else {
+        Err(PyValueError::new_err(format!(
+            "sequences of different lengths: {left} and {right}"
+        )))
+    }
+}
+
+/// A built-in holiday calendar, identified by its code, such as `"BRSettlement"`.
+#[pyclass(name = "Calendar", module = "bdays", frozen, subclass)]
+pub struct PyCalendar {
+    code: String,
+    engine: Engine,
+}
+
+#[pymethods]
+impl PyCalendar {
+    #[new]
+    fn new(code: &str) -> PyResult<Self> {
+        Ok(PyCalendar {
+            code: code.to_string(),
+            engine: Engine::Direct(calendar_from_code(code)?),
+        })
+    }
+
+    #[getter]
+    fn code(&self) -> &str {
+        &self.code
+    }
+
+    fn is_holiday(&self, date: DateArg) -> PyResult<bool> {
+        Ok(self.engine.is_holiday(date.0)?)
+    }
+
+    fn is_bday(&self, date: DateArg) -> PyResult<bool> {
+        Ok(self.engine.is_bday(date.0)?)
+    }
+
+    /// Returns the name of the holiday on `date`, or `None`.
+    fn holiday_name(&self, date: DateArg) -> Option<String> {
+        self.engine.holiday_name(date.0).map(str::to_string)
+    }
+
+    /// Adjusts `date` to the next business day if `adjust_next` is true,
+    /// or to the previous one otherwise.
+    #[pyo3(signature = (date, adjust_next = true))]
+    fn to_bday<'py>(&self, py: Python<'py>, date: DateArg, adjust_next: bool) -> PyResult<Bound<'py, types::PyDate>> {
+        to_py_date(py, self.engine.to_bday(date.0, adjust_next)?)
+    }
+
+    /// Advances `bdays_count` business days from `date`, backwards if negative.
+    fn advance_bdays<'py>(&self, py: Python<'py>, date: DateArg, bdays_count: i32) -> PyResult<Bound<'py, types::PyDate>> {
+        to_py_date(py, self.engine.advance_bdays(date.0, bdays_count)?)
+    }
+
+    /// Counts the business days from `d0` to `d1`.
+    fn bdays(&self, d0: DateArg, d1: DateArg) -> PyResult<i32> {
+        Ok(self.engine.bdays(d0.0, d1.0)?)
+    }
+
+    /// Counts the business days between each pair of dates of `d0s` and `d1s`.
+    fn bdays_many(&self, py: Python<'_>, d0s: Vec<DateArg>, d1s: Vec<DateArg>) -> PyResult<Vec<i32>> {
+        check_lengths(d0s.len(), d1s.len())?;
+
+        let result: Result<Vec<i32>, OutOfRange> = py.detach(|| {
+            d0s.iter()
+                .zip(&d1s)
+                .map(|(d0, d1)| self.engine.bdays(d0.0, d1.0))
+                .collect()
+        });
+
+        Ok(result?)
+    }
+
+    /// Advances business days from each date of `dates`,
+    /// by `bdays_count` if it's an integer, or by the matching item if it's a sequence.
+    fn advance_bdays_many<'py>(
+        &self,
+        py: Python<'py>,
+        dates: Vec<DateArg>,
+        bdays_count: BdaysCounts,
+    ) -> PyResult<Vec<Bound<'py, types::PyDate>>> {
+        let counts = match bdays_count {
+            BdaysCounts::One(count) => vec![count; dates.len()],
+            BdaysCounts::Many(counts) => {
+                check_lengths(dates.len(), counts.len())?;
+                counts
+            }
+        };
+
+        let result: Result<Vec<Date>, OutOfRange> = py.detach(|| {
+            dates.iter()
+                .zip(&counts)
+                .map(|(date, count)| self.engine.advance_bdays(date.0, *count))
+                .collect()
+        });
+
+        result?.into_iter().map(|date| to_py_date(py, date)).collect()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("bdays.Calendar({:?})", self.code)
+    }
+}
+
+/// A `Calendar` backed by a `HolidayCalendarCache` over the range of dates from `dt_min` to `dt_max`.
+/// Dates out of that range raise `ValueError`.
+#[pyclass(name = "CachedCalendar", module = "bdays", frozen, extends = PyCalendar)]
+pub struct PyCachedCalendar;
+
+#[pymethods]
+impl PyCachedCalendar {
+    #[new]
+    fn new(code: &str, dt_min: DateArg, dt_max: DateArg) -> PyResult<(Self, PyCalendar)> {
+        let cache = HolidayCalendarCache::new(calendar_from_code(code)?, dt_min.0, dt_max.0);
+
+        let base = PyCalendar {
+            code: code.to_string(),
+            engine: Engine::Cached {
+                calendar: calendar_from_code(code)?,
+                cache,
+            },
+        };
+
+        Ok((PyCachedCalendar, base))
+    }
+}
+
+/// Returns the codes and descriptions of the built-in calendars.
+#[pyfunction]
+fn calendar_codes() -> Vec<(&'static str, &'static str)> {
+    calendars::CALENDAR_CODES.to_vec()
+}
+
+/// Business days calculation over the calendars of the `bdays` Rust crate.
+#[pymodule]
+fn bdays(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyDate>()?;
+    m.add_class::<PyCalendar>()?;
+    m.add_class::<PyCachedCalendar>()?;
+    m.add_function(wrap_pyfunction!(calendar_codes, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+fn with_module<F: for<'py> FnOnce(Python<'py>, Bound<'py, PyModule>)>(f: F) {
+    Python::initialize();
+
+    Python::attach(|py| {
+        let module = PyModule::new(py, "bdays").unwrap();
+        bdays(&module).unwrap();
+        f(py, module);
+    });
+}
+
+#[cfg(test)]
+fn eval_with<'py>(py: Python<'py>, module: &Bound<'py, PyModule>, code: &str) -> PyResult<Bound<'py, PyAny>> {
+    let globals = types::PyDict::new(py);
+    globals.set_item("bdays", module)?;
+    globals.set_item("datetime", py.import("datetime")?)?;
+    py.eval(&std::ffi::CString::new(code).unwrap(), Some(&globals), None)
+}
+
+#[test]
+fn test_python_calendar() {
+    with_module(|py, module| {
+        let eval = |code: &str| eval_with(py, &module, code).unwrap();
+        let date = |y, m, d| types::PyDate::new(py, y, m, d).unwrap();
+
+        assert!(eval("bdays.Calendar('BRSettlement').is_holiday(datetime.date(2025, 11, 20))").extract::<bool>().unwrap());
+        assert!(eval("bdays.Calendar('BRSettlement').is_bday(bdays.Date(2025, 11, 19))").extract::<bool>().unwrap());
+        assert!(eval("bdays.Calendar('BRSettlement').to_bday(datetime.date(2025, 11, 20))").eq(date(2025, 11, 21)).unwrap());
+        assert!(eval("bdays.Calendar('BRSettlement').to_bday(datetime.date(2025, 11, 20), False)").eq(date(2025, 11, 19)).unwrap());
+        assert!(eval("bdays.Calendar('BRSettlement').advance_bdays(datetime.date(2025, 11, 19), 2)").eq(date(2025, 11, 24)).unwrap());
+        assert_eq!(eval("bdays.Calendar('brsettlement').bdays(datetime.date(2013, 2, 6), datetime.date(2013, 2, 14))").extract::<i32>().unwrap(), 4);
+        assert_eq!(eval("bdays.Calendar('USSettlement').holiday_name(datetime.date(2025, 11, 27))").extract::<String>().unwrap(), "Thanksgiving Day");
+        assert!(eval("bdays.Calendar('USSettlement').holiday_name(datetime.date(2025, 11, 28))").is_none());
+        assert_eq!(eval("repr(bdays.Calendar('DE-BY'))").extract::<String>().unwrap(), "bdays.Calendar(\"DE-BY\")");
+        assert!(eval("('WeekendsOnly', 'Accounts only weekends') in bdays.calendar_codes()").extract::<bool>().unwrap());
+    });
+}
+
+#[test]
+fn test_python_vectorised() {
+    with_module(|py, module| {
+        let eval = |code: &str| eval_with(py, &module, code).unwrap();
+
+        let code = "\
+[
+    cal.bdays_many([datetime.date(2013, 2, 6), datetime.date(2013, 2, 14)], [datetime.date(2013, 2, 14), datetime.date(2013, 2, 6)]),
+    cal.advance_bdays_many([datetime.date(2025, 11, 19), datetime.date(2025, 11, 24)], 2),
+    cal.advance_bdays_many((datetime.date(2025, 11, 19), bdays.Date(2025, 11, 24)), [2, -2]),
+]";
+
+        for cal in ["bdays.Calendar('BRSettlement')", "bdays.CachedCalendar('BRSettlement', datetime.date(2000, 1, 1), datetime.date(2030, 12, 31))"] {
+            let result = eval(&format!("(lambda cal: {code})({cal})"));
+            let expected = eval("[[4, -4], [datetime.date(2025, 11, 24), datetime.date(2025, 11, 26)], [datetime.date(2025, 11, 24), datetime.date(2025, 11, 19)]]");
+            assert!(result.eq(expected).unwrap(), "{cal}: {result}");
+        }
+
+        assert!(eval("isinstance(bdays.CachedCalendar('BRSettlement', datetime.date(2000, 1, 1), datetime.date(2030, 12, 31)), bdays.Calendar)").extract::<bool>().unwrap());
+    });
+}
+
+#[test]
+fn test_python_date() {
+    with_module(|py, module| {
+        let eval = |code: &str| eval_with(py, &module, code).unwrap();
+
+        assert_eq!(eval("str(bdays.Date(2025, 11, 20))").extract::<String>().unwrap(), "2025-11-20");
+        assert_eq!(eval("repr(bdays.Date.parse('2025-11-20'))").extract::<String>().unwrap(), "bdays.Date(2025, 11, 20)");
+        assert!(eval("bdays.Date(2025, 11, 20).to_date() == datetime.date(2025, 11, 20)").extract::<bool>().unwrap());
+        assert!(eval("bdays.Date.from_date(datetime.date(2025, 11, 20)) == bdays.Date(2025, 11, 20)").extract::<bool>().unwrap());
+        assert!(eval("bdays.Date(2025, 11, 20) < bdays.Date(2025, 11, 21)").extract::<bool>().unwrap());
+        assert!(eval("len({bdays.Date(2025, 11, 20), bdays.Date(2025, 11, 20)}) == 1").extract::<bool>().unwrap());
+        assert_eq!(eval("bdays.Date(2025, 11, 20).isoweekday()").extract::<u8>().unwrap(), 4);
+        assert_eq!(eval("(bdays.Date(2025, 11, 20).year, bdays.Date(2025, 11, 20).month, bdays.Date(2025, 11, 20).day)").extract::<(i32, i32, i32)>().unwrap(), (2025, 11, 20));
+    });
+}
+
+#[test]
+fn test_python_errors() {
+    with_module(|py, module| {
+        let error = |code: &str| eval_with(py, &module, code).unwrap_err();
+
+        assert!(error("bdays.Calendar('XX')").is_instance_of::<PyValueError>(py));
+        assert!(error("bdays.Date(2025, 2, 30)").is_instance_of::<PyValueError>(py));
+        assert!(error("bdays.Date.parse('20250230')").is_instance_of::<PyValueError>(py));
+        assert!(error("bdays.Calendar('BRSettlement').is_bday('2025-11-20')").is_instance_of::<PyTypeError>(py));
+        assert!(error("bdays.Calendar('BRSettlement').is_bday(datetime.date(1500, 1, 1))").is_instance_of::<PyValueError>(py));
+        assert!(error("bdays.Calendar('BRSettlement').bdays_many([datetime.date(2025, 1, 1)], [])").is_instance_of::<PyValueError>(py));
+
+        let cached = "bdays.CachedCalendar('BRSettlement', datetime.date(2000, 1, 1), datetime.date(2030, 12, 31))";
+        let err = error(&format!("{cached}.advance_bdays(datetime.date(2030, 12, 20), 30)"));
+        assert!(err.is_instance_of::<PyValueError>(py));
+        assert_eq!(err.value(py).to_string(), "date 2030-12-20 out of the range of the calendar [2000-01-01, 2030-12-31]");
+        assert!(error(&format!("{cached}.is_bday(datetime.date(1999, 12, 31))")).is_instance_of::<PyValueError>(py));
+        assert!(error(&format!("{cached}.bdays_many([datetime.date(2025, 1, 1)], [datetime.date(2031, 1, 1)])")).is_instance_of::<PyValueError>(py));
+    });
+}
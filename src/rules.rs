@@ -0,0 +1,997 @@
+use crate::{HolidayCalendar, easter, is_weekend, lunar};
+use crate::calendars::us::{adjust_weekend_holidays_us, find_weekday};
+use crate::date::{Date, Weekday};
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Determines the date of a holiday in a given year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateRule {
+    /// Same month and day every year.
+    /// A rule for February 29 only yields a date on leap years.
+    Fixed { month: i32, day: i32 },
+    /// The `nth` occurrence of `weekday` in `month`, counting from 1.
+    NthWeekday { month: i32, weekday: Weekday, nth: i32 },
+    /// The last occurrence of `weekday` in `month`.
+    LastWeekday { month: i32, weekday: Weekday },
+    /// A number of days relative to Easter Sunday.
+    /// Offsets of more than `MAX_EASTER_OFFSET` days yield no date.
+    EasterOffset(i32),
+    /// Same month and day of the Chinese lunisolar calendar, such as Lunar New Year on 1/1.
+    /// Only yields dates from 1900 to 2100, and never in a leap month.
+    /// The year of the rule is the lunar year, so a date late in the lunar year
+    /// falls in January or February of the next Gregorian year,
+    /// and a rule for the 11th or 12th month may occur twice in a Gregorian year.
+    Lunar { month: i32, day: i32 },
+    /// A single date, such as a one-off closure.
+    OneOff(Date),
+}
+
+/// Largest number of days an `EasterOffset` rule may be away from Easter Sunday,
+/// so that its holidays fall in the year before, the year of, or the year after Easter.
+pub const MAX_EASTER_OFFSET: i32 = 366;
+
+impl DateRule {
+
+    /// Returns the date this rule falls on in `year`, before any observance shift,
+    /// or `None` if the rule doesn't yield a date in that year.
+    /// For `Lunar` rules, `year` is the lunar year, see [DateRule::Lunar].
+    pub fn date_in_year(&self, year: i32) -> Option<Date> {
+        match *self {
+            DateRule::Fixed { month, day } => Date::from_ymd(year, month, day).ok(),
+            DateRule::NthWeekday { month, weekday, nth } => {
+                if !(1..=12).contains(&month) || nth < 1 {
+                    return None;
+                }
+
+                let date = find_weekday(weekday, year, month, nth, true);
+                (date.month() == month).then_some(date)
+            }
+            DateRule::LastWeekday { month, weekday } => {
+                if !(1..=12).contains(&month) {
+                    return None;
+                }
+
+                Some(find_weekday(weekday, year, month, 1, false))
+            }
+            DateRule::EasterOffset(offset) => {
+                if !(-MAX_EASTER_OFFSET..=MAX_EASTER_OFFSET).contains(&offset) {
+                    return None;
+                }
+
+                easter::easter_date(year).ok()?.checked_advance_days(offset)
+            }
+            DateRule::Lunar { month, day } => lunar::lunar_date(year, month, day).ok(),
+            DateRule::OneOff(date) => (date.year() == year).then_some(date),
+        }
+    }
+}
+
+/// Determines the day a holiday is observed when it falls on a weekend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Observance {
+    /// The holiday is observed on its actual date, even on weekends.
+    #[default]
+    Actual,
+    /// A Saturday holiday is observed on the preceding Friday,
+    /// and a Sunday holiday on the following Monday, as in the United States.
+    NearestWeekday,
+    /// A Sunday holiday is observed on the following Monday.
+    SundayToMonday,
+    /// A weekend holiday is observed on the next weekday that is not already a holiday,
+    /// as with substitute days in the United Kingdom.
+    Substitute,
+}
+
+impl Observance {
+
+    /// Observed date of a holiday on `date`, for every observance but a `Substitute` on a weekend,
+    /// which depends on the other holidays.
+    fn observed_date(self, date: Date) -> Date {
+        match self {
+            Observance::Actual | Observance::Substitute => date,
+            Observance::NearestWeekday => adjust_weekend_holidays_us(date),
+            Observance::SundayToMonday if date.weekday() == Weekday::Sunday => date.next_date(),
+            Observance::SundayToMonday => date,
+        }
+    }
+}
+
+/// Days after a weekend holiday within which its substitute day is looked up.
+/// A substitute day is further away only after a month of consecutive holidays.
+const MAX_SUBSTITUTE_DAYS: i32 = 31;
+
+/// A named holiday, defined by a `DateRule`, an `Observance`
+/// and an optional range of years in which the rule is valid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HolidayRule {
+    name: String,
+    date: DateRule,
+    observance: Observance,
+    first_year: Option<i32>,
+    last_year: Option<i32>,
+}
+
+impl HolidayRule {
+
+    /// Creates a rule that is valid in every year and observed on its actual date.
+    pub fn new(name: impl Into<String>, date: DateRule) -> Self {
+        HolidayRule {
+            name: name.into(),
+            date,
+            observance: Observance::Actual,
+            first_year: None,
+            last_year: None,
+        }
+    }
+
+    /// Sets how the holiday is observed when it falls on a weekend.
+    pub fn with_observance(mut self, observance: Observance) -> Self {
+        self.observance = observance;
+        self
+    }
+
+    /// Sets the first year in which the rule is valid.
+    pub fn since(mut self, year: i32) -> Self {
+        self.first_year = Some(year);
+        self
+    }
+
+    /// Sets the last year in which the rule is valid.
+    pub fn until(mut self, year: i32) -> Self {
+        self.last_year = Some(year);
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn date_rule(&self) -> DateRule {
+        self.date
+    }
+
+    pub fn observance(&self) -> Observance {
+        self.observance
+    }
+
+    /// Returns `true` if the rule is valid in `year`.
+    pub fn applies_to(&self, year: i32) -> bool {
+        self.first_year.is_none_or(|first| first <= year)
+            && self.last_year.is_none_or(|last| year <= last)
+    }
+}
+
+/// A holiday calendar defined by a list of `HolidayRule`s.
+///
+/// A rule-based calendar can be built in code or parsed from a text definition
+/// in a subset of TOML, so new calendars don't require a new release of this crate.
+///
+/// ```
+/// use bdays::HolidayCalendar;
+/// use bdays::date::Date;
+/// use bdays::rules::RuleCalendar;
+///
+/// let cal: RuleCalendar = r#"
+///     name = "Example"
+///
+///     [[holiday]]
+///     name = "Christmas Day"
+///     month = 12
+///     day = 25
+///     observance = "substitute"
+///
+///     [[holiday]]
+///     name = "Spring Bank Holiday"
+///     month = 5
+///     weekday = "monday"
+///     nth = "last"
+/// "#.parse().unwrap();
+///
+/// assert!(cal.is_holiday(Date::from_ymd(2021, 12, 27).unwrap()));
+/// assert_eq!(cal.holiday_name(Date::from_ymd(2024, 5, 27).unwrap()), Some("Spring Bank Holiday"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RuleCalendar {
+    name: Option<String>,
+    rules: Vec<HolidayRule>,
+}
+
+impl RuleCalendar {
+
+    /// Creates a calendar from a list of rules.
+    pub fn new(rules: Vec<HolidayRule>) -> Self {
+        RuleCalendar {
+            name: None,
+            rules,
+        }
+    }
+
+    /// Sets a descriptive name for the calendar.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn rules(&self) -> &[HolidayRule] {
+        &self.rules
+    }
+
+    /// Returns the holidays observed in `year` with their names, sorted by date.
+    pub fn holidays(&self, year: i32) -> Vec<(Date, &str)> {
+        let mut holidays: Vec<(Date, &str)> = Vec::new();
+
+        // observance shifts can move a holiday across the turn of the year
+        for rules_year in (year - 1)..=(year + 1) {
+            holidays.extend(
+                self.observed_holidays(rules_year)
+                    .into_iter()
+                    .filter(|(date, _)| date.year() == year),
+            );
+        }
+
+        holidays.sort_by_key(|(date, _)| *date);
+        holidays
+    }
+
+    /// Evaluates the rules for `year`, returning the observed date of each holiday.
+    /// Observed dates may fall in the previous or in the next year.
+    fn observed_holidays(&self, year: i32) -> Vec<(Date, &str)> {
+        let mut observed: Vec<(Date, &str)> = Vec::new();
+        let mut substitutes: Vec<(Date, &str)> = Vec::new();
+
+        for rule in self.rules.iter().filter(|rule| rule.applies_to(year)) {
+            let Some(date) = rule.date.date_in_year(year) else {
+                continue;
+            };
+
+            let name = rule.name();
+
+            if rule.observance == Observance::Substitute && is_weekend(date) {
+                substitutes.push((date, name));
+            } else {
+                observed.push((rule.observance.observed_date(date), name));
+            }
+        }
+
+        // Substitute days are assigned in chronological order,
+        // skipping days that are already holidays.
+        substitutes.sort_by_key(|(date, _)| *date);
+
+        for (mut date, name) in substitutes {
+            while is_weekend(date) || observed.iter().any(|(holiday, _)| *holiday == date) {
+                date = date.next_date();
+            }
+
+            observed.push((date, name));
+        }
+
+        observed
+    }
+}
+
+impl HolidayCalendar for RuleCalendar {
+    fn is_holiday(&self, date: Date) -> bool {
+        self.holiday_name(date).is_some()
+    }
+
+    fn holidays_in_year(&self, year: i32) -> Option<Vec<Date>> {
+        Some(self.holidays(year).into_iter().map(|(date, _)| date).collect())
+    }
+
+    fn holiday_name(&self, date: Date) -> Option<&str> {
+        let yy = date.year();
+
+        // observance shifts can move a holiday across the turn of the year,
+        // the end of a lunar year falls in the next year,
+        // and Easter offsets reach into the years before and after Easter
+        let rules_years = (yy - 1)..=(yy + 1);
+
+        // Checks each rule on its own, in the order of `observed_holidays`,
+        // and only evaluates every rule when a substitute day may fall on `date`.
+        for rules_year in rules_years {
+            let mut substitute_nearby = false;
+
+            for rule in self.rules.iter().filter(|rule| rule.applies_to(rules_year)) {
+                let Some(holiday) = rule.date.date_in_year(rules_year) else {
+                    continue;
+                };
+
+                if rule.observance == Observance::Substitute && is_weekend(holiday) {
+                    substitute_nearby |= holiday < date && date - holiday <= MAX_SUBSTITUTE_DAYS;
+                } else if rule.observance.observed_date(holiday) == date {
+                    return Some(rule.name());
+                }
+            }
+
+            if substitute_nearby {
+                let name = self
+                    .observed_holidays(rules_year)
+                    .into_iter()
+                    .find(|(observed, _)| *observed == date)
+                    .map(|(_, name)| name);
+
+                if name.is_some() {
+                    return name;
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Error type for parsing a `RuleCalendar` definition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    message: String,
+}
+
+impl ParseError {
+    fn new(line: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            message: message.into(),
+        }
+    }
+
+    /// Returns the line number where the error was found, starting from 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl error::Error for ParseError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Value {
+    Integer(i32),
+    Text(String),
+    Date(Date),
+}
+
+impl Value {
+    fn parse(text: &str, line: usize) -> Result<Value, ParseError> {
+        if let Some(quoted) = text.strip_prefix('"') {
+            return match quoted.strip_suffix('"') {
+                Some(inner) if !inner.contains('"') => Ok(Value::Text(inner.to_string())),
+                _ => Err(ParseError::new(line, format!("invalid string {text}"))),
+            };
+        }
+
+        if let Ok(number) = text.parse::<i32>() {
+            return Ok(Value::Integer(number));
+        }
+
+        if let Ok(date) = text.parse::<Date>() {
+            return Ok(Value::Date(date));
+        }
+
+        Err(ParseError::new(line, format!("invalid value {text}")))
+    }
+
+    fn type_name(&self) -> &'static str {
+        match self {
+            Value::Integer(_) => "an integer",
+            Value::Text(_) => "a string",
+            Value::Date(_) => "a date",
+        }
+    }
+}
+
+const HOLIDAY_KEYS: [&str; 12] = [
+    "name",
+    "month",
+    "day",
+    "weekday",
+    "nth",
+    "easter_offset",
+    "lunar_month",
+    "lunar_day",
+    "date",
+    "observance",
+    "first_year",
+    "last_year",
+];
+
+/// Fields of a `[[holiday]]` table.
+struct HolidayTable {
+    line: usize,
+    fields: Vec<(String, Value, usize)>,
+}
+
+impl HolidayTable {
+
+    fn insert(&mut self, key: &str, value: Value, line: usize) -> Result<(), ParseError> {
+        if !HOLIDAY_KEYS.contains(&key) {
+            return Err(ParseError::new(line, format!("unknown key `{key}`")));
+        }
+
+        if self.fields.iter().any(|(k, _, _)| k == key) {
+            return Err(ParseError::new(line, format!("duplicate key `{key}`")));
+        }
+
+        self.fields.push((key.to_string(), value, line));
+        Ok(())
+    }
+
+    fn get(&self, key: &str) -> Option<(&Value, usize)> {
+        self.fields
+            .iter()
+            .find(|(k, _, _)| k == key)
+            .map(|(_, value, line)| (value, *line))
+    }
+
+    fn integer(&self, key: &str) -> Result<Option<i32>, ParseError> {
+        match self.get(key) {
+            None => Ok(None),
+            Some((Value::Integer(number), _)) => Ok(Some(*number)),
+            Some((value, line)) => Err(ParseError::new(
+                line,
+                format!("`{key}` must be an integer, found {}", value.type_name()),
+            )),
+        }
+    }
+
+    fn text(&self, key: &str) -> Result<Option<&str>, ParseError> {
+        match self.get(key) {
+            None => Ok(None),
+            Some((Value::Text(text), _)) => Ok(Some(text)),
+            Some((value, line)) => Err(ParseError::new(
+                line,
+                format!("`{key}` must be a string, found {}", value.type_name()),
+            )),
+        }
+    }
+
+    fn required_integer(&self, key: &str) -> Result<i32, ParseError> {
+        self.integer(key)?
+            .ok_or_else(|| ParseError::new(self.line, format!("missing key `{key}`")))
+    }
+
+    /// Fails if any key other than the common ones and `allowed` is present.
+    fn only_allows(&self, allowed: &[&str]) -> Result<(), ParseError> {
+        const COMMON_KEYS: [&str; 4] = ["name", "observance", "first_year", "last_year"];
+
+        for (key, _, line) in &self.fields {
+            if !COMMON_KEYS.contains(&key.as_str()) && !allowed.contains(&key.as_str()) {
+                return Err(ParseError::new(
+                    *line,
+                    format!("`{key}` cannot be combined with `{}`", allowed[0]),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn month(&self) -> Result<i32, ParseError> {
+        let month = self.required_integer("month")?;
+
+        if !(1..=12).contains(&month) {
+            let (_, line) = self.get("month").expect("month is present");
+            return Err(ParseError::new(line, format!("invalid month {month}")));
+        }
+
+        Ok(month)
+    }
+
+    fn date_rule(&self) -> Result<DateRule, ParseError> {
+        if let Some((value, line)) = self.get("date") {
+            self.only_allows(&["date"])?;
+
+            match value {
+                Value::Date(date) => Ok(DateRule::OneOff(*date)),
+                Value::Text(text) => text
+                    .parse::<Date>()
+                    .map(DateRule::OneOff)
+                    .map_err(|_| ParseError::new(line, format!("invalid date {text}"))),
+                _ => Err(ParseError::new(
+                    line,
+                    format!("`date` must be a date, found {}", value.type_name()),
+                )),
+            }
+        } else if let Some(offset) = self.integer("easter_offset")? {
+            self.only_allows(&["easter_offset"])?;
+
+            if !(-MAX_EASTER_OFFSET..=MAX_EASTER_OFFSET).contains(&offset) {
+                let (_, line) = self.get("easter_offset").expect("easter_offset is present");
+                return Err(ParseError::new(line, format!("invalid easter offset {offset}")));
+            }
+
+            Ok(DateRule::EasterOffset(offset))
+        } else if self.get("lunar_day").is_some() {
+            self.only_allows(&["lunar_day", "lunar_month"])?;
+
+            let month = self.required_integer("lunar_month")?;
+            let day = self.required_integer("lunar_day")?;
+
+            if !(1..=12).contains(&month) {
+                let (_, line) = self.get("lunar_month").expect("lunar_month is present");
+                return Err(ParseError::new(line, format!("invalid lunar month {month}")));
+            }
+
+            // lunar months have 29 or 30 days
+            if !(1..=30).contains(&day) {
+                let (_, line) = self.get("lunar_day").expect("lunar_day is present");
+                return Err(ParseError::new(line, format!("invalid lunar day {day}")));
+            }
+
+            Ok(DateRule::Lunar { month, day })
+        } else if let Some(weekday_name) = self.text("weekday")? {
+            self.only_allows(&["weekday", "month", "nth"])?;
+
+            let (_, weekday_line) = self.get("weekday").expect("weekday is present");
+            let weekday = parse_weekday(weekday_name).ok_or_else(|| {
+                ParseError::new(weekday_line, format!("invalid weekday {weekday_name}"))
+            })?;
+
+            let month = self.month()?;
+
+            match self.get("nth") {
+                Some((Value::Integer(nth), _)) if (1..=5).contains(nth) => {
+                    Ok(DateRule::NthWeekday { month, weekday, nth: *nth })
+                }
+                Some((Value::Text(text), _)) if text == "last" => {
+                    Ok(DateRule::LastWeekday { month, weekday })
+                }
+                Some((_, line)) => Err(ParseError::new(
+                    line,
+                    "`nth` must be an integer from 1 to 5 or \"last\"",
+                )),
+                None => Err(ParseError::new(self.line, "missing key `nth`")),
+            }
+        } else if self.get("day").is_some() {
+            self.only_allows(&["day", "month"])?;
+
+            let month = self.month()?;
+            let day = self.required_integer("day")?;
+
+            // validates against a leap year, so February 29 is accepted
+            if Date::from_ymd(2000, month, day).is_err() {
+                let (_, line) = self.get("day").expect("day is present");
+                return Err(ParseError::new(line, format!("invalid day {day} for month {month}")));
+            }
+
+            Ok(DateRule::Fixed { month, day })
+        } else {
+            Err(ParseError::new(
+                self.line,
+                "holiday must define one of `date`, `easter_offset`, `weekday`, `day` or `lunar_day`",
+            ))
+        }
+    }
+
+    fn into_rule(self) -> Result<HolidayRule, ParseError> {
+        let name = self
+            .text("name")?
+            .ok_or_else(|| ParseError::new(self.line, "missing key `name`"))?;
+
+        let mut rule = HolidayRule::new(name, self.date_rule()?);
+
+        if let Some(observance) = self.text("observance")? {
+            rule.observance = match observance {
+                "actual" => Observance::Actual,
+                "nearest_weekday" => Observance::NearestWeekday,
+                "sunday_to_monday" => Observance::SundayToMonday,
+                "substitute" => Observance::Substitute,
+                _ => {
+                    let (_, line) = self.get("observance").expect("observance is present");
+                    return Err(ParseError::new(line, format!("invalid observance {observance}")));
+                }
+            };
+        }
+
+        rule.first_year = self.integer("first_year")?;
+        rule.last_year = self.integer("last_year")?;
+
+        if let (Some(first), Some(last)) = (rule.first_year, rule.last_year)
+            && first > last
+        {
+            return Err(ParseError::new(self.line, "`first_year` is after `last_year`"));
+        }
+
+        Ok(rule)
+    }
+}
+
+fn parse_weekday(name: &str) -> Option<Weekday> {
+    match name.to_ascii_lowercase().as_str() {
+        "monday" => Some(Weekday::Monday),
+        "tuesday" => Some(Weekday::Tuesday),
+        "wednesday" => Some(Weekday::Wednesday),
+        "thursday" => Some(Weekday::Thursday),
+        "friday" => Some(Weekday::Friday),
+        "saturday" => Some(Weekday::Saturday),
+        "sunday" => Some(Weekday::Sunday),
+        _ => None,
+    }
+}
+
+/// Removes a trailing `#` comment, ignoring `#` inside quoted strings.
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+
+    for (index, c) in line.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..index],
+            _ => {}
+        }
+    }
+
+    line
+}
+
+/// Parses a calendar definition written in a subset of TOML.
+///
+/// The definition has an optional top-level `name` and a `[[holiday]]` table per rule.
+/// Each holiday has a `name` and one of the following date specifications:
+///
+/// * `month` and `day` for a fixed date;
+/// * `month`, `weekday` and `nth` (1 to 5, or `"last"`) for a weekday of the month;
+/// * `easter_offset` for a number of days relative to Easter Sunday, up to `MAX_EASTER_OFFSET`;
+/// * `lunar_month` and `lunar_day` for a date of the Chinese lunisolar calendar;
+/// * `date` (`YYYY-MM-DD`) for a one-off closure.
+///
+/// Optional keys are `observance` (`"actual"`, `"nearest_weekday"`, `"sunday_to_monday"`
+/// or `"substitute"`), `first_year` and `last_year`.
+impl FromStr for RuleCalendar {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut calendar = RuleCalendar::default();
+        let mut table: Option<HolidayTable> = None;
+
+        for (index, raw_line) in s.lines().enumerate() {
+            let line_number = index + 1;
+            let line = strip_comment(raw_line).trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if line.starts_with('[') {
+                if line != "[[holiday]]" {
+                    return Err(ParseError::new(line_number, format!("unknown table {line}")));
+                }
+
+                if let Some(finished) = table.take() {
+                    calendar.rules.push(finished.into_rule()?);
+                }
+
+                table = Some(HolidayTable {
+                    line: line_number,
+                    fields: Vec::new(),
+                });
+
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(ParseError::new(line_number, format!("expected `key = value`, found {line}")));
+            };
+
+            let key = key.trim();
+            let value = Value::parse(value.trim(), line_number)?;
+
+            match table.as_mut() {
+                Some(table) => table.insert(key, value, line_number)?,
+                None if key == "name" => match value {
+                    Value::Text(name) if calendar.name.is_none() => calendar.name = Some(name),
+                    Value::Text(_) => return Err(ParseError::new(line_number, "duplicate key `name`")),
+                    _ => return Err(ParseError::new(line_number, "`name` must be a string")),
+                },
+                None => return Err(ParseError::new(line_number, format!("unknown key `{key}`"))),
+            }
+        }
+
+        if let Some(finished) = table {
+            calendar.rules.push(finished.into_rule()?);
+        }
+
+        Ok(calendar)
+    }
+}
+
+#[cfg(test)]
+const US_FEDERAL_RULES: &str = r#"
+name = "United States federal holidays"
+
+[[holiday]]
+name = "New Year's Day"
+month = 1
+day = 1
+observance = "nearest_weekday"
+
+[[holiday]]
+name = "Birthday of Martin Luther King, Jr."
+month = 1
+weekday = "monday"
+nth = 3
+observance = "nearest_weekday"
+first_year = 1986
+
+[[holiday]]
+name = "Washington's Birthday"
+month = 2
+weekday = "monday"
+nth = 3
+
+[[holiday]]
+name = "Memorial Day"
+month = 5
+weekday = "monday"
+nth = "last"
+
+[[holiday]]
+name = "Juneteenth"
+month = 6
+day = 19
+observance = "nearest_weekday"
+first_year = 2021
+
+[[holiday]]
+name = "Independence Day"
+month = 7
+day = 4
+observance = "nearest_weekday"
+
+[[holiday]]
+name = "Labor Day"
+month = 9
+weekday = "monday"
+nth = 1
+
+[[holiday]]
+name = "Columbus Day"
+month = 10
+weekday = "monday"
+nth = 2
+
+[[holiday]]
+name = "Veterans Day"
+month = 11
+day = 11
+observance = "nearest_weekday"
+
+[[holiday]]
+name = "Thanksgiving Day"
+month = 11
+weekday = "thursday"
+nth = 4
+
+[[holiday]]
+name = "Christmas"
+month = 12
+day = 25
+observance = "nearest_weekday"
+"#;
+
+#[test]
+fn test_rules_match_us_settlement() {
+    let cal: RuleCalendar = US_FEDERAL_RULES.parse().unwrap();
+    let us = crate::calendars::us::USSettlement;
+
+    assert_eq!(cal.name(), Some("United States federal holidays"));
+    assert_eq!(cal.rules().len(), 11);
+
+    let mut date = Date::from_ymd(1980, 1, 1).unwrap();
+    let end = Date::from_ymd(2030, 12, 31).unwrap();
+
+    while date <= end {
+        // USSettlement also treats every Friday December 31 as a holiday
+        let (_, mm, dd) = date.to_ymd();
+        if !(mm == 12 && dd == 31) {
+            assert_eq!(cal.is_holiday(date), us.is_holiday(date), "{date}");
+        }
+
+        date = date.next_date();
+    }
+
+    // New Year's Day 2022 was on a Saturday
+    assert_eq!(cal.holiday_name(Date::from_ymd(2021, 12, 31).unwrap()), Some("New Year's Day"));
+    assert_eq!(cal.holidays(2022).len(), 10);
+    assert_eq!(cal.holidays(2021).len(), 12);
+}
+
+#[test]
+fn test_substitute_days() {
+    let cal = RuleCalendar::new(vec![
+        HolidayRule::new("New Year's Day", DateRule::Fixed { month: 1, day: 1 })
+            .with_observance(Observance::Substitute),
+        HolidayRule::new("Christmas Day", DateRule::Fixed { month: 12, day: 25 })
+            .with_observance(Observance::Substitute),
+        HolidayRule::new("Boxing Day", DateRule::Fixed { month: 12, day: 26 })
+            .with_observance(Observance::Substitute),
+    ]);
+
+    let dates = |year| -> Vec<(i32, i32, i32)> {
+        cal.holidays(year).into_iter().map(|(date, _)| date.to_ymd()).collect()
+    };
+
+    // Christmas on Saturday, Boxing Day on Sunday
+    assert_eq!(dates(2021), vec![(2021, 1, 1), (2021, 12, 27), (2021, 12, 28)]);
+    // Christmas on Sunday, Boxing Day on Monday
+    assert_eq!(dates(2022), vec![(2022, 1, 3), (2022, 12, 26), (2022, 12, 27)]);
+    assert_eq!(cal.holiday_name(Date::from_ymd(2022, 12, 27).unwrap()), Some("Christmas Day"));
+    assert!(!cal.is_holiday(Date::from_ymd(2022, 12, 25).unwrap()));
+}
+
+#[test]
+fn test_holiday_name_matches_holidays() {
+    let mut rules: Vec<HolidayRule> = US_FEDERAL_RULES.parse::<RuleCalendar>().unwrap().rules().to_vec();
+    rules.extend([
+        HolidayRule::new("Boxing Day", DateRule::Fixed { month: 12, day: 26 }).with_observance(Observance::Substitute),
+        HolidayRule::new("Day after Boxing Day", DateRule::Fixed { month: 12, day: 27 }).with_observance(Observance::Substitute),
+        HolidayRule::new("Second of January", DateRule::Fixed { month: 1, day: 2 }).with_observance(Observance::Substitute),
+        HolidayRule::new("Easter Monday", DateRule::EasterOffset(1)),
+        HolidayRule::new("Long after Easter", DateRule::EasterOffset(300)),
+        HolidayRule::new("Long before Easter", DateRule::EasterOffset(-MAX_EASTER_OFFSET)),
+        HolidayRule::new("Lunar New Year", DateRule::Lunar { month: 1, day: 1 }).with_observance(Observance::Substitute),
+        HolidayRule::new("Winter", DateRule::Lunar { month: 11, day: 15 }).with_observance(Observance::SundayToMonday),
+        HolidayRule::new("Christmas Day", DateRule::Fixed { month: 12, day: 25 }).with_observance(Observance::Substitute),
+    ]);
+    let cal = RuleCalendar::new(rules);
+
+    for year in 1990..=2030 {
+        let holidays = cal.holidays(year);
+        let mut date = Date::from_ymd(year, 1, 1).unwrap();
+
+        while date.year() == year {
+            let expected = holidays.iter().find(|(holiday, _)| *holiday == date).map(|(_, name)| *name);
+            assert_eq!(cal.holiday_name(date), expected, "{date}");
+            date = date.next_date();
+        }
+    }
+}
+
+#[test]
+fn test_rule_validity_and_one_off() {
+    let cal = RuleCalendar::new(vec![
+        HolidayRule::new("Early May Bank Holiday", DateRule::NthWeekday { month: 5, weekday: Weekday::Monday, nth: 1 })
+            .until(2019),
+        HolidayRule::new("VE Day", DateRule::OneOff(Date::from_ymd(2020, 5, 8).unwrap())),
+        HolidayRule::new("Good Friday", DateRule::EasterOffset(-2)).since(2000),
+        HolidayRule::new("Leap Day", DateRule::Fixed { month: 2, day: 29 }),
+        HolidayRule::new("Fifth Friday", DateRule::NthWeekday { month: 2, weekday: Weekday::Friday, nth: 5 }),
+    ]);
+
+    assert!(cal.is_holiday(Date::from_ymd(2019, 5, 6).unwrap()));
+    assert!(!cal.is_holiday(Date::from_ymd(2020, 5, 4).unwrap()));
+    assert!(cal.is_holiday(Date::from_ymd(2020, 5, 8).unwrap()));
+    assert!(!cal.is_holiday(Date::from_ymd(2021, 5, 7).unwrap()));
+
+    assert!(cal.is_holiday(Date::from_ymd(2023, 4, 7).unwrap()));
+    assert!(!cal.is_holiday(Date::from_ymd(1999, 4, 2).unwrap()));
+
+    assert!(cal.is_holiday(Date::from_ymd(2024, 2, 29).unwrap()));
+    assert_eq!(cal.holidays(2023).len(), 1);
+
+    // February 2036 has five Fridays, February 2035 doesn't
+    assert!(cal.is_holiday(Date::from_ymd(2036, 2, 29).unwrap()));
+    assert_eq!(cal.holidays(2035).len(), 1);
+}
+
+#[test]
+fn test_parse_errors() {
+    let error_line = |text: &str| text.parse::<RuleCalendar>().unwrap_err().line();
+
+    assert_eq!(error_line("[[holiday]]\nname = \"X\"\nmonth = 13\nday = 1"), 3);
+    assert_eq!(error_line("[[holiday]]\nname = \"X\"\nmonth = 2\nday = 30"), 4);
+    assert_eq!(error_line("[[holiday]]\nname = \"X\"\nmonth = 1"), 1);
+    assert_eq!(error_line("[[holiday]]\nmonth = 1\nday = 1"), 1);
+    assert_eq!(error_line("[[holiday]]\nname = \"X\"\nmonth = 1\nday = 1\nday = 2"), 5);
+    assert_eq!(error_line("[[holiday]]\nname = \"X\"\ncolor = \"red\""), 3);
+    assert_eq!(error_line("[[holiday]]\nname = \"X\"\ndate = 2020-05-08\nmonth = 5"), 4);
+    assert_eq!(error_line("[[holiday]]\nname = \"X\"\nmonth = 5\nweekday = \"mon\"\nnth = 1"), 4);
+    assert_eq!(error_line("[[holiday]]\nname = \"X\"\nmonth = 5\nweekday = \"monday\"\nnth = 0"), 5);
+    assert_eq!(error_line("[[holiday]]\nname = \"X\"\neaster_offset = 1\nobservance = \"never\""), 4);
+    assert_eq!(error_line("[[holidays]]"), 1);
+    assert_eq!(error_line("\ncountry = \"BR\""), 2);
+    assert_eq!(error_line("name = \"unterminated"), 1);
+    assert_eq!(error_line("[[holiday]]\nname = \"X\"\neaster_offset = 2147000000"), 3);
+    assert_eq!(error_line("[[holiday]]\nname = \"X\"\neaster_offset = -367"), 3);
+
+    let err = "[[holiday]]\nname = \"X\"\nmonth = 13\nday = 1".parse::<RuleCalendar>().unwrap_err();
+    assert_eq!(err.to_string(), "line 3: invalid month 13");
+
+    let cal: RuleCalendar = "[[holiday]]\nname = \"X\"\neaster_offset = 366".parse().unwrap();
+    assert_eq!(cal.holidays(2025), vec![(Date::from_ymd(2025, 4, 1).unwrap(), "X")]);
+    assert_eq!(DateRule::EasterOffset(2147000000).date_in_year(2025), None);
+}
+
+#[test]
+fn test_lunar_rules() {
+    let cal: RuleCalendar = r#"
+        [[holiday]]
+        name = "Lunar New Year"
+        lunar_month = 1
+        lunar_day = 1
+        observance = "substitute"
+
+        [[holiday]]
+        name = "Chuseok"
+        lunar_month = 8
+        lunar_day = 15
+
+        [[holiday]]
+        name = "Mid-month of the 12th month"
+        lunar_month = 12
+        lunar_day = 15
+    "#.parse().unwrap();
+
+    assert_eq!(cal.rules()[0].date_rule(), DateRule::Lunar { month: 1, day: 1 });
+    assert_eq!(cal.holiday_name(Date::from_ymd(2024, 2, 10).unwrap()), None); // Saturday, observed on Monday
+    assert_eq!(cal.holiday_name(Date::from_ymd(2024, 2, 12).unwrap()), Some("Lunar New Year"));
+    assert_eq!(cal.holiday_name(Date::from_ymd(2024, 9, 17).unwrap()), Some("Chuseok"));
+    assert_eq!(cal.holiday_name(Date::from_ymd(2025, 1, 29).unwrap()), Some("Lunar New Year"));
+
+    // the 12th month of lunar year 2024 is in January 2025
+    let date = lunar::lunar_date(2024, 12, 15).unwrap();
+    assert_eq!(date.to_ymd(), (2025, 1, 14));
+    assert_eq!(DateRule::Lunar { month: 12, day: 15 }.date_in_year(2024), Some(date));
+    assert!(cal.is_holiday(date));
+
+    // the 11th month of lunar years 2006 and 2007 both have a date in 2007
+    let cal = RuleCalendar::new(vec![HolidayRule::new("Winter", DateRule::Lunar { month: 11, day: 15 })]);
+    let january = Date::from_ymd(2007, 1, 3).unwrap();
+    let december = lunar::lunar_date(2007, 11, 15).unwrap();
+    assert_eq!(lunar::lunar_date(2006, 11, 15), Ok(january));
+    assert_eq!(december.year(), 2007);
+    assert_eq!(cal.holidays(2007), vec![(january, "Winter"), (december, "Winter")]);
+    assert!(cal.is_holiday(january));
+    assert!(cal.is_holiday(december));
+    assert_eq!(cal.holidays_in_year(2007), Some(vec![january, december]));
+
+    let january = Date::from_ymd(2026, 1, 3).unwrap();
+    assert_eq!(cal.holiday_name(january), Some("Winter"));
+    assert_eq!(cal.holidays(2026).len(), 2);
+
+    // the end of the 12th month may fall in February
+    let cal = RuleCalendar::new(vec![HolidayRule::new("Lunar New Year's Eve", DateRule::Lunar { month: 12, day: 29 })]);
+    let new_years_eve = lunar::lunar_date(2023, 12, 29).unwrap();
+    assert_eq!(new_years_eve.to_ymd(), (2024, 2, 8));
+    assert!(cal.is_holiday(new_years_eve));
+
+    // out of the lunar tables
+    assert_eq!(DateRule::Lunar { month: 1, day: 1 }.date_in_year(1899), None);
+    assert_eq!(DateRule::Lunar { month: 1, day: 1 }.date_in_year(2101), None);
+
+    let error_line = |text: &str| text.parse::<RuleCalendar>().unwrap_err().line();
+    assert_eq!(error_line("[[holiday]]\nname = \"X\"\nlunar_month = 13\nlunar_day = 1"), 3);
+    assert_eq!(error_line("[[holiday]]\nname = \"X\"\nlunar_month = 1\nlunar_day = 31"), 4);
+    assert_eq!(error_line("[[holiday]]\nname = \"X\"\nlunar_day = 1"), 1);
+    assert_eq!(error_line("[[holiday]]\nname = \"X\"\nlunar_day = 1\nlunar_month = 1\nmonth = 1"), 5);
+}
+
+#[test]
+fn test_parse_comments_and_dates() {
+    let cal: RuleCalendar = r#"
+        # one-off closures
+        [[holiday]]
+        name = "State Funeral # 1" # trailing comment
+        date = "2022-09-19"
+
+        [[holiday]]
+        name = "Coronation"
+        date = 2023-05-08
+    "#.parse().unwrap();
+
+    assert_eq!(cal.name(), None);
+    assert_eq!(cal.holiday_name(Date::from_ymd(2022, 9, 19).unwrap()), Some("State Funeral # 1"));
+    assert!(cal.is_holiday(Date::from_ymd(2023, 5, 8).unwrap()));
+    assert!(!cal.is_holiday(Date::from_ymd(2024, 5, 8).unwrap()));
+}
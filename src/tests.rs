@@ -640,6 +640,75 @@ fn test_us_settlement_no_cache() {
     us_settlement_tests(us);
 }
 
+#[test]
+fn test_us_settlement_historical() {
+    let us = calendars::us::USSettlement;
+    let holiday = |yy, mm, dd| us.is_holiday(Date::from_ymd(yy, mm, dd).expect("Valid date"));
+
+    // fixed dates before the Uniform Monday Holiday Act
+    assert!(holiday(1950, 2, 22)); // Washington's Birthday - Wednesday
+    assert!(!holiday(1950, 2, 20));
+    assert!(holiday(1950, 5, 30)); // Memorial Day - Tuesday
+    assert!(!holiday(1950, 5, 29));
+    assert!(holiday(1950, 10, 12)); // Columbus Day - Thursday
+    assert!(!holiday(1950, 10, 9));
+    assert!(holiday(1953, 11, 11)); // Veterans Day - Wednesday
+    assert!(holiday(1965, 5, 31)); // Memorial Day on a Sunday, observed on Monday
+    assert!(holiday(1970, 2, 23)); // Washington's Birthday on a Sunday, observed on Monday
+    assert!(!holiday(1970, 2, 16));
+
+    // Monday holidays since 1971
+    assert!(holiday(1971, 2, 15));
+    assert!(!holiday(1971, 2, 22));
+    assert!(holiday(1971, 5, 31));
+    assert!(holiday(1971, 10, 11));
+    assert!(!holiday(1971, 10, 12));
+
+    // Veterans Day on the fourth Monday of October from 1971 to 1977
+    assert!(holiday(1971, 10, 25));
+    assert!(!holiday(1971, 11, 11));
+    assert!(holiday(1977, 10, 24));
+    assert!(!holiday(1977, 11, 11));
+    assert!(holiday(1978, 11, 10)); // Saturday, observed on Friday
+    assert!(!holiday(1978, 10, 23));
+    assert!(holiday(1980, 11, 11));
+
+    // Inauguration Day is only a holiday in the District of Columbia
+    assert!(!holiday(1969, 1, 20));
+    assert!(!holiday(2009, 1, 20));
+    assert!(!holiday(2017, 1, 20));
+    assert!(!holiday(2021, 1, 20));
+
+    let dc = calendars::us::USSettlementDC;
+    let dc_holiday = |yy, mm, dd| dc.is_holiday(Date::from_ymd(yy, mm, dd).expect("Valid date"));
+
+    assert!(!dc_holiday(1961, 1, 20));
+    assert!(dc_holiday(1969, 1, 20));
+    assert!(!dc_holiday(1973, 1, 19)); // Saturday, not observed
+    assert!(dc_holiday(1977, 1, 20));
+    assert!(dc_holiday(1985, 1, 21)); // Sunday, observed on Monday
+    assert!(!dc_holiday(1978, 1, 20));
+    assert!(dc_holiday(2021, 1, 20));
+    assert!(dc_holiday(1986, 1, 20)); // Martin Luther King Jr. Day
+    assert_eq!(dc.holiday_name(Date::from_ymd(1977, 1, 20).expect("Valid date")), Some("Inauguration Day"));
+    assert_eq!(dc.holiday_name(Date::from_ymd(2013, 1, 21).expect("Valid date")), Some("Birthday of Martin Luther King, Jr."));
+
+    // Martin Luther King Jr. Day since 1986
+    assert!(!holiday(1984, 1, 16));
+    assert!(holiday(1986, 1, 20));
+
+    // Thanksgiving Day
+    assert!(holiday(1933, 11, 30)); // last Thursday
+    assert!(!holiday(1933, 11, 23));
+    assert!(holiday(1939, 11, 23)); // next to last Thursday
+    assert!(!holiday(1939, 11, 30));
+    assert!(holiday(1941, 11, 20));
+    assert!(holiday(1942, 11, 26)); // fourth Thursday
+
+    assert!(!holiday(1936, 10, 12)); // Columbus Day since 1937
+    assert!(holiday(1937, 10, 12));
+}
+
 #[test]
 fn test_us_settlement_cached() {
     let d0 = Date::from_ymd(1980, 1, 1).expect("Valid date");
@@ -648,6 +717,530 @@ fn test_us_settlement_cached() {
     us_settlement_tests(cal);
 }
 
+fn holidays_between<H: HolidayCalendar>(cal: &H, d0: Date, d1: Date) -> Vec<Date> {
+    let mut holidays = Vec::new();
+    let mut date = d0;
+
+    while date <= d1 {
+        if cal.is_holiday(date) {
+            holidays.push(date);
+        }
+
+        date = date.next_date();
+    }
+
+    holidays
+}
+
+fn dates(ymds: &[(i32, i32, i32)]) -> Vec<Date> {
+    ymds.iter().map(|&(yy, mm, dd)| Date::from_ymd(yy, mm, dd).expect("Valid date")).collect()
+}
+
+#[test]
+fn test_nyse() {
+    let cal = calendars::us::NYSE;
+    let d0 = Date::from_ymd(2021, 1, 1).expect("Valid date");
+    let d1 = Date::from_ymd(2025, 12, 31).expect("Valid date");
+
+    assert_eq!(
+        holidays_between(&cal, d0, d1),
+        dates(&[
+            (2021, 1, 1), (2021, 1, 18), (2021, 2, 15), (2021, 4, 2), (2021, 5, 31), (2021, 7, 5), (2021, 9, 6), (2021, 11, 25), (2021, 12, 24),
+            (2022, 1, 1), (2022, 1, 17), (2022, 2, 21), (2022, 4, 15), (2022, 5, 30), (2022, 6, 20), (2022, 7, 4), (2022, 9, 5), (2022, 11, 24), (2022, 12, 26),
+            (2023, 1, 2), (2023, 1, 16), (2023, 2, 20), (2023, 4, 7), (2023, 5, 29), (2023, 6, 19), (2023, 7, 4), (2023, 9, 4), (2023, 11, 23), (2023, 12, 25),
+            (2024, 1, 1), (2024, 1, 15), (2024, 2, 19), (2024, 3, 29), (2024, 5, 27), (2024, 6, 19), (2024, 7, 4), (2024, 9, 2), (2024, 11, 28), (2024, 12, 25),
+            (2025, 1, 1), (2025, 1, 9), (2025, 1, 20), (2025, 2, 17), (2025, 4, 18), (2025, 5, 26), (2025, 6, 19), (2025, 7, 4), (2025, 9, 1), (2025, 11, 27), (2025, 12, 25),
+        ])
+    );
+
+    assert!(cal.is_bday(Date::from_ymd(2021, 12, 31).expect("Valid date"))); // New Year's Day on a Saturday
+    assert!(cal.is_bday(Date::from_ymd(2021, 6, 18).expect("Valid date"))); // Juneteenth since 2022
+    assert!(cal.is_bday(Date::from_ymd(2023, 10, 9).expect("Valid date"))); // Columbus Day
+    assert!(cal.is_bday(Date::from_ymd(2023, 11, 10).expect("Valid date"))); // Veterans Day
+    assert!(cal.is_bday(Date::from_ymd(1997, 1, 20).expect("Valid date"))); // Martin Luther King Jr. Day since 1998
+    assert!(!cal.is_bday(Date::from_ymd(1998, 1, 19).expect("Valid date")));
+
+    // special closings
+    for (yy, mm, dd) in [(1976, 11, 2), (1977, 7, 14), (1985, 9, 27), (1994, 4, 27), (2001, 9, 11), (2001, 9, 14), (2004, 6, 11), (2007, 1, 2), (2012, 10, 29), (2012, 10, 30), (2018, 12, 5)] {
+        assert!(!cal.is_bday(Date::from_ymd(yy, mm, dd).expect("Valid date")), "{yy}-{mm}-{dd}");
+    }
+
+    assert!(cal.is_bday(Date::from_ymd(1984, 11, 6).expect("Valid date"))); // Election Day until 1980
+    assert!(cal.is_bday(Date::from_ymd(2001, 9, 17).expect("Valid date")));
+    assert_eq!(cal.holiday_name(Date::from_ymd(2012, 10, 29).expect("Valid date")), Some("Hurricane Sandy"));
+}
+
+#[test]
+fn test_sifma_bond() {
+    let cal = calendars::us::SIFMABond;
+    let d0 = Date::from_ymd(2023, 1, 1).expect("Valid date");
+    let d1 = Date::from_ymd(2024, 12, 31).expect("Valid date");
+
+    assert_eq!(
+        holidays_between(&cal, d0, d1),
+        dates(&[
+            (2023, 1, 2), (2023, 1, 16), (2023, 2, 20), (2023, 5, 29), (2023, 6, 19), (2023, 7, 4), (2023, 9, 4), (2023, 10, 9), (2023, 11, 10), (2023, 11, 23), (2023, 12, 25),
+            (2024, 1, 1), (2024, 1, 15), (2024, 2, 19), (2024, 3, 29), (2024, 5, 27), (2024, 6, 19), (2024, 7, 4), (2024, 9, 2), (2024, 10, 14), (2024, 11, 11), (2024, 11, 28), (2024, 12, 25),
+        ])
+    );
+
+    assert!(cal.is_bday(Date::from_ymd(2021, 12, 31).expect("Valid date"))); // New Year's Day on a Saturday
+    assert!(!cal.is_bday(Date::from_ymd(2021, 12, 24).expect("Valid date"))); // Christmas Day on a Saturday
+    assert!(!cal.is_bday(Date::from_ymd(2012, 10, 30).expect("Valid date"))); // Hurricane Sandy
+    assert!(!cal.is_bday(Date::from_ymd(2018, 12, 5).expect("Valid date"))); // Funeral of President George H. W. Bush
+
+    let early_closes: Vec<Date> = {
+        let mut date = d0;
+        let mut early_closes = Vec::new();
+
+        while date <= d1 {
+            if cal.is_early_close(date) {
+                early_closes.push(date);
+            }
+
+            date = date.next_date();
+        }
+
+        early_closes
+    };
+
+    assert_eq!(
+        early_closes,
+        dates(&[
+            (2023, 4, 6), (2023, 4, 7), (2023, 5, 26), (2023, 7, 3), (2023, 11, 24), (2023, 12, 22), (2023, 12, 29),
+            (2024, 3, 28), (2024, 5, 24), (2024, 7, 3), (2024, 11, 29), (2024, 12, 24), (2024, 12, 31),
+        ])
+    );
+
+    assert!(cal.is_early_close(Date::from_ymd(2021, 12, 23).expect("Valid date")));
+    assert!(cal.is_early_close(Date::from_ymd(2021, 12, 31).expect("Valid date")));
+    assert!(cal.is_early_close(Date::from_ymd(2025, 1, 9).expect("Valid date"))); // Funeral of President Carter
+    assert!(!cal.is_early_close(Date::from_ymd(2023, 4, 8).expect("Valid date"))); // Saturday
+}
+
+#[test]
+fn test_federal_reserve_and_nerc() {
+    let fed = calendars::us::FederalReserve;
+    assert!(!fed.is_bday(Date::from_ymd(2023, 1, 2).expect("Valid date"))); // New Year's Day on a Sunday
+    assert!(fed.is_bday(Date::from_ymd(2021, 12, 31).expect("Valid date"))); // New Year's Day on a Saturday
+    assert!(fed.is_bday(Date::from_ymd(2023, 11, 10).expect("Valid date"))); // Veterans Day on a Saturday
+    assert!(!fed.is_bday(Date::from_ymd(2023, 10, 9).expect("Valid date"))); // Columbus Day
+    assert!(fed.is_bday(Date::from_ymd(2021, 6, 18).expect("Valid date"))); // Juneteenth since 2022
+    assert!(!fed.is_bday(Date::from_ymd(2022, 6, 20).expect("Valid date")));
+    assert!(fed.is_bday(Date::from_ymd(2024, 3, 29).expect("Valid date"))); // Good Friday
+    assert!(fed.is_bday(Date::from_ymd(2018, 12, 5).expect("Valid date")));
+    assert!(fed.is_bday(Date::from_ymd(1985, 1, 21).expect("Valid date"))); // Martin Luther King Jr. Day since 1986
+    assert!(!fed.is_bday(Date::from_ymd(1986, 1, 20).expect("Valid date")));
+
+    let nerc = calendars::us::NERC;
+    let d0 = Date::from_ymd(2022, 1, 1).expect("Valid date");
+    let d1 = Date::from_ymd(2023, 12, 31).expect("Valid date");
+
+    assert_eq!(
+        holidays_between(&nerc, d0, d1),
+        dates(&[
+            (2022, 1, 1), (2022, 5, 30), (2022, 7, 4), (2022, 9, 5), (2022, 11, 24), (2022, 12, 26),
+            (2023, 1, 2), (2023, 5, 29), (2023, 7, 4), (2023, 9, 4), (2023, 11, 23), (2023, 12, 25),
+        ])
+    );
+}
+
+#[test]
+fn test_uk_settlement() {
+    // Bank holidays listed on https://www.gov.uk/bank-holidays
+    let cal = calendars::uk::UKSettlement;
+    let d0 = Date::from_ymd(2020, 1, 1).expect("Valid date");
+    let d1 = Date::from_ymd(2023, 12, 31).expect("Valid date");
+
+    assert_eq!(
+        holidays_between(&cal, d0, d1),
+        dates(&[
+            (2020, 1, 1), (2020, 4, 10), (2020, 4, 13), (2020, 5, 8), (2020, 5, 25), (2020, 8, 31), (2020, 12, 25), (2020, 12, 28),
+            (2021, 1, 1), (2021, 4, 2), (2021, 4, 5), (2021, 5, 3), (2021, 5, 31), (2021, 8, 30), (2021, 12, 27), (2021, 12, 28),
+            (2022, 1, 3), (2022, 4, 15), (2022, 4, 18), (2022, 5, 2), (2022, 6, 2), (2022, 6, 3), (2022, 8, 29), (2022, 9, 19), (2022, 12, 26), (2022, 12, 27),
+            (2023, 1, 2), (2023, 4, 7), (2023, 4, 10), (2023, 5, 1), (2023, 5, 8), (2023, 5, 29), (2023, 8, 28), (2023, 12, 25), (2023, 12, 26),
+        ])
+    );
+
+    assert_eq!(cal.holiday_name(Date::from_ymd(2020, 5, 8).expect("Valid date")), Some("Early May bank holiday"));
+    assert_eq!(cal.holiday_name(Date::from_ymd(2022, 9, 19).expect("Valid date")), Some("State Funeral of Queen Elizabeth II"));
+    assert_eq!(cal.holiday_name(Date::from_ymd(2022, 12, 27).expect("Valid date")), Some("Christmas Day"));
+    assert_eq!(cal.holiday_name(Date::from_ymd(2022, 12, 25).expect("Valid date")), None); // Sunday
+
+    // one-off bank holidays
+    assert!(!cal.is_bday(Date::from_ymd(1981, 7, 29).expect("Valid date"))); // royal wedding
+    assert!(!cal.is_bday(Date::from_ymd(1995, 5, 8).expect("Valid date"))); // VE Day 50th anniversary
+    assert!(cal.is_bday(Date::from_ymd(1995, 5, 1).expect("Valid date")));
+    assert!(!cal.is_bday(Date::from_ymd(1999, 12, 31).expect("Valid date"))); // millennium
+    assert!(!cal.is_bday(Date::from_ymd(2000, 1, 3).expect("Valid date"))); // New Year's Day on a Saturday
+    assert!(!cal.is_bday(Date::from_ymd(2002, 6, 3).expect("Valid date"))); // Golden Jubilee
+    assert!(!cal.is_bday(Date::from_ymd(2002, 6, 4).expect("Valid date"))); // Spring bank holiday
+    assert!(cal.is_bday(Date::from_ymd(2002, 5, 27).expect("Valid date")));
+    assert!(!cal.is_bday(Date::from_ymd(2011, 4, 29).expect("Valid date"))); // royal wedding
+    assert!(!cal.is_bday(Date::from_ymd(2012, 6, 4).expect("Valid date"))); // Spring bank holiday
+    assert!(!cal.is_bday(Date::from_ymd(2012, 6, 5).expect("Valid date"))); // Diamond Jubilee
+    assert!(cal.is_bday(Date::from_ymd(2012, 5, 28).expect("Valid date")));
+
+    // New Year's Day is a bank holiday since 1974, and the Early May bank holiday since 1978
+    assert!(cal.is_bday(Date::from_ymd(1973, 1, 1).expect("Valid date")));
+    assert!(!cal.is_bday(Date::from_ymd(1974, 1, 1).expect("Valid date")));
+    assert!(cal.is_bday(Date::from_ymd(1977, 5, 2).expect("Valid date")));
+    assert!(!cal.is_bday(Date::from_ymd(1978, 5, 1).expect("Valid date")));
+
+    let cached = HolidayCalendarCache::new(calendars::uk::UKSettlement, d0, d1);
+    assert_eq!(holidays_between(&cached, d0, d1), holidays_between(&cal, d0, d1));
+
+    let mut date = d0;
+    while date <= d1 {
+        assert_eq!(cached.holiday_name(date), cal.holiday_name(date));
+        date = date.next_date();
+    }
+    assert_eq!(cached.holiday_name(Date::from_ymd(2022, 9, 19).expect("Valid date")), Some("State Funeral of Queen Elizabeth II"));
+}
+
+#[test]
+fn test_uk_regions() {
+    let scotland = calendars::uk::ScotlandSettlement;
+    let d0 = Date::from_ymd(2022, 1, 1).expect("Valid date");
+    let d1 = Date::from_ymd(2023, 12, 31).expect("Valid date");
+
+    assert_eq!(
+        holidays_between(&scotland, d0, d1),
+        dates(&[
+            (2022, 1, 3), (2022, 1, 4), (2022, 4, 15), (2022, 5, 2), (2022, 6, 2), (2022, 6, 3), (2022, 8, 1), (2022, 9, 19), (2022, 11, 30), (2022, 12, 26), (2022, 12, 27),
+            (2023, 1, 2), (2023, 1, 3), (2023, 4, 7), (2023, 5, 1), (2023, 5, 8), (2023, 5, 29), (2023, 8, 7), (2023, 11, 30), (2023, 12, 25), (2023, 12, 26),
+        ])
+    );
+
+    assert!(!scotland.is_bday(Date::from_ymd(2019, 12, 2).expect("Valid date"))); // St Andrew's Day on a Saturday
+    assert!(scotland.is_bday(Date::from_ymd(2006, 11, 30).expect("Valid date"))); // St Andrew's Day since 2007
+    assert!(!scotland.is_bday(Date::from_ymd(2000, 1, 3).expect("Valid date")));
+    assert!(!scotland.is_bday(Date::from_ymd(2000, 1, 4).expect("Valid date")));
+
+    let northern_ireland = calendars::uk::NorthernIrelandSettlement;
+
+    assert_eq!(
+        holidays_between(&northern_ireland, d0, d1),
+        dates(&[
+            (2022, 1, 3), (2022, 3, 17), (2022, 4, 15), (2022, 4, 18), (2022, 5, 2), (2022, 6, 2), (2022, 6, 3), (2022, 7, 12), (2022, 8, 29), (2022, 9, 19), (2022, 12, 26), (2022, 12, 27),
+            (2023, 1, 2), (2023, 3, 17), (2023, 4, 7), (2023, 4, 10), (2023, 5, 1), (2023, 5, 8), (2023, 5, 29), (2023, 7, 12), (2023, 8, 28), (2023, 12, 25), (2023, 12, 26),
+        ])
+    );
+
+    assert!(!northern_ireland.is_bday(Date::from_ymd(2018, 3, 19).expect("Valid date"))); // St Patrick's Day on a Saturday
+    assert!(!northern_ireland.is_bday(Date::from_ymd(2020, 7, 13).expect("Valid date"))); // Battle of the Boyne on a Sunday
+    assert_eq!(northern_ireland.holiday_name(Date::from_ymd(2023, 7, 12).expect("Valid date")), Some("Battle of the Boyne"));
+
+    let lse = calendars::uk::LSE;
+    assert_eq!(holidays_between(&lse, d0, d1), holidays_between(&calendars::uk::UKSettlement, d0, d1));
+    assert!(lse.is_bday(Date::from_ymd(2024, 12, 24).expect("Valid date"))); // half trading day
+    assert!(lse.is_bday(Date::from_ymd(2024, 12, 31).expect("Valid date"))); // half trading day
+
+    // no Easter dates before 1583, and no holidays either
+    let d0 = Date::from_ymd(1500, 1, 10).expect("Valid date");
+    let d1 = Date::from_ymd(1500, 3, 31).expect("Valid date");
+    assert_eq!(calendars::uk::UKSettlement.bdays(d0, d1), super::weekdays_between(d0, d1));
+    assert_eq!(calendars::uk::ScotlandSettlement.bdays(d0, d1), super::weekdays_between(d0, d1));
+    assert_eq!(northern_ireland.bdays(d0, d1), super::weekdays_between(d0, d1));
+    assert_eq!(lse.bdays(d0, d1), super::weekdays_between(d0, d1));
+}
+
+#[test]
+fn test_target() {
+    // TARGET2 closing days published by the ECB
+    let cal = calendars::eu::Target;
+    assert_eq!(format!("{cal:?}"), "Target");
+
+    let d0 = Date::from_ymd(2023, 1, 1).expect("Valid date");
+    let d1 = Date::from_ymd(2025, 12, 31).expect("Valid date");
+
+    assert_eq!(
+        holidays_between(&cal, d0, d1),
+        dates(&[
+            (2023, 1, 1), (2023, 4, 7), (2023, 4, 10), (2023, 5, 1), (2023, 12, 25), (2023, 12, 26),
+            (2024, 1, 1), (2024, 3, 29), (2024, 4, 1), (2024, 5, 1), (2024, 12, 25), (2024, 12, 26),
+            (2025, 1, 1), (2025, 4, 18), (2025, 4, 21), (2025, 5, 1), (2025, 12, 25), (2025, 12, 26),
+        ])
+    );
+
+    assert_eq!(cal.holiday_name(Date::from_ymd(2024, 12, 26).expect("Valid date")), Some("Christmas Holiday"));
+    assert!(cal.is_bday(Date::from_ymd(2023, 1, 2).expect("Valid date"))); // no substitute day for New Year's Day on a Sunday
+    assert!(cal.is_bday(Date::from_ymd(2024, 5, 9).expect("Valid date"))); // Ascension Day
+    assert!(cal.is_bday(Date::from_ymd(2024, 10, 3).expect("Valid date"))); // German Unity Day
+
+    // 1999 to 2001
+    assert_eq!(
+        holidays_between(&cal, Date::from_ymd(1999, 1, 1).expect("Valid date"), Date::from_ymd(2002, 12, 31).expect("Valid date")),
+        dates(&[
+            (1999, 1, 1), (1999, 12, 25), (1999, 12, 31),
+            (2000, 1, 1), (2000, 4, 21), (2000, 4, 24), (2000, 5, 1), (2000, 12, 25), (2000, 12, 26),
+            (2001, 1, 1), (2001, 4, 13), (2001, 4, 16), (2001, 5, 1), (2001, 12, 25), (2001, 12, 26), (2001, 12, 31),
+            (2002, 1, 1), (2002, 3, 29), (2002, 4, 1), (2002, 5, 1), (2002, 12, 25), (2002, 12, 26),
+        ])
+    );
+
+    assert!(cal.is_bday(Date::from_ymd(1999, 4, 2).expect("Valid date"))); // Good Friday, open in 1999
+    assert!(cal.is_bday(Date::from_ymd(2000, 12, 29).expect("Valid date")));
+}
+
+#[test]
+fn test_japan_settlement() {
+    // national holidays published by the Cabinet Office
+    let cal = calendars::jp::JapanSettlement;
+    assert_eq!(format!("{cal:?}"), "JapanSettlement");
+
+    let d0 = Date::from_ymd(2019, 1, 1).expect("Valid date");
+    let d1 = Date::from_ymd(2021, 12, 31).expect("Valid date");
+
+    assert_eq!(
+        holidays_between(&cal, d0, d1),
+        dates(&[
+            (2019, 1, 1), (2019, 1, 14), (2019, 2, 11), (2019, 3, 21), (2019, 4, 29), (2019, 4, 30), (2019, 5, 1), (2019, 5, 2),
+            (2019, 5, 3), (2019, 5, 4), (2019, 5, 5), (2019, 5, 6), (2019, 7, 15), (2019, 8, 11), (2019, 8, 12), (2019, 9, 16),
+            (2019, 9, 23), (2019, 10, 14), (2019, 10, 22), (2019, 11, 3), (2019, 11, 4), (2019, 11, 23),
+            (2020, 1, 1), (2020, 1, 13), (2020, 2, 11), (2020, 2, 23), (2020, 2, 24), (2020, 3, 20), (2020, 4, 29), (2020, 5, 3),
+            (2020, 5, 4), (2020, 5, 5), (2020, 5, 6), (2020, 7, 23), (2020, 7, 24), (2020, 8, 10), (2020, 9, 21), (2020, 9, 22),
+            (2020, 11, 3), (2020, 11, 23),
+            (2021, 1, 1), (2021, 1, 11), (2021, 2, 11), (2021, 2, 23), (2021, 3, 20), (2021, 4, 29), (2021, 5, 3), (2021, 5, 4),
+            (2021, 5, 5), (2021, 7, 22), (2021, 7, 23), (2021, 8, 8), (2021, 8, 9), (2021, 9, 20), (2021, 9, 23), (2021, 11, 3),
+            (2021, 11, 23),
+        ])
+    );
+
+    assert_eq!(cal.holiday_name(Date::from_ymd(2019, 4, 30).expect("Valid date")), Some("Citizen's Holiday"));
+    assert_eq!(cal.holiday_name(Date::from_ymd(2019, 5, 6).expect("Valid date")), Some("Substitute Holiday"));
+    assert_eq!(cal.holiday_name(Date::from_ymd(2020, 7, 24).expect("Valid date")), Some("Sports Day"));
+    assert_eq!(cal.holiday_name(Date::from_ymd(2019, 10, 14).expect("Valid date")), Some("Health and Sports Day"));
+    assert_eq!(cal.holiday_name(Date::from_ymd(2026, 9, 22).expect("Valid date")), Some("Citizen's Holiday"));
+    assert!(cal.is_bday(Date::from_ymd(2021, 8, 11).expect("Valid date"))); // Mountain Day, moved in 2021
+    assert!(cal.is_bday(Date::from_ymd(2019, 12, 23).expect("Valid date"))); // no Emperor's Birthday in 2019
+
+    // historical rules
+    assert!(cal.is_bday(Date::from_ymd(1948, 1, 1).expect("Valid date"))); // before the National Holidays Act
+    assert_eq!(cal.holiday_name(Date::from_ymd(1959, 4, 10).expect("Valid date")), Some("Wedding of Crown Prince Akihito"));
+    assert!(cal.is_bday(Date::from_ymd(1967, 1, 16).expect("Valid date"))); // no substitute holiday before 1973
+    assert_eq!(cal.holiday_name(Date::from_ymd(1973, 4, 30).expect("Valid date")), Some("Substitute Holiday"));
+    assert_eq!(cal.holiday_name(Date::from_ymd(1979, 9, 24).expect("Valid date")), Some("Autumnal Equinox Day"));
+    assert_eq!(cal.holiday_name(Date::from_ymd(1988, 4, 29).expect("Valid date")), Some("Emperor's Birthday"));
+    assert_eq!(cal.holiday_name(Date::from_ymd(1988, 5, 4).expect("Valid date")), Some("Citizen's Holiday"));
+    assert_eq!(cal.holiday_name(Date::from_ymd(1999, 1, 15).expect("Valid date")), Some("Coming of Age Day"));
+    assert_eq!(cal.holiday_name(Date::from_ymd(2006, 4, 29).expect("Valid date")), Some("Greenery Day"));
+    assert_eq!(cal.holiday_name(Date::from_ymd(2007, 4, 29).expect("Valid date")), Some("Showa Day"));
+    assert!(cal.is_bday(Date::from_ymd(2003, 5, 6).expect("Valid date"))); // before 2007, substitute holidays were only on Mondays
+    assert_eq!(cal.holiday_name(Date::from_ymd(2008, 5, 6).expect("Valid date")), Some("Substitute Holiday"));
+    assert_eq!(cal.holiday_name(Date::from_ymd(2009, 9, 22).expect("Valid date")), Some("Citizen's Holiday"));
+}
+
+#[test]
+fn test_japan_equinox() {
+    use calendars::jp::{autumnal_equinox_day, vernal_equinox_day};
+
+    assert_eq!(vernal_equinox_day(1900), Some(Date::from_ymd(1900, 3, 21).expect("Valid date")));
+    assert_eq!(vernal_equinox_day(1960), Some(Date::from_ymd(1960, 3, 20).expect("Valid date")));
+    assert_eq!(vernal_equinox_day(1978), Some(Date::from_ymd(1978, 3, 21).expect("Valid date")));
+    assert_eq!(vernal_equinox_day(2023), Some(Date::from_ymd(2023, 3, 21).expect("Valid date")));
+    assert_eq!(vernal_equinox_day(2024), Some(Date::from_ymd(2024, 3, 20).expect("Valid date")));
+    assert_eq!(autumnal_equinox_day(1950), Some(Date::from_ymd(1950, 9, 23).expect("Valid date")));
+    assert_eq!(autumnal_equinox_day(1979), Some(Date::from_ymd(1979, 9, 24).expect("Valid date")));
+    assert_eq!(autumnal_equinox_day(2012), Some(Date::from_ymd(2012, 9, 22).expect("Valid date")));
+    assert_eq!(autumnal_equinox_day(2025), Some(Date::from_ymd(2025, 9, 23).expect("Valid date")));
+    assert_eq!(vernal_equinox_day(1899), None);
+    assert_eq!(autumnal_equinox_day(2151), None);
+
+    for year in 1900..=2150 {
+        let vernal = vernal_equinox_day(year).expect("Valid year").to_ymd();
+        let autumnal = autumnal_equinox_day(year).expect("Valid year").to_ymd();
+        assert!((19..=22).contains(&vernal.2), "{year}");
+        assert!((21..=24).contains(&autumnal.2), "{year}");
+    }
+}
+
+#[test]
+fn test_jpx() {
+    let cal = calendars::jp::JPX;
+
+    assert_eq!(
+        holidays_between(&cal, Date::from_ymd(2022, 12, 30).expect("Valid date"), Date::from_ymd(2023, 1, 10).expect("Valid date")),
+        dates(&[(2022, 12, 31), (2023, 1, 1), (2023, 1, 2), (2023, 1, 3), (2023, 1, 9)])
+    );
+
+    assert_eq!(cal.holiday_name(Date::from_ymd(2023, 1, 2).expect("Valid date")), Some("Substitute Holiday"));
+    assert_eq!(cal.holiday_name(Date::from_ymd(2024, 1, 3).expect("Valid date")), Some("New Year Holiday"));
+    assert_eq!(cal.holiday_name(Date::from_ymd(2024, 12, 31).expect("Valid date")), Some("Year-end Holiday"));
+    assert!(cal.is_bday(Date::from_ymd(2024, 12, 30).expect("Valid date")));
+    assert!(!cal.is_bday(Date::from_ymd(2024, 11, 4).expect("Valid date"))); // Substitute Holiday for Culture Day
+}
+
+#[test]
+fn test_sse() {
+    let cal = calendars::cn::SSE;
+    assert_eq!(format!("{cal:?}"), "SSE");
+
+    // 2024 Spring Festival
+    assert_eq!(
+        holidays_between(&cal, Date::from_ymd(2024, 2, 1).expect("Valid date"), Date::from_ymd(2024, 2, 29).expect("Valid date")),
+        dates(&[(2024, 2, 9), (2024, 2, 10), (2024, 2, 11), (2024, 2, 12), (2024, 2, 13), (2024, 2, 14), (2024, 2, 15), (2024, 2, 16), (2024, 2, 17)])
+    );
+
+    assert_eq!(cal.holiday_name(Date::from_ymd(2023, 10, 2).expect("Valid date")), Some("Mid-Autumn Festival and National Day"));
+    assert_eq!(cal.holiday_name(Date::from_ymd(2015, 9, 3).expect("Valid date")), Some("Victory Day"));
+    assert!(!cal.is_bday(Date::from_ymd(2024, 2, 18).expect("Valid date"))); // working Sunday, but the exchange is closed
+    assert!(!cal.is_bday(Date::from_ymd(2020, 1, 31).expect("Valid date"))); // extended Spring Festival
+    assert!(cal.is_bday(Date::from_ymd(2020, 2, 3).expect("Valid date")));
+    assert_eq!(cal.bdays(Date::from_ymd(2024, 2, 8).expect("Valid date"), Date::from_ymd(2024, 2, 19).expect("Valid date")), 1);
+
+    // only the published schedules are known
+    assert!(cal.covers_year(calendars::cn::FIRST_KNOWN_YEAR));
+    assert!(cal.covers_year(calendars::cn::LAST_KNOWN_YEAR));
+    assert!(!cal.covers_year(calendars::cn::LAST_KNOWN_YEAR + 1));
+    assert!(!calendars::from_code("CFETS").expect("Valid code").covers_year(2007));
+    assert!(calendars::us::USSettlement.covers_year(2027));
+}
+
+#[test]
+fn test_cfets() {
+    let cal = calendars::cn::CFETS;
+
+    let d0 = Date::from_ymd(2024, 2, 8).expect("Valid date");
+    let working_sunday = Date::from_ymd(2024, 2, 18).expect("Valid date");
+    assert!(cal.is_working_weekend(working_sunday));
+    assert!(cal.is_bday(working_sunday));
+    assert!(!cal.is_bday(Date::from_ymd(2024, 2, 17).expect("Valid date"))); // Saturday, in the holiday
+    assert!(!cal.is_bday(Date::from_ymd(2024, 2, 25).expect("Valid date"))); // regular Sunday
+    assert_eq!(cal.to_bday(Date::from_ymd(2024, 2, 10).expect("Valid date"), true), working_sunday);
+    assert_eq!(cal.advance_bdays(d0, 1), working_sunday);
+    assert_eq!(cal.bdays(d0, Date::from_ymd(2024, 2, 19).expect("Valid date")), 2);
+    assert_eq!(cal.bdays(Date::from_ymd(2024, 2, 19).expect("Valid date"), d0), -2);
+
+    // the State Council schedule: working weekend days are weekends, outside holidays
+    let mut date = Date::from_ymd(2007, 1, 1).expect("Valid date");
+    let mut working_weekends = 0;
+
+    while date.year() <= 2026 {
+        if cal.is_working_weekend(date) {
+            assert!(super::is_weekend(date), "{date}");
+            assert!(!cal.is_holiday(date), "{date}");
+            working_weekends += 1;
+        }
+
+        date = date.next_date();
+    }
+
+    assert_eq!(working_weekends, 124);
+
+    // the cache respects working weekend days
+    let d1 = Date::from_ymd(2026, 12, 31).expect("Valid date");
+    let d0 = Date::from_ymd(2007, 1, 1).expect("Valid date");
+    let cached_cal = HolidayCalendarCache::new(calendars::cn::CFETS, d0, d1);
+    assert!(cached_cal.is_bday(working_sunday));
+    assert!(cached_cal.is_working_weekend(working_sunday));
+    assert!(!cached_cal.is_working_weekend(Date::from_ymd(2024, 2, 25).expect("Valid date")));
+    assert_eq!(cached_cal.bdays(d0, d1), cal.bdays(d0, d1));
+    assert_eq!(cached_cal.bdays(d0, d1), calendars::cn::SSE.bdays(d0, d1) + 124);
+
+    let boxed = calendars::from_code("CFETS").expect("Valid code");
+    assert!(boxed.is_bday(working_sunday));
+    assert_eq!(boxed.bdays(d0, d1), cal.bdays(d0, d1));
+}
+
+#[test]
+fn test_tadawul() {
+    let cal = calendars::sa::Tadawul::new();
+    let ymd = |yy, mm, dd| Date::from_ymd(yy, mm, dd).expect("Valid date");
+
+    // Thursday and Friday weekend until June 2013, then Friday and Saturday
+    assert!(!cal.is_bday(ymd(2012, 1, 5))); // Thursday
+    assert!(!cal.is_bday(ymd(2012, 1, 6))); // Friday
+    assert!(cal.is_bday(ymd(2012, 1, 7))); // Saturday
+    assert!(cal.is_bday(ymd(2024, 4, 18))); // Thursday
+    assert!(!cal.is_bday(ymd(2024, 4, 20))); // Saturday
+    assert!(cal.is_bday(ymd(2024, 4, 21))); // Sunday
+    assert_eq!(cal.advance_bdays(ymd(2024, 4, 18), 1), ymd(2024, 4, 21));
+    assert!(!cal.is_working_weekend(ymd(2024, 4, 21)));
+
+    // Eid al-Fitr 1445: Ramadan had 30 days, the holiday starts on 30 Ramadan
+    assert_eq!(holidays_between(&cal, ymd(2024, 4, 1), ymd(2024, 4, 30)), dates(&[(2024, 4, 9), (2024, 4, 10), (2024, 4, 11), (2024, 4, 12)]));
+    // Eid al-Fitr 1444: Ramadan had 29 days
+    assert_eq!(holidays_between(&cal, ymd(2023, 4, 1), ymd(2023, 4, 30)), dates(&[(2023, 4, 21), (2023, 4, 22), (2023, 4, 23), (2023, 4, 24)]));
+    // Eid al-Adha 1445, from Arafat Day
+    assert_eq!(holidays_between(&cal, ymd(2024, 6, 1), ymd(2024, 6, 30)), dates(&[(2024, 6, 15), (2024, 6, 16), (2024, 6, 17), (2024, 6, 18)]));
+    assert_eq!(cal.holiday_name(ymd(2024, 6, 15)), Some("Eid al-Adha"));
+
+    // National Day on a Friday is observed on Thursday since 2022
+    assert_eq!(holidays_between(&cal, ymd(2022, 9, 1), ymd(2022, 9, 30)), dates(&[(2022, 9, 22), (2022, 9, 23)]));
+    assert_eq!(holidays_between(&cal, ymd(2016, 9, 20), ymd(2016, 9, 30)), dates(&[(2016, 9, 23)]));
+    assert!(!cal.is_holiday(ymd(2004, 9, 23)));
+    // Founding Day on a Saturday is observed on Sunday
+    assert_eq!(cal.holiday_name(ymd(2025, 2, 23)), Some("Founding Day"));
+    assert!(!cal.is_bday(ymd(2025, 2, 23)));
+    assert!(!cal.is_holiday(ymd(2021, 2, 22)));
+
+    // more announcements can be added
+    let tabular = calendars::sa::Tadawul::new().with_month_start(1448, 10, ymd(2027, 3, 9)).expect("Valid month start");
+    assert_eq!(tabular.holiday_name(ymd(2027, 3, 9)), Some("Eid al-Fitr"));
+    assert_eq!(tabular.hijri_calendar().month_start(1448, 10), Ok(ymd(2027, 3, 9)));
+
+    // the cache respects the weekend
+    let d0 = ymd(2010, 1, 1);
+    let d1 = ymd(2026, 12, 31);
+    let cached_cal = HolidayCalendarCache::new(cal.clone(), d0, d1);
+    assert!(cached_cal.is_weekend(ymd(2024, 4, 19)));
+    assert!(!cached_cal.is_weekend(ymd(2024, 4, 21)));
+    assert!(cached_cal.is_bday(ymd(2024, 4, 21)));
+    assert_eq!(cached_cal.bdays(d0, d1), cal.bdays(d0, d1));
+    assert_eq!(cached_cal.advance_bdays(ymd(2024, 4, 8), 1), ymd(2024, 4, 14));
+
+    let boxed = calendars::from_code("Tadawul").expect("Valid code");
+    assert!(boxed.is_weekend(ymd(2024, 4, 20)));
+    assert_eq!(boxed.bdays(d0, d1), cal.bdays(d0, d1));
+}
+
+#[test]
+fn test_uae_settlement() {
+    let cal = calendars::ae::UAESettlement::new();
+    let ymd = |yy, mm, dd| Date::from_ymd(yy, mm, dd).expect("Valid date");
+
+    // Thursday and Friday weekend until August 2006, Friday and Saturday until 2021, then Saturday and Sunday
+    assert!(!cal.is_bday(ymd(2006, 8, 31))); // Thursday
+    assert!(cal.is_bday(ymd(2006, 8, 26))); // Saturday
+    assert!(cal.is_bday(ymd(2006, 9, 7))); // Thursday
+    assert!(!cal.is_bday(ymd(2006, 9, 9))); // Saturday
+    assert!(cal.is_bday(ymd(2021, 12, 26))); // Sunday
+    assert!(!cal.is_bday(ymd(2021, 12, 31))); // Friday
+    assert!(!cal.is_bday(ymd(2022, 1, 2))); // Sunday
+    assert!(cal.is_bday(ymd(2022, 1, 7))); // Friday
+
+    // Eid al-Fitr from 29 Ramadan since 2019
+    assert_eq!(holidays_between(&cal, ymd(2024, 4, 1), ymd(2024, 4, 30)), dates(&[(2024, 4, 8), (2024, 4, 9), (2024, 4, 10), (2024, 4, 11), (2024, 4, 12)]));
+    assert_eq!(holidays_between(&cal, ymd(2018, 6, 1), ymd(2018, 6, 30)), dates(&[(2018, 6, 15), (2018, 6, 16), (2018, 6, 17)]));
+    assert_eq!(holidays_between(&cal, ymd(2024, 6, 1), ymd(2024, 6, 30)), dates(&[(2024, 6, 15), (2024, 6, 16), (2024, 6, 17), (2024, 6, 18)]));
+    assert_eq!(cal.holiday_name(ymd(2024, 7, 7)), Some("Islamic New Year"));
+
+    // Isra and Mi'raj until 2018
+    let isra_miraj = cal.hijri_calendar().to_date(1439, 7, 27).expect("Valid date");
+    assert_eq!(cal.holiday_name(isra_miraj), Some("Isra and Mi'raj"));
+    assert!(!cal.is_holiday(cal.hijri_calendar().to_date(1441, 7, 27).expect("Valid date")));
+
+    // Commemoration Day and National Day
+    assert_eq!(holidays_between(&cal, ymd(2018, 11, 29), ymd(2018, 12, 4)), dates(&[(2018, 11, 30), (2018, 12, 2), (2018, 12, 3)]));
+    assert_eq!(holidays_between(&cal, ymd(2024, 11, 29), ymd(2024, 12, 4)), dates(&[(2024, 12, 1), (2024, 12, 2), (2024, 12, 3)]));
+    assert!(!cal.is_holiday(ymd(2014, 11, 30)));
+
+    let d0 = ymd(2000, 1, 1);
+    let d1 = ymd(2026, 12, 31);
+    let cached_cal = HolidayCalendarCache::new(cal.clone(), d0, d1);
+    assert_eq!(cached_cal.bdays(d0, d1), cal.bdays(d0, d1));
+
+    let boxed = calendars::from_code("UAESettlement").expect("Valid code");
+    assert_eq!(boxed.bdays(d0, d1), cal.bdays(d0, d1));
+
+    // out of the range of the Hijri calendar
+    for date in [Date::MIN.advance_days(200), Date::MAX.advance_days(-200)] {
+        assert!(!cal.is_holiday(date));
+        assert!(!calendars::sa::Tadawul::new().is_holiday(date));
+    }
+}
+
 #[test]
 fn test_brazil_exchange() {
     let cal = calendars::brazil::BrazilExchange;
@@ -702,3 +1295,427 @@ fn test_brazil_exchange() {
     assert!( cal.is_holiday(Date::from_ymd(2022, 11, 2).expect("Valid date")) == true );
     assert!( cal.is_holiday(Date::from_ymd(2022, 11, 15).expect("Valid date")) == true );
 }
+
+#[test]
+fn test_brazil_optional_days() {
+    use calendars::brazil::{BrazilPublicAdministration, HolidayKind};
+
+    let carnival = Date::from_ymd(2025, 3, 4).expect("Valid date");
+    let ash_wednesday = Date::from_ymd(2025, 3, 5).expect("Valid date");
+    let corpus_christi = Date::from_ymd(2025, 6, 19).expect("Valid date");
+    let christmas_eve = Date::from_ymd(2025, 12, 24).expect("Valid date");
+    let independence_day = Date::from_ymd(2025, 9, 7).expect("Valid date");
+    let regular_day = Date::from_ymd(2025, 3, 6).expect("Valid date");
+
+    let br = calendars::brazil::BRSettlement;
+    assert_eq!(br.day_kind(carnival), Some(HolidayKind::Holiday));
+    assert_eq!(br.day_kind(ash_wednesday), Some(HolidayKind::HalfDay));
+    assert!(br.is_bday(ash_wednesday));
+    assert_eq!(br.day_kind(christmas_eve), None);
+    assert_eq!(br.day_kind(regular_day), None);
+
+    let b3 = calendars::brazil::BrazilExchange;
+    assert_eq!(b3.day_kind(ash_wednesday), Some(HolidayKind::HalfDay));
+    assert_eq!(b3.day_kind(christmas_eve), Some(HolidayKind::Holiday));
+
+    let cal = BrazilPublicAdministration::new();
+    assert_eq!(cal.day_kind(carnival), Some(HolidayKind::OptionalDay));
+    assert_eq!(cal.day_kind(ash_wednesday), Some(HolidayKind::HalfDay));
+    assert_eq!(cal.day_kind(corpus_christi), Some(HolidayKind::OptionalDay));
+    assert_eq!(cal.day_kind(christmas_eve), Some(HolidayKind::HalfDay));
+    assert_eq!(cal.day_kind(independence_day), Some(HolidayKind::Holiday));
+    assert_eq!(cal.day_kind(regular_day), None);
+
+    assert!(!cal.is_bday(carnival));
+    assert!(!cal.is_bday(corpus_christi));
+    assert!(cal.is_bday(ash_wednesday));
+    assert!(cal.is_bday(christmas_eve));
+    assert_eq!(cal.holiday_name(corpus_christi), Some("Corpus Christi"));
+    assert_eq!(cal.holiday_name(ash_wednesday), None);
+
+    let cal = BrazilPublicAdministration::new().with_optional_days_as_bdays(true).with_half_days_as_bdays(false);
+    assert!(cal.is_bday(carnival));
+    assert!(cal.is_bday(corpus_christi));
+    assert!(!cal.is_bday(ash_wednesday));
+    assert!(!cal.is_bday(christmas_eve));
+    assert_eq!(cal.holiday_name(ash_wednesday), Some("Quarta-feira de Cinzas"));
+    assert_eq!(cal.day_kind(carnival), Some(HolidayKind::OptionalDay));
+
+    // the national holidays are holidays in any configuration
+    assert!(!cal.is_bday(Date::from_ymd(2025, 4, 18).expect("Valid date"))); // Sexta-feira Santa
+
+    // same as BRSettlement with the default configuration
+    let d0 = Date::from_ymd(2000, 1, 1).expect("Valid date");
+    let d1 = Date::from_ymd(2030, 12, 31).expect("Valid date");
+    assert_eq!(holidays_between(&BrazilPublicAdministration::default(), d0, d1), holidays_between(&br, d0, d1));
+}
+
+#[test]
+fn test_brazil_state() {
+    use calendars::brazil::{BrazilState, BrazilMunicipality};
+
+    let sp = BrazilState::SP;
+    assert!(!sp.is_bday(Date::from_ymd(2024, 7, 9).expect("Valid date"))); // Revolução Constitucionalista
+    assert!(sp.is_bday(Date::from_ymd(1996, 7, 9).expect("Valid date"))); // state holiday since 1997
+    assert!(sp.is_bday(Date::from_ymd(2024, 1, 25).expect("Valid date"))); // municipal holiday in São Paulo
+    assert!(!sp.is_bday(Date::from_ymd(2024, 11, 15).expect("Valid date"))); // national holiday
+    assert_eq!(sp.holiday_name(Date::from_ymd(2024, 7, 9).expect("Valid date")), Some("Revolução Constitucionalista de 1932"));
+
+    let rj = BrazilState::RJ;
+    assert!(!rj.is_bday(Date::from_ymd(2024, 4, 23).expect("Valid date"))); // São Jorge
+    assert!(rj.is_bday(Date::from_ymd(2007, 4, 23).expect("Valid date"))); // state holiday since 2008
+    assert!(!rj.is_bday(Date::from_ymd(2023, 11, 20).expect("Valid date"))); // Dia da Consciência Negra
+    assert!(rj.is_bday(Date::from_ymd(2025, 1, 20).expect("Valid date"))); // municipal holiday in Rio de Janeiro
+
+    // the national holiday comes first
+    assert_eq!(BrazilState::MG.holiday_name(Date::from_ymd(2025, 4, 21).expect("Valid date")), Some("Tiradentes"));
+    assert_eq!(BrazilState::MG.num_holidays(2025), 0);
+    assert_eq!(BrazilState::BA.holiday_name(Date::from_ymd(2025, 7, 2).expect("Valid date")), Some("Independência da Bahia"));
+    assert_eq!(BrazilState::AC.num_holidays(2025), 5);
+    assert!(!BrazilState::RS.is_bday(Date::from_ymd(2024, 9, 20).expect("Valid date"))); // Revolução Farroupilha
+
+    let sao_paulo = BrazilMunicipality::SaoPaulo;
+    assert_eq!(sao_paulo.state(), BrazilState::SP);
+    assert!(!sao_paulo.is_bday(Date::from_ymd(2024, 1, 25).expect("Valid date"))); // Aniversário de São Paulo
+    assert!(!sao_paulo.is_bday(Date::from_ymd(2024, 7, 9).expect("Valid date"))); // state holiday
+    assert!(!sao_paulo.is_bday(Date::from_ymd(2019, 11, 20).expect("Valid date"))); // Dia da Consciência Negra
+    assert!(!sao_paulo.is_bday(Date::from_ymd(2024, 5, 30).expect("Valid date"))); // Corpus Christi
+
+    let rio = BrazilMunicipality::RioDeJaneiro;
+    assert_eq!(rio.holiday_name(Date::from_ymd(2025, 1, 20).expect("Valid date")), Some("São Sebastião"));
+    assert_eq!(rio.holiday_name(Date::from_ymd(2025, 4, 23).expect("Valid date")), Some("São Jorge"));
+    let rio_holidays = rio.holidays_in_year(2025).expect("holidays in year");
+    assert!(rio_holidays.windows(2).all(|pair| pair[0] < pair[1])); // São Jorge is both a state and a municipal holiday
+
+    let vitoria = BrazilMunicipality::Vitoria;
+    assert_eq!(vitoria.holiday_name(Date::from_ymd(2025, 4, 28).expect("Valid date")), Some("Nossa Senhora da Penha"));
+
+    // the state and municipal Dia da Consciência Negra ends when the national holiday starts
+    let nov20 = |year| Date::from_ymd(year, 11, 20).expect("Valid date");
+    assert_eq!(rj.holiday_name(nov20(2023)), Some("Dia da Consciência Negra"));
+    assert_eq!(rj.holiday_name(nov20(2024)), Some("Dia Nacional de Zumbi e da Consciência Negra"));
+    assert_eq!(BrazilState::MT.num_holidays(2023), 1);
+    assert_eq!(BrazilState::MT.num_holidays(2024), 0);
+    for year in [2023, 2024] {
+        let holidays = sao_paulo.holidays_in_year(year).expect("holidays in year");
+        assert_eq!(holidays.iter().filter(|&&date| date == nov20(year)).count(), 1);
+    }
+
+    let code_calendar = calendars::from_code("br-sp-sao_paulo").expect("São Paulo code");
+    assert_eq!(code_calendar.holidays_in_year(2024), sao_paulo.holidays_in_year(2024));
+
+    // same holidays as the exchange in São Paulo before 2022, except for the exchange's own closing days
+    let d0 = Date::from_ymd(2010, 1, 1).expect("Valid date");
+    let d1 = Date::from_ymd(2019, 12, 31).expect("Valid date");
+    let exchange_holidays: Vec<Date> = holidays_between(&calendars::brazil::BrazilExchange, d0, d1)
+        .into_iter()
+        .filter(|date| !matches!(date.to_ymd(), (_, 12, 24 | 29 | 30 | 31)))
+        .collect();
+    assert_eq!(holidays_between(&sao_paulo, d0, d1), exchange_holidays);
+}
+
+#[test]
+fn test_holiday_names() {
+    let br = calendars::brazil::BRSettlement;
+    assert_eq!(br.holiday_name(Date::from_ymd(2013, 2, 12).expect("Valid date")), Some("Terça-feira de Carnaval"));
+    assert_eq!(br.holiday_name(Date::from_ymd(2024, 11, 20).expect("Valid date")), Some("Dia Nacional de Zumbi e da Consciência Negra"));
+    assert_eq!(br.holiday_name(Date::from_ymd(2013, 2, 13).expect("Valid date")), None);
+
+    let b3 = calendars::brazil::BrazilExchange;
+    assert_eq!(b3.holiday_name(Date::from_ymd(2019, 1, 25).expect("Valid date")), Some("Aniversário de São Paulo"));
+    assert_eq!(b3.holiday_name(Date::from_ymd(2019, 4, 19).expect("Valid date")), Some("Sexta-feira Santa"));
+
+    let us = calendars::us::USSettlement;
+    assert_eq!(us.holiday_name(Date::from_ymd(2015, 7, 3).expect("Valid date")), Some("Independence Day"));
+    assert_eq!(us.holiday_name(Date::from_ymd(2015, 7, 4).expect("Valid date")), None);
+
+    let de = calendars::de::GermanState::BY;
+    assert_eq!(de.holiday_name(Date::from_ymd(2023, 8, 15).expect("Valid date")), Some("Mariä Himmelfahrt"));
+    assert_eq!(calendars::de::GermanState::NI.holiday_name(Date::from_ymd(2023, 8, 15).expect("Valid date")), None);
+
+    assert_eq!(calendars::WeekendsOnly.holiday_name(Date::from_ymd(2023, 12, 25).expect("Valid date")), None);
+}
+
+#[test]
+fn test_holiday_calendar_cache_checked() {
+    let cal = calendars::brazil::BRSettlement;
+    let d0 = Date::from_ymd(2012, 1, 1).expect("Valid date");
+    let d1 = Date::from_ymd(2014, 12, 31).expect("Valid date");
+    let cached_cal = HolidayCalendarCache::new(calendars::brazil::BRSettlement, d0, d1);
+
+    let mut dt = Date::from_ymd(2013, 1, 1).expect("Valid date");
+    while dt <= Date::from_ymd(2013, 12, 31).expect("Valid date") {
+        for bdays_count in [-200, -30, -1, 0, 1, 2, 5, 30, 200] {
+            assert_eq!(cached_cal.checked_advance_bdays(dt, bdays_count), Some(cal.advance_bdays(dt, bdays_count)));
+        }
+
+        assert_eq!(cached_cal.checked_to_bday(dt, true), Some(cal.to_bday(dt, true)));
+        assert_eq!(cached_cal.checked_to_bday(dt, false), Some(cal.to_bday(dt, false)));
+        assert_eq!(cached_cal.checked_bdays(d0, dt), Some(cal.bdays(d0, dt)));
+        dt = dt.next_date();
+    }
+
+    // 2012-01-01 is a holiday on a Sunday, 2014-12-31 is a Wednesday
+    assert_eq!(cached_cal.checked_to_bday(d0, false), None);
+    assert_eq!(cached_cal.checked_advance_bdays(d0, -1), None);
+    assert_eq!(cached_cal.checked_advance_bdays(Date::from_ymd(2012, 1, 2).expect("Valid date"), 0), Some(Date::from_ymd(2012, 1, 2).expect("Valid date")));
+    assert_eq!(cached_cal.checked_advance_bdays(Date::from_ymd(2012, 1, 3).expect("Valid date"), -1), Some(Date::from_ymd(2012, 1, 2).expect("Valid date")));
+    assert_eq!(cached_cal.checked_advance_bdays(d1, 0), Some(d1));
+    assert_eq!(cached_cal.checked_advance_bdays(d1, 1), None);
+    assert_eq!(cached_cal.checked_advance_bdays(d1, i32::MAX), None);
+    assert_eq!(cached_cal.checked_bdays(d0, Date::from_ymd(2015, 1, 1).expect("Valid date")), None);
+    assert_eq!(cached_cal.checked_bdays(Date::from_ymd(2011, 12, 31).expect("Valid date"), d1), None);
+}
+
+#[test]
+fn test_bdays_many() {
+    let cal = calendars::brazil::BRSettlement;
+    let cached_cal = HolidayCalendarCache::new(
+        calendars::brazil::BRSettlement,
+        Date::from_ymd(2012, 1, 1).expect("Valid date"),
+        Date::from_ymd(2016, 12, 31).expect("Valid date"),
+    );
+
+    let d0 = Date::from_ymd(2014, 1, 1).expect("Valid date");
+    let mut pairs: Vec<(Date, Date)> = Vec::new();
+    let mut advances: Vec<(Date, i32)> = Vec::new();
+
+    for i in 0..365 {
+        let dt = d0.advance_days(i);
+        pairs.push((d0, dt));
+        pairs.push((dt, d0.advance_days(i * 3 - 400)));
+
+        for bdays_count in [-300, -1, 0, 1, 7, 300] {
+            advances.push((dt, bdays_count));
+        }
+    }
+
+    let expected: Vec<i32> = pairs.iter().map(|&(d0, d1)| cal.bdays(d0, d1)).collect();
+    let mut out = vec![0; pairs.len()];
+    cal.bdays_many(&pairs, &mut out);
+    assert_eq!(out, expected);
+
+    let mut out = vec![0; pairs.len()];
+    cached_cal.bdays_many(&pairs, &mut out);
+    assert_eq!(out, expected);
+
+    let boxed: Box<dyn HolidayCalendar> = Box::new(cached_cal);
+    let mut out = vec![0; pairs.len()];
+    boxed.bdays_many(&pairs, &mut out);
+    assert_eq!(out, expected);
+
+    let expected: Vec<Date> = advances.iter().map(|&(dt, bdays_count)| cal.advance_bdays(dt, bdays_count)).collect();
+    let mut out = vec![d0; advances.len()];
+    cal.advance_bdays_many(&advances, &mut out);
+    assert_eq!(out, expected);
+
+    let mut out = vec![d0; advances.len()];
+    boxed.advance_bdays_many(&advances, &mut out);
+    assert_eq!(out, expected);
+}
+
+#[test]
+#[should_panic(expected = "same length")]
+fn test_bdays_many_length_mismatch() {
+    let d0 = Date::from_ymd(2014, 1, 1).expect("Valid date");
+    calendars::WeekendsOnly.bdays_many(&[(d0, d0)], &mut []);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_advance_bdays_many_cache_out_of_bounds() {
+    let d0 = Date::from_ymd(2014, 1, 1).expect("Valid date");
+    let d1 = Date::from_ymd(2014, 12, 31).expect("Valid date");
+    let cached_cal = HolidayCalendarCache::new(calendars::WeekendsOnly, d0, d1);
+
+    let mut out = [d0];
+    cached_cal.advance_bdays_many(&[(d1, 1)], &mut out);
+}
+
+#[test]
+fn test_holidays_in_year() {
+    // the enumeration must match checking every date
+    fn check<H: HolidayCalendar>(cal: &H, years: std::ops::RangeInclusive<i32>) {
+        for year in years {
+            let mut expected: Vec<Date> = Vec::new();
+            let mut date = Date::from_ymd(year, 1, 1).expect("Valid date");
+
+            while date.year() == year {
+                if cal.is_holiday(date) {
+                    expected.push(date);
+                }
+
+                date = date.next_date();
+            }
+
+            let mut holidays = cal.holidays_in_year(year).expect("enumerates holidays");
+            holidays.sort();
+            holidays.dedup();
+            assert_eq!(holidays, expected, "{year}");
+        }
+    }
+
+    check(&calendars::WeekendsOnly, 2000..=2001);
+    check(&calendars::brazil::BRSettlement, 1900..=2150);
+    check(&calendars::brazil::BrazilExchange, 1900..=2150);
+
+    for state in calendars::brazil::BrazilState::ALL {
+        check(&state, 1990..=2050);
+    }
+
+    check(&calendars::brazil::BrazilPublicAdministration::new(), 1900..=2150);
+    check(&calendars::brazil::BrazilPublicAdministration::new().with_optional_days_as_bdays(true).with_half_days_as_bdays(false), 1900..=2150);
+    check(&calendars::brazil::BrazilMunicipality::SaoPaulo, 1900..=2150);
+    check(&calendars::brazil::BrazilMunicipality::RioDeJaneiro, 1900..=2150);
+    check(&calendars::brazil::BrazilMunicipality::Vitoria, 1900..=2150);
+    check(&calendars::us::USSettlement, 1900..=2150);
+    check(&calendars::us::USSettlementDC, 1900..=2150);
+    check(&calendars::us::NYSE, 1900..=2150);
+    check(&calendars::us::SIFMABond, 1900..=2150);
+    check(&calendars::us::FederalReserve, 1900..=2150);
+    check(&calendars::us::NERC, 1900..=2150);
+    check(&calendars::uk::UKSettlement, 1900..=2150);
+    check(&calendars::uk::ScotlandSettlement, 1900..=2150);
+    check(&calendars::uk::NorthernIrelandSettlement, 1900..=2150);
+    check(&calendars::eu::Target, 1990..=2150);
+    check(&calendars::jp::JapanSettlement, 1900..=2150);
+    check(&calendars::jp::JPX, 1900..=2150);
+    check(&calendars::cn::SSE, 2000..=2030);
+    check(&calendars::cn::CFETS, 2000..=2030);
+    check(&calendars::de::GermanState::ANY, 1985..=2150);
+    check(&calendars::de::GermanState::BY, 1985..=2150);
+    check(&vec![calendars::de::GermanState::BE, calendars::de::GermanState::SN], 1985..=2150);
+
+    let rules: crate::rules::RuleCalendar = "\
+[[holiday]]
+name = \"New Year's Day\"
+month = 1
+day = 1
+observance = \"nearest_weekday\"
+
+[[holiday]]
+name = \"Good Friday\"
+easter_offset = -2
+".parse().unwrap();
+    check(&rules, 1990..=2050);
+}
+
+#[test]
+fn test_bdays_closed_form() {
+    // compares the closed form with counting business days one by one
+    fn check<H: HolidayCalendar>(cal: &H) {
+        let d0 = Date::from_ymd(2011, 12, 20).expect("Valid date");
+
+        for offset in (-800..800).step_by(7) {
+            for shift in 0..7 {
+                let d1 = d0.advance_days(offset + shift);
+                let (mut from, to) = (cal.to_bday(d0.min(d1), true), cal.to_bday(d0.max(d1), true));
+                let mut expected = 0;
+
+                while from < to {
+                    from = cal.advance_bdays(from, 1);
+                    expected += 1;
+                }
+
+                if d1 < d0 {
+                    expected = -expected;
+                }
+
+                assert_eq!(cal.bdays(d0, d1), expected, "{d0} {d1}");
+            }
+        }
+    }
+
+    check(&calendars::brazil::BRSettlement);
+    check(&calendars::brazil::BrazilExchange);
+    check(&calendars::us::USSettlement);
+    check(&calendars::us::USSettlementDC);
+    check(&calendars::us::NYSE);
+    check(&calendars::us::SIFMABond);
+    check(&calendars::uk::UKSettlement);
+    check(&calendars::uk::ScotlandSettlement);
+    check(&calendars::eu::Target);
+    check(&calendars::jp::JPX);
+    check(&calendars::cn::SSE);
+    check(&calendars::cn::CFETS);
+    check(&calendars::de::GermanState::NW);
+
+    // a Friday and Saturday weekend, which was Thursday and Friday until 2013-06-28
+    struct GulfCalendar;
+
+    impl HolidayCalendar for GulfCalendar {
+        fn is_holiday(&self, date: Date) -> bool {
+            matches!(date.to_ymd(), (_, 5, 1) | (_, 12, 2))
+        }
+
+        fn holidays_in_year(&self, year: i32) -> Option<Vec<Date>> {
+            Some(vec![Date::from_ymd(year, 5, 1).ok()?, Date::from_ymd(year, 12, 2).ok()?])
+        }
+
+        fn is_weekend(&self, date: Date) -> bool {
+            let weekend_changed = Date::from_ymd_const(2013, 6, 29);
+
+            match date.weekday() {
+                crate::date::Weekday::Thursday => date < weekend_changed,
+                crate::date::Weekday::Friday => true,
+                crate::date::Weekday::Saturday => date >= weekend_changed,
+                _ => false,
+            }
+        }
+    }
+
+    check(&GulfCalendar);
+
+    // 2015 to 2100, without a HolidayCalendarCache
+    let d0 = Date::from_ymd(2015, 6, 29).expect("Valid date");
+    let d1 = Date::from_ymd(2100, 12, 20).expect("Valid date");
+    let cached_cal = HolidayCalendarCache::new(calendars::brazil::BRSettlement, d0, Date::from_ymd(2101, 1, 31).expect("Valid date"));
+    assert_eq!(calendars::brazil::BRSettlement.bdays(d0, d1), cached_cal.bdays(d0, d1));
+    assert_eq!(calendars::brazil::BRSettlement.bdays(d1, d0), cached_cal.bdays(d1, d0));
+
+    // no Easter dates before 1583, where only the fixed holidays are counted
+    let d0 = Date::from_ymd(1500, 1, 1).expect("Valid date");
+    let d1 = Date::from_ymd(1501, 1, 1).expect("Valid date");
+
+    for cal in [&calendars::brazil::BRSettlement as &dyn HolidayCalendar, &calendars::brazil::BrazilExchange] {
+        let mut expected = 0;
+        let mut date = d0;
+
+        while date < d1 {
+            expected += i32::from(cal.is_bday(date));
+            date = date.next_date();
+        }
+
+        assert_eq!(cal.bdays(d0, d1), expected);
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_holiday_calendar_cache_par_new() {
+    use calendars::de::GermanState;
+
+    fn check<H: HolidayCalendar + Sync>(cal: impl Fn() -> H, dt_min: Date, dt_max: Date) {
+        let serial = HolidayCalendarCache::new(cal(), dt_min, dt_max);
+        let parallel = HolidayCalendarCache::par_new(cal(), dt_min, dt_max);
+
+        assert_eq!(parallel.dt_min, serial.dt_min);
+        assert_eq!(parallel.dt_max, serial.dt_max);
+        assert_eq!(parallel.is_holiday_vec, serial.is_holiday_vec);
+        assert_eq!(parallel.is_weekend_vec, serial.is_weekend_vec);
+        assert_eq!(parallel.is_bday_vec, serial.is_bday_vec);
+        assert_eq!(parallel.bdays_counter_vec, serial.bdays_counter_vec);
+        assert_eq!(parallel.holiday_names, serial.holiday_names);
+    }
+
+    let ymd = |y, m, d| Date::from_ymd(y, m, d).expect("Valid date");
+
+    // starting on a business day and on a holiday, and with the dates swapped
+    check(|| calendars::brazil::BRSettlement, ymd(1980, 1, 2), ymd(2100, 12, 31));
+    check(|| calendars::brazil::BRSettlement, ymd(1980, 1, 1), ymd(2100, 6, 15));
+    check(|| calendars::brazil::BRSettlement, ymd(2100, 6, 15), ymd(1980, 3, 1));
+    check(|| calendars::brazil::BRSettlement, ymd(2024, 5, 1), ymd(2024, 5, 20));
+    check(|| calendars::brazil::BRSettlement, ymd(2024, 12, 31), ymd(2024, 12, 31));
+    check(|| vec![GermanState::BY, GermanState::NW, GermanState::SN], ymd(1990, 1, 1), ymd(2150, 12, 31));
+    check(calendars::sa::Tadawul::new, ymd(2010, 1, 1), ymd(2030, 12, 31));
+}
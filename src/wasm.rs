@@ -0,0 +1,185 @@
+//! WebAssembly bindings over the built-in calendars, through `wasm-bindgen`.
+//!
+//! Dates are ISO 8601 strings (`YYYY-MM-DD`) and errors are thrown as JavaScript `Error`s.
+//!
+//! ```js
+//! import { Calendar } from "bdays";
+//!
+//! const cal = new Calendar("BRSettlement");
+//! cal.advanceBdays("2025-11-19", 2); // "2025-11-24"
+//! ```
+
+use crate::HolidayCalendar;
+use crate::calendars;
+use crate::date::Date;
+use wasm_bindgen::prelude::*;
+
+// Years supported by the bindings, where every built-in calendar is defined.
+const MIN_YEAR: i32 = 1583;
+const MAX_YEAR: i32 = 9999;
+
+fn parse_date(text: &str) -> Result<Date, JsError> {
+    let date: Date = text
+        .parse()
+        .map_err(|_| JsError::new(&format!("invalid date `{text}`, expected YYYY-MM-DD")))?;
+
+    if (MIN_YEAR..=MAX_YEAR).contains(&date.year()) {
+        Ok(date)
+    } else {
+        Err(JsError::new(&format!(
+            "date `{text}` out of the supported range of years [{MIN_YEAR}, {MAX_YEAR}]"
+        )))
+    }
+}
+
+/// Returns the codes of the built-in calendars, as accepted by `new Calendar(code)`.
+#[wasm_bindgen(js_name = calendarCodes)]
+pub fn calendar_codes() -> Vec<String> {
+    calendars::CALENDAR_CODES
+        .iter()
+        .map(|(code, _)| code.to_string())
+        .collect()
+}
+
+/// A built-in holiday calendar.
+#[wasm_bindgen]
+pub struct Calendar {
+    code: String,
+    calendar: Box<dyn HolidayCalendar + Send + Sync>,
+}
+
+/// A holiday, as listed by `Calendar.holidays`.
+#[wasm_bindgen]
+pub struct Holiday {
+    date: String,
+    name: Option<String>,
+}
+
+#[wasm_bindgen]
+impl Holiday {
+    /// The date of the holiday, as `YYYY-MM-DD`.
+    #[wasm_bindgen(getter)]
+    pub fn date(&self) -> String {
+        self.date.clone()
+    }
+
+    /// The name of the holiday, if the calendar provides one.
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> Option<String> {
+        self.name.clone()
+    }
+}
+
+#[wasm_bindgen]
+impl Calendar {
+    /// Creates the built-in calendar identified by `code`, such as `"BRSettlement"`.
+    /// Codes are case-insensitive.
+    #[wasm_bindgen(constructor)]
+    pub fn new(code: &str) -> Result<Calendar, JsError> {
+        let calendar = calendars::from_code(code).ok_or_else(|| JsError::new(&format!("unknown calendar `{code}`")))?;
+
+        Ok(Calendar {
+            code: code.to_string(),
+            calendar,
+        })
+    }
+
+    /// The code this calendar was created with.
+    #[wasm_bindgen(getter)]
+    pub fn code(&self) -> String {
+        self.code.clone()
+    }
+
+    #[wasm_bindgen(js_name = isHoliday)]
+    pub fn is_holiday(&self, date: &str) -> Result<bool, JsError> {
+        Ok(self.calendar.is_holiday(parse_date(date)?))
+    }
+
+    #[wasm_bindgen(js_name = isBday)]
+    pub fn is_bday(&self, date: &str) -> Result<bool, JsError> {
+        Ok(self.calendar.is_bday(parse_date(date)?))
+    }
+
+    /// Adjusts `date` to the next business day if `adjust_next` is true,
+    /// or to the previous one otherwise.
+    #[wasm_bindgen(js_name = toBday)]
+    pub fn to_bday(&self, date: &str, adjust_next: bool) -> Result<String, JsError> {
+        Ok(self.calendar.to_bday(parse_date(date)?, adjust_next).to_string())
+    }
+
+    /// Advances `bdays_count` business days from `date`, backwards if negative.
+    #[wasm_bindgen(js_name = advanceBdays)]
+    pub fn advance_bdays(&self, date: &str, bdays_count: i32) -> Result<String, JsError> {
+        Ok(self.calendar.advance_bdays(parse_date(date)?, bdays_count).to_string())
+    }
+
+    /// Counts the business days from `d0` to `d1`.
+    pub fn bdays(&self, d0: &str, d1: &str) -> Result<i32, JsError> {
+        Ok(self.calendar.bdays(parse_date(d0)?, parse_date(d1)?))
+    }
+
+    /// Lists the holidays from `from` to `to`, both inclusive, including those on weekends.
+    pub fn holidays(&self, from: &str, to: &str) -> Result<Vec<Holiday>, JsError> {
+        let mut date = parse_date(from)?;
+        let to = parse_date(to)?;
+        let mut holidays = Vec::new();
+
+        while date <= to {
+            if self.calendar.is_holiday(date) {
+                holidays.push(Holiday {
+                    date: date.to_string(),
+                    name: self.calendar.holiday_name(date).map(str::to_string),
+                });
+            }
+
+            date = date.next_date();
+        }
+
+        Ok(holidays)
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[cfg(all(test, target_arch = "wasm32"))]
+#[wasm_bindgen_test]
+fn test_wasm_calendar() {
+    let cal = Calendar::new("brsettlement").unwrap();
+    assert_eq!(cal.code(), "brsettlement");
+
+    assert!(cal.is_holiday("2025-11-20").unwrap());
+    assert!(!cal.is_bday("2025-11-20").unwrap());
+    assert!(cal.is_bday("2025-11-19").unwrap());
+    assert_eq!(cal.to_bday("2025-11-20", true).unwrap(), "2025-11-21");
+    assert_eq!(cal.to_bday("2025-11-20", false).unwrap(), "2025-11-19");
+    assert_eq!(cal.advance_bdays("2025-11-19", 2).unwrap(), "2025-11-24");
+    assert_eq!(cal.advance_bdays("2025-11-24", -2).unwrap(), "2025-11-19");
+    assert_eq!(cal.bdays("2013-02-06", "2013-02-14").unwrap(), 4);
+
+    let holidays = cal.holidays("2025-11-01", "2025-12-31").unwrap();
+    let holidays: Vec<(String, Option<String>)> = holidays.iter().map(|h| (h.date(), h.name())).collect();
+    assert_eq!(
+        holidays,
+        vec![
+            ("2025-11-02".to_string(), Some("Finados".to_string())),
+            ("2025-11-15".to_string(), Some("Proclamação da República".to_string())),
+            ("2025-11-20".to_string(), Some("Dia Nacional de Zumbi e da Consciência Negra".to_string())),
+            ("2025-12-25".to_string(), Some("Natal".to_string())),
+        ]
+    );
+
+    assert!(calendar_codes().iter().any(|code| code == "USSettlement"));
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+#[wasm_bindgen_test]
+fn test_wasm_errors() {
+    assert!(Calendar::new("XX").is_err());
+
+    let cal = Calendar::new("USSettlement").unwrap();
+    assert!(cal.is_bday("2025-13-01").is_err());
+    assert!(cal.is_bday("20251201").is_err());
+    assert!(cal.advance_bdays("1500-01-01", 1).is_err());
+    assert!(cal.bdays("2025-01-01", "not a date").is_err());
+}